@@ -1,5 +1,4 @@
 import React, { useState, useEffect } from 'react';
-import { useProgram } from '~/solana/useProgram';
 import { useWallet } from '@solana/wallet-adapter-react';
 import { PublicKey } from '@solana/web3.js';
 import type { IdlAccounts } from '@coral-xyz/anchor';
@@ -27,7 +26,6 @@ const FareSelection: React.FC<FareSelectionProps> = ({
   const [selectedMode, setSelectedMode] = useState<0 | 1 | null>(null);
   const [fareAmount, setFareAmount] = useState<bigint>(0n);
   const { publicKey } = useWallet();
-  const { purchaseTicketSendAndConfirm } = useProgram();
 
   useEffect(() => {
     if (selectedMode === 0) {
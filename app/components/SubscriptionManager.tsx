@@ -90,6 +90,9 @@ export function SubscriptionManager({
         mint: fareConfig.currencyMint,
         destination: publicKey,
         subscriptionType,
+        seatCount: 1,
+        paymentId: BigInt(passengerData?.paymentCount?.toString() ?? '0'),
+        maxPrice: BigInt((subscriptionType === 1 ? fareConfig.monthlyPassPrice : fareConfig.yearlyPassPrice).toString()),
         signers: {
           feePayer: { publicKey } as any,
           user: { publicKey } as any,
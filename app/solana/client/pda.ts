@@ -10,6 +10,15 @@ export const deriveFareConfigPDA = (programId: PublicKey): [PublicKey, number] =
     )
 };
 
+export const deriveRolesPDA = (programId: PublicKey): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("roles"),
+        ],
+        programId,
+    )
+};
+
 export type PassengerSeeds = {
     user: PublicKey, 
 };
@@ -65,6 +74,40 @@ export const derivePaymentPDA = (
     )
 };
 
+export type HouseholdSeeds = {
+    holder: PublicKey, 
+};
+
+export const deriveHouseholdPDA = (
+    seeds: HouseholdSeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("household"),
+            seeds.holder.toBuffer(),
+        ],
+        programId,
+    )
+};
+
+export type RideHistorySeeds = {
+    user: PublicKey, 
+};
+
+export const deriveRideHistoryPDA = (
+    seeds: RideHistorySeeds,
+    programId: PublicKey
+): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("ride_history"),
+            seeds.user.toBuffer(),
+        ],
+        programId,
+    )
+};
+
 export module TokenProgramPDAs {
     export type AccountSeeds = {
         wallet: PublicKey, 
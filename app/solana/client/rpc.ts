@@ -130,83 +130,103 @@ export const initializeFareConfigSendAndConfirm = async (
     .rpc();
 }
 
-export type UpdateFareConfigArgs = {
+export type ScheduleFareChangeArgs = {
   feePayer: web3.PublicKey;
-  admin: web3.PublicKey;
+  fareManager: web3.PublicKey;
   mode0Fare: bigint | undefined;
   mode1Fare: bigint | undefined;
+  monthlyPassPrice: bigint | undefined;
+  yearlyPassPrice: bigint | undefined;
+  effectiveAt: bigint;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Update transit fare configuration settings
+ * Queue new fares and pass prices to take effect at a later time
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
  * 1. `[writable]` fare_config: {@link FareConfig}
- * 2. `[signer]` admin: {@link PublicKey} Administrator account
+ * 2. `[]` roles: {@link Roles} Role holders
+ * 3. `[signer]` fare_manager: {@link PublicKey} Fare manager role holder
  *
  * Data:
  * - mode_0_fare: {@link BigInt | undefined} New bus fare amount (transport mode 0 = bus, optional)
  * - mode_1_fare: {@link BigInt | undefined} New train fare amount (transport mode 1 = train, optional)
+ * - monthly_pass_price: {@link BigInt | undefined} New monthly subscription price (optional)
+ * - yearly_pass_price: {@link BigInt | undefined} New yearly subscription price (optional)
+ * - effective_at: {@link BigInt} Timestamp the new fares take effect, at least min_fare_notice from now
  */
-export const updateFareConfigBuilder = (
-	args: UpdateFareConfigArgs,
+export const scheduleFareChangeBuilder = (
+	args: ScheduleFareChangeArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<TransitFarePayment, never> => {
   const [fareConfigPubkey] = pda.deriveFareConfigPDA(_program.programId);
+  const [rolesPubkey] = pda.deriveRolesPDA(_program.programId);
 
   return _program
     .methods
-    .updateFareConfig(
-      args.mode0Fare ? new BN(args.mode0Fare.toString()) : undefined,
-      args.mode1Fare ? new BN(args.mode1Fare.toString()) : undefined,
+    .scheduleFareChange(
+      args.mode0Fare !== undefined ? new BN(args.mode0Fare.toString()) : null,
+      args.mode1Fare !== undefined ? new BN(args.mode1Fare.toString()) : null,
+      args.monthlyPassPrice !== undefined ? new BN(args.monthlyPassPrice.toString()) : null,
+      args.yearlyPassPrice !== undefined ? new BN(args.yearlyPassPrice.toString()) : null,
+      new BN(args.effectiveAt.toString()),
     )
     .accountsStrict({
       feePayer: args.feePayer,
       fareConfig: fareConfigPubkey,
-      admin: args.admin,
+      roles: rolesPubkey,
+      fareManager: args.fareManager,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Update fare configuration settings
+ * Queue new fares and pass prices to take effect at a later time
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` fare_config: {@link FareConfig} 
- * 2. `[signer]` admin: {@link PublicKey} Administrator account
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig}
+ * 2. `[]` roles: {@link Roles} Role holders
+ * 3. `[signer]` fare_manager: {@link PublicKey} Fare manager role holder
  *
  * Data:
- * - bus_fare: {@link BigInt | undefined} New bus fare amount (optional)
- * - train_fare: {@link BigInt | undefined} New train fare amount (optional)
+ * - mode_0_fare: {@link BigInt | undefined} New bus fare amount (transport mode 0 = bus, optional)
+ * - mode_1_fare: {@link BigInt | undefined} New train fare amount (transport mode 1 = train, optional)
+ * - monthly_pass_price: {@link BigInt | undefined} New monthly subscription price (optional)
+ * - yearly_pass_price: {@link BigInt | undefined} New yearly subscription price (optional)
+ * - effective_at: {@link BigInt} Timestamp the new fares take effect, at least min_fare_notice from now
  */
-export const updateFareConfig = (
-	args: UpdateFareConfigArgs,
+export const scheduleFareChange = (
+	args: ScheduleFareChangeArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): Promise<web3.TransactionInstruction> =>
-    updateFareConfigBuilder(args, remainingAccounts).instruction();
+    scheduleFareChangeBuilder(args, remainingAccounts).instruction();
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Update fare configuration settings
+ * Queue new fares and pass prices to take effect at a later time
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` fare_config: {@link FareConfig} 
- * 2. `[signer]` admin: {@link PublicKey} Administrator account
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig}
+ * 2. `[]` roles: {@link Roles} Role holders
+ * 3. `[signer]` fare_manager: {@link PublicKey} Fare manager role holder
  *
  * Data:
- * - bus_fare: {@link BigInt | undefined} New bus fare amount (optional)
- * - train_fare: {@link BigInt | undefined} New train fare amount (optional)
+ * - mode_0_fare: {@link BigInt | undefined} New bus fare amount (transport mode 0 = bus, optional)
+ * - mode_1_fare: {@link BigInt | undefined} New train fare amount (transport mode 1 = train, optional)
+ * - monthly_pass_price: {@link BigInt | undefined} New monthly subscription price (optional)
+ * - yearly_pass_price: {@link BigInt | undefined} New yearly subscription price (optional)
+ * - effective_at: {@link BigInt} Timestamp the new fares take effect, at least min_fare_notice from now
  */
-export const updateFareConfigSendAndConfirm = async (
-  args: Omit<UpdateFareConfigArgs, "feePayer" | "admin"> & {
+export const scheduleFareChangeSendAndConfirm = async (
+  args: Omit<ScheduleFareChangeArgs, "feePayer" | "fareManager"> & {
     signers: {
       feePayer: web3.Signer,
-      admin: web3.Signer,
+      fareManager: web3.Signer,
     },
   },
   remainingAccounts: Array<web3.AccountMeta> = [],
@@ -214,13 +234,89 @@ export const updateFareConfigSendAndConfirm = async (
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
 
-  return updateFareConfigBuilder({
+  return scheduleFareChangeBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
-      admin: args.signers.admin.publicKey,
+      fareManager: args.signers.fareManager.publicKey,
     }, remainingAccounts)
     .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.admin])
+    .signers([args.signers.feePayer, args.signers.fareManager])
+    .rpc();
+}
+
+export type ApplyFareChangeArgs = {
+  feePayer: web3.PublicKey;
+};
+
+/**
+ * ### Returns a {@link MethodsBuilder}
+ * Write a due fare or price feed change into the live settings
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig}
+ *
+ * Data: None
+ */
+export const applyFareChangeBuilder = (
+	args: ApplyFareChangeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): MethodsBuilder<TransitFarePayment, never> => {
+  const [fareConfigPubkey] = pda.deriveFareConfigPDA(_program.programId);
+
+  return _program
+    .methods
+    .applyFareChange()
+    .accountsStrict({
+      feePayer: args.feePayer,
+      fareConfig: fareConfigPubkey,
+    })
+    .remainingAccounts(remainingAccounts);
+};
+
+/**
+ * ### Returns a {@link web3.TransactionInstruction}
+ * Write a due fare or price feed change into the live settings
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig}
+ *
+ * Data: None
+ */
+export const applyFareChange = (
+	args: ApplyFareChangeArgs,
+	remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionInstruction> =>
+    applyFareChangeBuilder(args, remainingAccounts).instruction();
+
+/**
+ * ### Returns a {@link web3.TransactionSignature}
+ * Write a due fare or price feed change into the live settings
+ *
+ * Accounts:
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig}
+ *
+ * Data: None
+ */
+export const applyFareChangeSendAndConfirm = async (
+  args: Omit<ApplyFareChangeArgs, "feePayer"> & {
+    signers: {
+      feePayer: web3.Signer,
+    },
+  },
+  remainingAccounts: Array<web3.AccountMeta> = [],
+): Promise<web3.TransactionSignature> => {
+  const preInstructions: Array<web3.TransactionInstruction> = [];
+
+
+  return applyFareChangeBuilder({
+      ...args,
+      feePayer: args.signers.feePayer.publicKey,
+    }, remainingAccounts)
+    .preInstructions(preInstructions)
+    .signers([args.signers.feePayer])
     .rpc();
 }
 
@@ -236,33 +332,46 @@ export type PurchaseTicketArgs = {
   authority: web3.PublicKey;
   transportMode: number;
   ticketId: bigint;
-  amount: bigint;
+  // Next receipt id, the passenger's paymentCount or the fare config's totalReceipts for a new rider
+  paymentId: bigint;
+  maxPrice: bigint;
+  promoCode?: string;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Purchase a ticket for either bus or train travel
+ * Purchase a transit ticket for any transport mode
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
- * 2. `[writable]` passenger: {@link Passenger} 
- * 3. `[writable]` ticket: {@link Ticket} 
- * 4. `[signer]` user: {@link PublicKey} User's wallet address
- * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 7. `[]` currency_mint: {@link Mint} Currency mint address
- * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 9. `[writable]` source: {@link PublicKey} The source account.
- * 10. `[]` mint: {@link Mint} The token mint.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger}
+ * 3. `[writable]` ticket: {@link Ticket}
+ * 4. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 5. `[signer]` user: {@link PublicKey} User's wallet address
+ * 6. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 7. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 8. `[]` currency_mint: {@link Mint} Currency mint address
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[writable]` source: {@link PublicKey} The source account.
+ * 11. `[]` mint: {@link Mint} The token mint.
+ * 12. `[writable]` destination: {@link PublicKey} The destination account.
+ * 13. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 14. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 15. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 16. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 17. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the fare
+ * 18. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 19. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 20. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 21. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
+ * 22. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - transport_mode: {@link number} Transport mode (0 = bus, 1 = train)
  * - ticket_id: {@link BigInt} Unique ticket identifier
- * - amount: {@link BigInt} Amount to pay for the ticket
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the ticket, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseTicketBuilder = (
 	args: PurchaseTicketArgs,
@@ -276,19 +385,25 @@ export const purchaseTicketBuilder = (
         user: args.user,
         ticketId: args.ticketId,
     }, _program.programId);
+    const [paymentPubkey] = pda.derivePaymentPDA({
+        user: args.user,
+        paymentId: args.paymentId,
+    }, _program.programId);
 
   return _program
     .methods
     .purchaseTicket(
       args.transportMode,
       new BN(args.ticketId.toString()),
-      new BN(args.amount.toString()),
+      new BN(args.maxPrice.toString()),
+      args.promoCode ?? null,
     )
     .accountsStrict({
       feePayer: args.feePayer,
       fareConfig: fareConfigPubkey,
       passenger: passengerPubkey,
       ticket: ticketPubkey,
+      payment: paymentPubkey,
       user: args.user,
       userTokenAccount: args.userTokenAccount,
       systemTokenAccount: args.systemTokenAccount,
@@ -299,34 +414,52 @@ export const purchaseTicketBuilder = (
       destination: args.destination,
       authority: args.authority,
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      employer: null,
+      employeeBenefit: null,
+      employerEscrow: null,
+      promotion: null,
+      promotionRedemption: null,
+      acceptedCurrency: null,
+      priceFeed: null,
+      rideHistory: null,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Purchase a ticket for either bus or train travel
+ * Purchase a transit ticket for any transport mode
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
- * 2. `[writable]` passenger: {@link Passenger} 
- * 3. `[writable]` ticket: {@link Ticket} 
- * 4. `[signer]` user: {@link PublicKey} User's wallet address
- * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 7. `[]` currency_mint: {@link Mint} Currency mint address
- * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 9. `[writable]` source: {@link PublicKey} The source account.
- * 10. `[]` mint: {@link Mint} The token mint.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger}
+ * 3. `[writable]` ticket: {@link Ticket}
+ * 4. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 5. `[signer]` user: {@link PublicKey} User's wallet address
+ * 6. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 7. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 8. `[]` currency_mint: {@link Mint} Currency mint address
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[writable]` source: {@link PublicKey} The source account.
+ * 11. `[]` mint: {@link Mint} The token mint.
+ * 12. `[writable]` destination: {@link PublicKey} The destination account.
+ * 13. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 14. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 15. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 16. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 17. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the fare
+ * 18. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 19. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 20. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 21. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
+ * 22. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - transport_mode: {@link number} Transport mode (0 = bus, 1 = train)
  * - ticket_id: {@link BigInt} Unique ticket identifier
- * - amount: {@link BigInt} Amount to pay for the ticket
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the ticket, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseTicket = (
 	args: PurchaseTicketArgs,
@@ -336,28 +469,38 @@ export const purchaseTicket = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Purchase a ticket for either bus or train travel
+ * Purchase a transit ticket for any transport mode
  *
  * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
- * 2. `[writable]` passenger: {@link Passenger} 
- * 3. `[writable]` ticket: {@link Ticket} 
- * 4. `[signer]` user: {@link PublicKey} User's wallet address
- * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 7. `[]` currency_mint: {@link Mint} Currency mint address
- * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 9. `[writable]` source: {@link PublicKey} The source account.
- * 10. `[]` mint: {@link Mint} The token mint.
- * 11. `[writable]` destination: {@link PublicKey} The destination account.
- * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+ * 1. `[writable]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger}
+ * 3. `[writable]` ticket: {@link Ticket}
+ * 4. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 5. `[signer]` user: {@link PublicKey} User's wallet address
+ * 6. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 7. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 8. `[]` currency_mint: {@link Mint} Currency mint address
+ * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 10. `[writable]` source: {@link PublicKey} The source account.
+ * 11. `[]` mint: {@link Mint} The token mint.
+ * 12. `[writable]` destination: {@link PublicKey} The destination account.
+ * 13. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 14. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 15. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 16. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 17. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the fare
+ * 18. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 19. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 20. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 21. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
+ * 22. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - transport_mode: {@link number} Transport mode (0 = bus, 1 = train)
  * - ticket_id: {@link BigInt} Unique ticket identifier
- * - amount: {@link BigInt} Amount to pay for the ticket
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the ticket, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseTicketSendAndConfirm = async (
   args: Omit<PurchaseTicketArgs, "feePayer" | "user" | "authority"> & {
@@ -387,6 +530,9 @@ export type UseTicketArgs = {
   feePayer: web3.PublicKey;
   user: web3.PublicKey;
   ticketId: bigint;
+  stationId: number;
+  // Append the ride to the rider's ride history, which must already exist
+  recordHistory?: boolean;
 };
 
 /**
@@ -395,30 +541,45 @@ export type UseTicketArgs = {
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` ticket: {@link Ticket} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` ticket: {@link Ticket} 
+ * 3. `[writable]` passenger: {@link Passenger}
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useTicketBuilder = (
 	args: UseTicketArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<TransitFarePayment, never> => {
-    const [ticketPubkey] = pda.deriveTicketPDA({
-        user: args.user,
-        ticketId: args.ticketId,
-    }, _program.programId);
+  const [fareConfigPubkey] = pda.deriveFareConfigPDA(_program.programId);
+  const [ticketPubkey] = pda.deriveTicketPDA({
+      user: args.user,
+      ticketId: args.ticketId,
+  }, _program.programId);
+  const [passengerPubkey] = pda.derivePassengerPDA({
+      user: args.user,
+  }, _program.programId);
+  const [rideHistoryPubkey] = pda.deriveRideHistoryPDA({
+      user: args.user,
+  }, _program.programId);
 
   return _program
     .methods
     .useTicket(
       new BN(args.ticketId.toString()),
+      args.stationId,
     )
     .accountsStrict({
       feePayer: args.feePayer,
+      fareConfig: fareConfigPubkey,
       ticket: ticketPubkey,
+      passenger: passengerPubkey,
       user: args.user,
+      rideHistory: args.recordHistory ? rideHistoryPubkey : null,
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -429,11 +590,15 @@ export const useTicketBuilder = (
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` ticket: {@link Ticket} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` ticket: {@link Ticket} 
+ * 3. `[writable]` passenger: {@link Passenger}
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useTicket = (
 	args: UseTicketArgs,
@@ -447,11 +612,15 @@ export const useTicket = (
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` ticket: {@link Ticket} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` ticket: {@link Ticket} 
+ * 3. `[writable]` passenger: {@link Passenger}
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useTicketSendAndConfirm = async (
   args: Omit<UseTicketArgs, "feePayer" | "user"> & {
@@ -485,6 +654,7 @@ export type RefundTicketArgs = {
   mint: web3.PublicKey;
   destination: web3.PublicKey;
   authority: web3.PublicKey;
+  rentPayer: web3.PublicKey;
   ticketId: bigint;
 };
 
@@ -506,6 +676,7 @@ export type RefundTicketArgs = {
  * 10. `[writable]` destination: {@link PublicKey} The destination account.
  * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 13. `[writable]` rent_payer: {@link PublicKey} Fee payer recorded on the ticket
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
@@ -542,6 +713,7 @@ export const refundTicketBuilder = (
       destination: args.destination,
       authority: args.authority,
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      rentPayer: args.rentPayer,
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -564,6 +736,7 @@ export const refundTicketBuilder = (
  * 10. `[writable]` destination: {@link PublicKey} The destination account.
  * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 13. `[writable]` rent_payer: {@link PublicKey} Fee payer recorded on the ticket
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
@@ -592,6 +765,7 @@ export const refundTicket = (
  * 10. `[writable]` destination: {@link PublicKey} The destination account.
  * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 13. `[writable]` rent_payer: {@link PublicKey} Fee payer recorded on the ticket
  *
  * Data:
  * - ticket_id: {@link BigInt} Ticket identifier
@@ -620,117 +794,6 @@ export const refundTicketSendAndConfirm = async (
     .rpc();
 }
 
-export type RecordPaymentArgs = {
-  feePayer: web3.PublicKey;
-  user: web3.PublicKey;
-  paymentId: bigint;
-  amount: bigint;
-  currencyMint: web3.PublicKey;
-  transactionHash: string;
-};
-
-/**
- * ### Returns a {@link MethodsBuilder}
- * Record a payment transaction
- *
- * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` payment: {@link Payment} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
- * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- *
- * Data:
- * - payment_id: {@link BigInt} Unique payment identifier
- * - amount: {@link BigInt} Payment amount
- * - currency_mint: {@link PublicKey} Currency mint address
- * - transaction_hash: {@link string} Transaction hash for reference
- */
-export const recordPaymentBuilder = (
-	args: RecordPaymentArgs,
-	remainingAccounts: Array<web3.AccountMeta> = [],
-): MethodsBuilder<TransitFarePayment, never> => {
-    const [paymentPubkey] = pda.derivePaymentPDA({
-        user: args.user,
-        paymentId: args.paymentId,
-    }, _program.programId);
-
-  return _program
-    .methods
-    .recordPayment(
-      new BN(args.paymentId.toString()),
-      new BN(args.amount.toString()),
-      args.currencyMint,
-      args.transactionHash,
-    )
-    .accountsStrict({
-      feePayer: args.feePayer,
-      payment: paymentPubkey,
-      user: args.user,
-      systemProgram: new web3.PublicKey("11111111111111111111111111111111"),
-    })
-    .remainingAccounts(remainingAccounts);
-};
-
-/**
- * ### Returns a {@link web3.TransactionInstruction}
- * Record a payment transaction
- *
- * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` payment: {@link Payment} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
- * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- *
- * Data:
- * - payment_id: {@link BigInt} Unique payment identifier
- * - amount: {@link BigInt} Payment amount
- * - currency_mint: {@link PublicKey} Currency mint address
- * - transaction_hash: {@link string} Transaction hash for reference
- */
-export const recordPayment = (
-	args: RecordPaymentArgs,
-	remainingAccounts: Array<web3.AccountMeta> = [],
-): Promise<web3.TransactionInstruction> =>
-    recordPaymentBuilder(args, remainingAccounts).instruction();
-
-/**
- * ### Returns a {@link web3.TransactionSignature}
- * Record a payment transaction
- *
- * Accounts:
- * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
- * 1. `[writable]` payment: {@link Payment} 
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
- * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- *
- * Data:
- * - payment_id: {@link BigInt} Unique payment identifier
- * - amount: {@link BigInt} Payment amount
- * - currency_mint: {@link PublicKey} Currency mint address
- * - transaction_hash: {@link string} Transaction hash for reference
- */
-export const recordPaymentSendAndConfirm = async (
-  args: Omit<RecordPaymentArgs, "feePayer" | "user"> & {
-    signers: {
-      feePayer: web3.Signer,
-      user: web3.Signer,
-    },
-  },
-  remainingAccounts: Array<web3.AccountMeta> = [],
-): Promise<web3.TransactionSignature> => {
-  const preInstructions: Array<web3.TransactionInstruction> = [];
-
-
-  return recordPaymentBuilder({
-      ...args,
-      feePayer: args.signers.feePayer.publicKey,
-      user: args.signers.user.publicKey,
-    }, remainingAccounts)
-    .preInstructions(preInstructions)
-    .signers([args.signers.feePayer, args.signers.user])
-    .rpc();
-}
-
 export type PurchaseSubscriptionArgs = {
   feePayer: web3.PublicKey;
   user: web3.PublicKey;
@@ -742,29 +805,45 @@ export type PurchaseSubscriptionArgs = {
   destination: web3.PublicKey;
   authority: web3.PublicKey;
   subscriptionType: number;
+  seatCount: number;
+  // Next receipt id, the passenger's paymentCount or the fare config's totalReceipts for a new rider
+  paymentId: bigint;
+  maxPrice: bigint;
+  promoCode?: string;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Purchase a subscription pass (monthly or yearly)
+ * Purchase a subscription pass for transit travel
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
  * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
  * 2. `[writable]` passenger: {@link Passenger}
- * 3. `[signer]` user: {@link PublicKey} User's wallet address
- * 4. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 5. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 6. `[]` currency_mint: {@link Mint} Currency mint address
- * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 8. `[writable]` source: {@link PublicKey} The source account.
- * 9. `[]` mint: {@link Mint} The token mint.
- * 10. `[writable]` destination: {@link PublicKey} The destination account.
- * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 3. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 7. `[]` currency_mint: {@link Mint} Currency mint address
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[writable]` source: {@link PublicKey} The source account.
+ * 10. `[]` mint: {@link Mint} The token mint.
+ * 11. `[writable]` destination: {@link PublicKey} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 14. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 15. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 16. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the pass
+ * 17. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 18. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 19. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 20. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
  *
  * Data:
- * - subscription_type: {@link number} Subscription type (1 = monthly, 2 = yearly)
+ * - subscription_type: {@link number} Subscription type (1=monthly, 2=yearly)
+ * - seat_count: {@link number} Riders covered by the pass (1 = individual, more = household)
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the pass, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseSubscriptionBuilder = (
 	args: PurchaseSubscriptionArgs,
@@ -774,16 +853,24 @@ export const purchaseSubscriptionBuilder = (
   const [passengerPubkey] = pda.derivePassengerPDA({
       user: args.user,
   }, _program.programId);
+  const [paymentPubkey] = pda.derivePaymentPDA({
+      user: args.user,
+      paymentId: args.paymentId,
+  }, _program.programId);
 
   return _program
     .methods
     .purchaseSubscription(
       args.subscriptionType,
+      args.seatCount,
+      new BN(args.maxPrice.toString()),
+      args.promoCode ?? null,
     )
     .accountsStrict({
       feePayer: args.feePayer,
       fareConfig: fareConfigPubkey,
       passenger: passengerPubkey,
+      payment: paymentPubkey,
       user: args.user,
       userTokenAccount: args.userTokenAccount,
       systemTokenAccount: args.systemTokenAccount,
@@ -794,31 +881,49 @@ export const purchaseSubscriptionBuilder = (
       destination: args.destination,
       authority: args.authority,
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      employer: null,
+      employeeBenefit: null,
+      employerEscrow: null,
+      promotion: null,
+      promotionRedemption: null,
+      acceptedCurrency: null,
+      priceFeed: null,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Purchase a subscription pass (monthly or yearly)
+ * Purchase a subscription pass for transit travel
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
  * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
  * 2. `[writable]` passenger: {@link Passenger}
- * 3. `[signer]` user: {@link PublicKey} User's wallet address
- * 4. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 5. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 6. `[]` currency_mint: {@link Mint} Currency mint address
- * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 8. `[writable]` source: {@link PublicKey} The source account.
- * 9. `[]` mint: {@link Mint} The token mint.
- * 10. `[writable]` destination: {@link PublicKey} The destination account.
- * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 3. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 7. `[]` currency_mint: {@link Mint} Currency mint address
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[writable]` source: {@link PublicKey} The source account.
+ * 10. `[]` mint: {@link Mint} The token mint.
+ * 11. `[writable]` destination: {@link PublicKey} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 14. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 15. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 16. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the pass
+ * 17. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 18. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 19. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 20. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
  *
  * Data:
- * - subscription_type: {@link number} Subscription type (1 = monthly, 2 = yearly)
+ * - subscription_type: {@link number} Subscription type (1=monthly, 2=yearly)
+ * - seat_count: {@link number} Riders covered by the pass (1 = individual, more = household)
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the pass, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseSubscription = (
 	args: PurchaseSubscriptionArgs,
@@ -828,25 +933,36 @@ export const purchaseSubscription = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Purchase a subscription pass (monthly or yearly)
+ * Purchase a subscription pass for transit travel
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
  * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
  * 2. `[writable]` passenger: {@link Passenger}
- * 3. `[signer]` user: {@link PublicKey} User's wallet address
- * 4. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
- * 5. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
- * 6. `[]` currency_mint: {@link Mint} Currency mint address
- * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
- * 8. `[writable]` source: {@link PublicKey} The source account.
- * 9. `[]` mint: {@link Mint} The token mint.
- * 10. `[writable]` destination: {@link PublicKey} The destination account.
- * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
- * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 3. `[writable]` payment: {@link Payment} Receipt for this purchase
+ * 4. `[signer]` user: {@link PublicKey} User's wallet address
+ * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+ * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+ * 7. `[]` currency_mint: {@link Mint} Currency mint address
+ * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+ * 9. `[writable]` source: {@link PublicKey} The source account.
+ * 10. `[]` mint: {@link Mint} The token mint.
+ * 11. `[writable]` destination: {@link PublicKey} The destination account.
+ * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+ * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+ * 14. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+ * 15. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+ * 16. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the pass
+ * 17. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+ * 18. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+ * 19. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+ * 20. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
  *
  * Data:
- * - subscription_type: {@link number} Subscription type (1 = monthly, 2 = yearly)
+ * - subscription_type: {@link number} Subscription type (1=monthly, 2=yearly)
+ * - seat_count: {@link number} Riders covered by the pass (1 = individual, more = household)
+ * - max_price: {@link BigInt} Most the rider agrees to pay for the pass, in the payment mint
+ * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
  */
 export const purchaseSubscriptionSendAndConfirm = async (
   args: Omit<PurchaseSubscriptionArgs, "feePayer" | "user" | "authority"> & {
@@ -860,6 +976,7 @@ export const purchaseSubscriptionSendAndConfirm = async (
 ): Promise<web3.TransactionSignature> => {
   const preInstructions: Array<web3.TransactionInstruction> = [];
 
+
   return purchaseSubscriptionBuilder({
       ...args,
       feePayer: args.signers.feePayer.publicKey,
@@ -874,50 +991,84 @@ export const purchaseSubscriptionSendAndConfirm = async (
 export type UseSubscriptionRideArgs = {
   feePayer: web3.PublicKey;
   user: web3.PublicKey;
+  // Pass holder, when the rider is a member of their household
+  holder?: web3.PublicKey;
+  transportMode: number;
+  stationId: number;
+  // Append the ride to the rider's ride history, which must already exist
+  recordHistory?: boolean;
 };
 
 /**
  * ### Returns a {@link MethodsBuilder}
- * Use a subscription ride (requires active subscription)
+ * Use a subscription ride for transit travel
+ *
+ * The rider is either the subscription holder or a member of the holder's
+ * household. Ride caps apply to each rider separately.
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
- * 1. `[writable]` passenger: {@link Passenger}
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger} Passenger holding the subscription
+ * 3. `[signer]` user: {@link PublicKey} Rider's wallet address (holder or household member)
+ * 4. `[writable, optional]` household: {@link Household} Required when the rider is a household member
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} Rider's ride history to append to
  *
  * Data:
- * (none)
+ * - transport_mode: {@link number} Transport mode being boarded (0 = bus, 1 = train)
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useSubscriptionRideBuilder = (
 	args: UseSubscriptionRideArgs,
 	remainingAccounts: Array<web3.AccountMeta> = [],
 ): MethodsBuilder<TransitFarePayment, never> => {
+  const holder = args.holder ?? args.user;
+  const [fareConfigPubkey] = pda.deriveFareConfigPDA(_program.programId);
   const [passengerPubkey] = pda.derivePassengerPDA({
+      user: holder,
+  }, _program.programId);
+  const [householdPubkey] = pda.deriveHouseholdPDA({
+      holder,
+  }, _program.programId);
+  const [rideHistoryPubkey] = pda.deriveRideHistoryPDA({
       user: args.user,
   }, _program.programId);
 
   return _program
     .methods
-    .useSubscriptionRide()
+    .useSubscriptionRide(
+      args.transportMode,
+      args.stationId,
+    )
     .accountsStrict({
       feePayer: args.feePayer,
+      fareConfig: fareConfigPubkey,
       passenger: passengerPubkey,
       user: args.user,
+      household: holder.equals(args.user) ? null : householdPubkey,
+      rideHistory: args.recordHistory ? rideHistoryPubkey : null,
     })
     .remainingAccounts(remainingAccounts);
 };
 
 /**
  * ### Returns a {@link web3.TransactionInstruction}
- * Use a subscription ride (requires active subscription)
+ * Use a subscription ride for transit travel
+ *
+ * The rider is either the subscription holder or a member of the holder's
+ * household. Ride caps apply to each rider separately.
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
- * 1. `[writable]` passenger: {@link Passenger}
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger} Passenger holding the subscription
+ * 3. `[signer]` user: {@link PublicKey} Rider's wallet address (holder or household member)
+ * 4. `[writable, optional]` household: {@link Household} Required when the rider is a household member
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} Rider's ride history to append to
  *
  * Data:
- * (none)
+ * - transport_mode: {@link number} Transport mode being boarded (0 = bus, 1 = train)
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useSubscriptionRide = (
 	args: UseSubscriptionRideArgs,
@@ -927,15 +1078,22 @@ export const useSubscriptionRide = (
 
 /**
  * ### Returns a {@link web3.TransactionSignature}
- * Use a subscription ride (requires active subscription)
+ * Use a subscription ride for transit travel
+ *
+ * The rider is either the subscription holder or a member of the holder's
+ * household. Ride caps apply to each rider separately.
  *
  * Accounts:
  * 0. `[writable, signer]` fee_payer: {@link PublicKey}
- * 1. `[writable]` passenger: {@link Passenger}
- * 2. `[signer]` user: {@link PublicKey} User's wallet address
+ * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+ * 2. `[writable]` passenger: {@link Passenger} Passenger holding the subscription
+ * 3. `[signer]` user: {@link PublicKey} Rider's wallet address (holder or household member)
+ * 4. `[writable, optional]` household: {@link Household} Required when the rider is a household member
+ * 5. `[writable, optional]` ride_history: {@link RideHistory} Rider's ride history to append to
  *
  * Data:
- * (none)
+ * - transport_mode: {@link number} Transport mode being boarded (0 = bus, 1 = train)
+ * - station_id: {@link number} Boarding station identifier
  */
 export const useSubscriptionRideSendAndConfirm = async (
   args: Omit<UseSubscriptionRideArgs, "feePayer" | "user"> & {
@@ -987,6 +1145,8 @@ export type CancelSubscriptionArgs = {
  * 9. `[writable]` destination: {@link PublicKey} The destination account.
  * 10. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 11. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+
+ * 12. `[optional]` accepted_currency: {@link AcceptedCurrency} Required when the pass was paid in another accepted currency
  *
  * Data:
  * (none)
@@ -1016,6 +1176,7 @@ export const cancelSubscriptionBuilder = (
       destination: args.destination,
       authority: args.authority,
       tokenProgram: new web3.PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+      acceptedCurrency: null,
     })
     .remainingAccounts(remainingAccounts);
 };
@@ -1037,6 +1198,8 @@ export const cancelSubscriptionBuilder = (
  * 9. `[writable]` destination: {@link PublicKey} The destination account.
  * 10. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 11. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+
+ * 12. `[optional]` accepted_currency: {@link AcceptedCurrency} Required when the pass was paid in another accepted currency
  *
  * Data:
  * (none)
@@ -1064,6 +1227,8 @@ export const cancelSubscription = (
  * 9. `[writable]` destination: {@link PublicKey} The destination account.
  * 10. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
  * 11. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+
+ * 12. `[optional]` accepted_currency: {@link AcceptedCurrency} Required when the pass was paid in another accepted currency
  *
  * Data:
  * (none)
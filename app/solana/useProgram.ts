@@ -134,95 +134,155 @@ const useProgram = (props?: UseProgramProps | undefined) => {
   ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.initializeFareConfigSendAndConfirm(args, remainingAccounts)), [])
 
   /**
-   * Update fare configuration settings
+   * Queue new fares and pass prices to take effect at a later time
    *
    * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` fare_config: {@link FareConfig} 
-   * 2. `[signer]` admin: {@link PublicKey} Administrator account
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig}
+   * 2. `[]` roles: {@link Roles} Role holders
+   * 3. `[signer]` fare_manager: {@link PublicKey} Fare manager role holder
    *
    * Data:
-   * - bus_fare: {@link BigInt | undefined} New bus fare amount (optional)
-   * - train_fare: {@link BigInt | undefined} New train fare amount (optional)
+   * - mode_0_fare: {@link BigInt | undefined} New bus fare amount (transport mode 0 = bus, optional)
+   * - mode_1_fare: {@link BigInt | undefined} New train fare amount (transport mode 1 = train, optional)
+   * - monthly_pass_price: {@link BigInt | undefined} New monthly subscription price (optional)
+   * - yearly_pass_price: {@link BigInt | undefined} New yearly subscription price (optional)
+   * - effective_at: {@link BigInt} Timestamp the new fares take effect, at least min_fare_notice from now
    *
    * @returns {@link TransactionInstruction}
    */
-  const updateFareConfig = useCallback(programClient.updateFareConfig, [])
+  const scheduleFareChange = useCallback(programClient.scheduleFareChange, [])
 
   /**
-   * Update fare configuration settings
+   * Queue new fares and pass prices to take effect at a later time
    *
    * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` fare_config: {@link FareConfig} 
-   * 2. `[signer]` admin: {@link PublicKey} Administrator account
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig}
+   * 2. `[]` roles: {@link Roles} Role holders
+   * 3. `[signer]` fare_manager: {@link PublicKey} Fare manager role holder
    *
    * Data:
-   * - bus_fare: {@link BigInt | undefined} New bus fare amount (optional)
-   * - train_fare: {@link BigInt | undefined} New train fare amount (optional)
+   * - mode_0_fare: {@link BigInt | undefined} New bus fare amount (transport mode 0 = bus, optional)
+   * - mode_1_fare: {@link BigInt | undefined} New train fare amount (transport mode 1 = train, optional)
+   * - monthly_pass_price: {@link BigInt | undefined} New monthly subscription price (optional)
+   * - yearly_pass_price: {@link BigInt | undefined} New yearly subscription price (optional)
+   * - effective_at: {@link BigInt} Timestamp the new fares take effect, at least min_fare_notice from now
    *
    * @returns {@link SendAndConfirmTxResult}
    */
-  const updateFareConfigSendAndConfirm = useCallback(async (
-    args: Omit<programClient.UpdateFareConfigArgs, "feePayer" | "admin"> & {
+  const scheduleFareChangeSendAndConfirm = useCallback(async (
+    args: Omit<programClient.ScheduleFareChangeArgs, "feePayer" | "fareManager"> & {
     signers: {
         feePayer: Keypair,
-        admin: Keypair,
+        fareManager: Keypair,
     }}, 
     remainingAccounts: Array<AccountMeta> = []
-  ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.updateFareConfigSendAndConfirm(args, remainingAccounts)), [])
+  ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.scheduleFareChangeSendAndConfirm(args, remainingAccounts)), [])
 
   /**
-   * Purchase a ticket for either bus or train travel
+   * Write a due fare or price feed change into the live settings
    *
    * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
-   * 2. `[writable]` passenger: {@link Passenger} 
-   * 3. `[writable]` ticket: {@link Ticket} 
-   * 4. `[signer]` user: {@link PublicKey} User's wallet address
-   * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
-   * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
-   * 7. `[]` currency_mint: {@link Mint} Currency mint address
-   * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   * 9. `[writable]` source: {@link PublicKey} The source account.
-   * 10. `[]` mint: {@link Mint} The token mint.
-   * 11. `[writable]` destination: {@link PublicKey} The destination account.
-   * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
-   * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig}
+   *
+   * Data: None
+   *
+   * @returns {@link TransactionInstruction}
+   */
+  const applyFareChange = useCallback(programClient.applyFareChange, [])
+
+  /**
+   * Write a due fare or price feed change into the live settings
+   *
+   * Accounts:
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig}
+   *
+   * Data: None
+   *
+   * @returns {@link SendAndConfirmTxResult}
+   */
+  const applyFareChangeSendAndConfirm = useCallback(async (
+    args: Omit<programClient.ApplyFareChangeArgs, "feePayer"> & {
+    signers: {
+        feePayer: Keypair,
+    }}, 
+    remainingAccounts: Array<AccountMeta> = []
+  ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.applyFareChangeSendAndConfirm(args, remainingAccounts)), [])
+
+  /**
+   * Purchase a transit ticket for any transport mode
+   *
+   * Accounts:
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` passenger: {@link Passenger}
+   * 3. `[writable]` ticket: {@link Ticket}
+   * 4. `[writable]` payment: {@link Payment} Receipt for this purchase
+   * 5. `[signer]` user: {@link PublicKey} User's wallet address
+   * 6. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+   * 7. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+   * 8. `[]` currency_mint: {@link Mint} Currency mint address
+   * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+   * 10. `[writable]` source: {@link PublicKey} The source account.
+   * 11. `[]` mint: {@link Mint} The token mint.
+   * 12. `[writable]` destination: {@link PublicKey} The destination account.
+   * 13. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+   * 14. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 15. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+   * 16. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+   * 17. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the fare
+   * 18. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+   * 19. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+   * 20. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+   * 21. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
+   * 22. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
    *
    * Data:
    * - transport_mode: {@link number} Transport mode (0 = bus, 1 = train)
    * - ticket_id: {@link BigInt} Unique ticket identifier
-   * - amount: {@link BigInt} Amount to pay for the ticket
+   * - max_price: {@link BigInt} Most the rider agrees to pay for the ticket, in the payment mint
+   * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
    *
    * @returns {@link TransactionInstruction}
    */
   const purchaseTicket = useCallback(programClient.purchaseTicket, [])
 
   /**
-   * Purchase a ticket for either bus or train travel
+   * Purchase a transit ticket for any transport mode
    *
    * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
-   * 2. `[writable]` passenger: {@link Passenger} 
-   * 3. `[writable]` ticket: {@link Ticket} 
-   * 4. `[signer]` user: {@link PublicKey} User's wallet address
-   * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
-   * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
-   * 7. `[]` currency_mint: {@link Mint} Currency mint address
-   * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   * 9. `[writable]` source: {@link PublicKey} The source account.
-   * 10. `[]` mint: {@link Mint} The token mint.
-   * 11. `[writable]` destination: {@link PublicKey} The destination account.
-   * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
-   * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 0. `[writable, signer]` fee_payer: {@link PublicKey}
+   * 1. `[writable]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` passenger: {@link Passenger}
+   * 3. `[writable]` ticket: {@link Ticket}
+   * 4. `[writable]` payment: {@link Payment} Receipt for this purchase
+   * 5. `[signer]` user: {@link PublicKey} User's wallet address
+   * 6. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+   * 7. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+   * 8. `[]` currency_mint: {@link Mint} Currency mint address
+   * 9. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+   * 10. `[writable]` source: {@link PublicKey} The source account.
+   * 11. `[]` mint: {@link Mint} The token mint.
+   * 12. `[writable]` destination: {@link PublicKey} The destination account.
+   * 13. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+   * 14. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 15. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+   * 16. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+   * 17. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the fare
+   * 18. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+   * 19. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+   * 20. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+   * 21. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
+   * 22. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
    *
    * Data:
    * - transport_mode: {@link number} Transport mode (0 = bus, 1 = train)
    * - ticket_id: {@link BigInt} Unique ticket identifier
-   * - amount: {@link BigInt} Amount to pay for the ticket
+   * - max_price: {@link BigInt} Most the rider agrees to pay for the ticket, in the payment mint
+   * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
    *
    * @returns {@link SendAndConfirmTxResult}
    */
@@ -241,11 +301,15 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` ticket: {@link Ticket} 
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
+   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` ticket: {@link Ticket} 
+   * 3. `[writable]` passenger: {@link Passenger}
+   * 4. `[signer]` user: {@link PublicKey} User's wallet address
+   * 5. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
    *
    * Data:
    * - ticket_id: {@link BigInt} Ticket identifier
+   * - station_id: {@link number} Boarding station identifier
    *
    * @returns {@link TransactionInstruction}
    */
@@ -256,11 +320,15 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` ticket: {@link Ticket} 
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
+   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` ticket: {@link Ticket} 
+   * 3. `[writable]` passenger: {@link Passenger}
+   * 4. `[signer]` user: {@link PublicKey} User's wallet address
+   * 5. `[writable, optional]` ride_history: {@link RideHistory} User's ride history to append to
    *
    * Data:
    * - ticket_id: {@link BigInt} Ticket identifier
+   * - station_id: {@link number} Boarding station identifier
    *
    * @returns {@link SendAndConfirmTxResult}
    */
@@ -290,6 +358,7 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    * 10. `[writable]` destination: {@link PublicKey} The destination account.
    * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
    * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 13. `[writable]` rent_payer: {@link PublicKey} Fee payer recorded on the ticket
    *
    * Data:
    * - ticket_id: {@link BigInt} Ticket identifier
@@ -315,6 +384,7 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    * 10. `[writable]` destination: {@link PublicKey} The destination account.
    * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
    * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 13. `[writable]` rent_payer: {@link PublicKey} Fee payer recorded on the ticket
    *
    * Data:
    * - ticket_id: {@link BigInt} Ticket identifier
@@ -332,96 +402,72 @@ const useProgram = (props?: UseProgramProps | undefined) => {
   ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.refundTicketSendAndConfirm(args, remainingAccounts)), [])
 
   /**
-   * Record a payment transaction
-   *
-   * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` payment: {@link Payment} 
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
-   * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   *
-   * Data:
-   * - payment_id: {@link BigInt} Unique payment identifier
-   * - amount: {@link BigInt} Payment amount
-   * - currency_mint: {@link PublicKey} Currency mint address
-   * - transaction_hash: {@link string} Transaction hash for reference
-   *
-   * @returns {@link TransactionInstruction}
-   */
-  const recordPayment = useCallback(programClient.recordPayment, [])
-
-  /**
-   * Record a payment transaction
-   *
-   * Accounts:
-   * 0. `[writable, signer]` fee_payer: {@link PublicKey} 
-   * 1. `[writable]` payment: {@link Payment} 
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
-   * 3. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   *
-   * Data:
-   * - payment_id: {@link BigInt} Unique payment identifier
-   * - amount: {@link BigInt} Payment amount
-   * - currency_mint: {@link PublicKey} Currency mint address
-   * - transaction_hash: {@link string} Transaction hash for reference
-   *
-   * @returns {@link SendAndConfirmTxResult}
-   */
-  const recordPaymentSendAndConfirm = useCallback(async (
-    args: Omit<programClient.RecordPaymentArgs, "feePayer" | "user"> & {
-    signers: {
-        feePayer: Keypair,
-        user: Keypair,
-    }}, 
-    remainingAccounts: Array<AccountMeta> = []
-  ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.recordPaymentSendAndConfirm(args, remainingAccounts)), [])
-
-
-  /**
-   * Purchase a subscription pass (monthly or yearly)
+   * Purchase a subscription pass for transit travel
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey}
    * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
    * 2. `[writable]` passenger: {@link Passenger}
-   * 3. `[signer]` user: {@link PublicKey} User's wallet address
-   * 4. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
-   * 5. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
-   * 6. `[]` currency_mint: {@link Mint} Currency mint address
-   * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   * 8. `[writable]` source: {@link PublicKey} The source account.
-   * 9. `[]` mint: {@link Mint} The token mint.
-   * 10. `[writable]` destination: {@link PublicKey} The destination account.
-   * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
-   * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 3. `[writable]` payment: {@link Payment} Receipt for this purchase
+   * 4. `[signer]` user: {@link PublicKey} User's wallet address
+   * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+   * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+   * 7. `[]` currency_mint: {@link Mint} Currency mint address
+   * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+   * 9. `[writable]` source: {@link PublicKey} The source account.
+   * 10. `[]` mint: {@link Mint} The token mint.
+   * 11. `[writable]` destination: {@link PublicKey} The destination account.
+   * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+   * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 14. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+   * 15. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+   * 16. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the pass
+   * 17. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+   * 18. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+   * 19. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+   * 20. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
    *
    * Data:
-   * - subscription_type: {@link number} Subscription type (1 = monthly, 2 = yearly)
+   * - subscription_type: {@link number} Subscription type (1=monthly, 2=yearly)
+   * - seat_count: {@link number} Riders covered by the pass (1 = individual, more = household)
+   * - max_price: {@link BigInt} Most the rider agrees to pay for the pass, in the payment mint
+   * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
    *
    * @returns {@link TransactionInstruction}
    */
   const purchaseSubscription = useCallback(programClient.purchaseSubscription, [])
 
   /**
-   * Purchase a subscription pass (monthly or yearly)
+   * Purchase a subscription pass for transit travel
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey}
    * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
    * 2. `[writable]` passenger: {@link Passenger}
-   * 3. `[signer]` user: {@link PublicKey} User's wallet address
-   * 4. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
-   * 5. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
-   * 6. `[]` currency_mint: {@link Mint} Currency mint address
-   * 7. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
-   * 8. `[writable]` source: {@link PublicKey} The source account.
-   * 9. `[]` mint: {@link Mint} The token mint.
-   * 10. `[writable]` destination: {@link PublicKey} The destination account.
-   * 11. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
-   * 12. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 3. `[writable]` payment: {@link Payment} Receipt for this purchase
+   * 4. `[signer]` user: {@link PublicKey} User's wallet address
+   * 5. `[writable]` user_token_account: {@link PublicKey} User's token account for payment
+   * 6. `[writable]` system_token_account: {@link PublicKey} System's token account for receiving payment
+   * 7. `[]` currency_mint: {@link Mint} Currency mint address
+   * 8. `[]` system_program: {@link PublicKey} Auto-generated, for account initialization
+   * 9. `[writable]` source: {@link PublicKey} The source account.
+   * 10. `[]` mint: {@link Mint} The token mint.
+   * 11. `[writable]` destination: {@link PublicKey} The destination account.
+   * 12. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
+   * 13. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 14. `[optional]` employer: {@link Employer} Employer sponsoring the purchase
+   * 15. `[writable, optional]` employee_benefit: {@link EmployeeBenefit} User's enrollment with the employer
+   * 16. `[writable, optional]` employer_escrow: {@link TokenAccount} Employer escrow paying the pass
+   * 17. `[writable, optional]` promotion: {@link Promotion} Promotion to redeem
+   * 18. `[writable, optional]` promotion_redemption: {@link PromotionRedemption} User's redemptions of the promotion
+   * 19. `[optional]` accepted_currency: {@link AcceptedCurrency} Registry entry when paying in another mint
+   * 20. `[optional]` price_feed: {@link PublicKey} Oracle price account, required while fares are in fiat
    *
    * Data:
-   * - subscription_type: {@link number} Subscription type (1 = monthly, 2 = yearly)
+   * - subscription_type: {@link number} Subscription type (1=monthly, 2=yearly)
+   * - seat_count: {@link number} Riders covered by the pass (1 = individual, more = household)
+   * - max_price: {@link BigInt} Most the rider agrees to pay for the pass, in the payment mint
+   * - promo_code: {@link string | undefined} Promo code for the promotion (optional)
    *
    * @returns {@link SendAndConfirmTxResult}
    */
@@ -436,30 +482,44 @@ const useProgram = (props?: UseProgramProps | undefined) => {
   ): Promise<SendAndConfirmTxResult> => sendAndConfirmTx(() => programClient.purchaseSubscriptionSendAndConfirm(args, remainingAccounts)), [])
 
   /**
-   * Use a subscription ride (requires active subscription)
+   * Use a subscription ride for transit travel
+   *
+   * The rider is either the subscription holder or a member of the holder's
+   * household. Ride caps apply to each rider separately.
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey}
-   * 1. `[writable]` passenger: {@link Passenger}
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
+   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` passenger: {@link Passenger} Passenger holding the subscription
+   * 3. `[signer]` user: {@link PublicKey} Rider's wallet address (holder or household member)
+   * 4. `[writable, optional]` household: {@link Household} Required when the rider is a household member
+   * 5. `[writable, optional]` ride_history: {@link RideHistory} Rider's ride history to append to
    *
    * Data:
-   * (none)
+   * - transport_mode: {@link number} Transport mode being boarded (0 = bus, 1 = train)
+   * - station_id: {@link number} Boarding station identifier
    *
    * @returns {@link TransactionInstruction}
    */
   const useSubscriptionRide = useCallback(programClient.useSubscriptionRide, [])
 
   /**
-   * Use a subscription ride (requires active subscription)
+   * Use a subscription ride for transit travel
+   *
+   * The rider is either the subscription holder or a member of the holder's
+   * household. Ride caps apply to each rider separately.
    *
    * Accounts:
    * 0. `[writable, signer]` fee_payer: {@link PublicKey}
-   * 1. `[writable]` passenger: {@link Passenger}
-   * 2. `[signer]` user: {@link PublicKey} User's wallet address
+   * 1. `[]` fare_config: {@link FareConfig} Fare configuration account
+   * 2. `[writable]` passenger: {@link Passenger} Passenger holding the subscription
+   * 3. `[signer]` user: {@link PublicKey} Rider's wallet address (holder or household member)
+   * 4. `[writable, optional]` household: {@link Household} Required when the rider is a household member
+   * 5. `[writable, optional]` ride_history: {@link RideHistory} Rider's ride history to append to
    *
    * Data:
-   * (none)
+   * - transport_mode: {@link number} Transport mode being boarded (0 = bus, 1 = train)
+   * - station_id: {@link number} Boarding station identifier
    *
    * @returns {@link SendAndConfirmTxResult}
    */
@@ -488,6 +548,7 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    * 9. `[writable]` destination: {@link PublicKey} The destination account.
    * 10. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
    * 11. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 12. `[optional]` accepted_currency: {@link AcceptedCurrency} Required when the pass was paid in another accepted currency
    *
    * Data:
    * (none)
@@ -512,6 +573,7 @@ const useProgram = (props?: UseProgramProps | undefined) => {
    * 9. `[writable]` destination: {@link PublicKey} The destination account.
    * 10. `[signer]` authority: {@link PublicKey} The source account's owner/delegate.
    * 11. `[]` token_program: {@link PublicKey} Auto-generated, TokenProgram
+   * 12. `[optional]` accepted_currency: {@link AcceptedCurrency} Required when the pass was paid in another accepted currency
    *
    * Data:
    * (none)
@@ -544,16 +606,16 @@ const useProgram = (props?: UseProgramProps | undefined) => {
 	programId,
     initializeFareConfig,
     initializeFareConfigSendAndConfirm,
-    updateFareConfig,
-    updateFareConfigSendAndConfirm,
+    scheduleFareChange,
+    scheduleFareChangeSendAndConfirm,
+    applyFareChange,
+    applyFareChangeSendAndConfirm,
     purchaseTicket,
     purchaseTicketSendAndConfirm,
     useTicket,
     useTicketSendAndConfirm,
     refundTicket,
     refundTicketSendAndConfirm,
-    recordPayment,
-    recordPaymentSendAndConfirm,
     purchaseSubscription,
     purchaseSubscriptionSendAndConfirm,
     useSubscriptionRide,
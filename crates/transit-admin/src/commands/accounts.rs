@@ -0,0 +1,100 @@
+//! Inspecting passengers, tickets and payment receipts
+
+use clap::Subcommand;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+use transit_client::{pda, Passenger};
+
+use crate::context::Context;
+use crate::{output, CliResult};
+
+#[derive(Subcommand)]
+pub enum PassengersCommand {
+    /// List every passenger account
+    List,
+    /// Show the passenger account of a rider
+    Show { user: Pubkey },
+}
+
+#[derive(Subcommand)]
+pub enum TicketsCommand {
+    /// List tickets, of every rider or only of --user
+    List {
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+    /// Show one ticket of a rider
+    Show { user: Pubkey, ticket_id: u64 },
+}
+
+#[derive(Subcommand)]
+pub enum PaymentsCommand {
+    /// List payment receipts, of every rider or only of --user
+    List {
+        #[arg(long)]
+        user: Option<Pubkey>,
+    },
+    /// Show one payment receipt of a rider
+    Show { user: Pubkey, payment_id: u64 },
+}
+
+pub fn run_passengers(ctx: &Context, command: PassengersCommand) -> CliResult<()> {
+    match command {
+        PassengersCommand::List => {
+            let mut passengers = ctx.client.all::<Passenger>()?;
+            passengers.sort_by_key(|(_, passenger)| passenger.user);
+            let rows = passengers
+                .iter()
+                .map(|(address, passenger)| output::passenger(address, passenger));
+            ctx.output.print(&Value::Array(rows.collect()));
+        }
+        PassengersCommand::Show { user } => {
+            let passenger = ctx.client.passenger(&user)?;
+            ctx.output
+                .print(&output::passenger(&pda::passenger(&user).0, &passenger));
+        }
+    }
+    Ok(())
+}
+
+pub fn run_tickets(ctx: &Context, command: TicketsCommand) -> CliResult<()> {
+    match command {
+        TicketsCommand::List { user } => {
+            let mut tickets = match user {
+                Some(user) => ctx.client.tickets_of(&user)?,
+                None => ctx.client.all()?,
+            };
+            tickets.sort_by_key(|(_, ticket)| (ticket.purchase_timestamp, ticket.user, ticket.ticket_id));
+            let rows = tickets.iter().map(|(address, ticket)| output::ticket(address, ticket));
+            ctx.output.print(&Value::Array(rows.collect()));
+        }
+        TicketsCommand::Show { user, ticket_id } => {
+            let ticket = ctx.client.ticket(&user, ticket_id)?;
+            ctx.output
+                .print(&output::ticket(&pda::ticket(&user, ticket_id).0, &ticket));
+        }
+    }
+    Ok(())
+}
+
+pub fn run_payments(ctx: &Context, command: PaymentsCommand) -> CliResult<()> {
+    match command {
+        PaymentsCommand::List { user } => {
+            let mut payments = match user {
+                Some(user) => ctx.client.payments_of(&user)?,
+                None => ctx.client.all()?,
+            };
+            payments.sort_by_key(|(_, payment)| (payment.payment_timestamp, payment.user, payment.payment_id));
+            let rows = payments
+                .iter()
+                .map(|(address, payment)| output::payment(address, payment));
+            ctx.output.print(&Value::Array(rows.collect()));
+        }
+        PaymentsCommand::Show { user, payment_id } => {
+            let payment = ctx.client.payment(&user, payment_id)?;
+            ctx.output
+                .print(&output::payment(&pda::payment(&user, payment_id).0, &payment));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use clap::Subcommand;
+use serde_json::Map;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use transit_client::instructions::{InitializeFareConfig, UpdateFareConfig};
+use transit_client::pda;
+
+use crate::context::Context;
+use crate::{output, CliResult};
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Show the fare configuration
+    Show,
+    /// Create the fare configuration with the signer as admin
+    Init {
+        /// Mint fares are paid in
+        #[arg(long)]
+        currency_mint: Pubkey,
+        #[arg(long)]
+        bus_fare: u64,
+        #[arg(long)]
+        train_fare: u64,
+        #[arg(long)]
+        monthly_price: u64,
+        #[arg(long)]
+        yearly_price: u64,
+        /// Flat fee withheld from every pass refund
+        #[arg(long, default_value_t = 0)]
+        cancellation_fee: u64,
+        /// Seconds of a pass that are always charged
+        #[arg(long, default_value_t = 0)]
+        non_refundable_period: i64,
+    },
+    /// Change refund terms and the expiry crank reward, signed by the fare manager
+    Update {
+        #[arg(long)]
+        cancellation_fee: Option<u64>,
+        #[arg(long)]
+        non_refundable_period: Option<i64>,
+        /// Lamports paid to whoever expires a lapsed pass
+        #[arg(long)]
+        expiry_crank_reward: Option<u64>,
+    },
+}
+
+pub fn run(ctx: &Context, command: ConfigCommand) -> CliResult<()> {
+    match command {
+        ConfigCommand::Show => {
+            let address = pda::fare_config().0;
+            let config = ctx.client.fare_config()?;
+            ctx.output.print(&output::fare_config(&address, &config));
+            Ok(())
+        }
+        ConfigCommand::Init {
+            currency_mint,
+            bus_fare,
+            train_fare,
+            monthly_price,
+            yearly_price,
+            cancellation_fee,
+            non_refundable_period,
+        } => {
+            let admin = ctx.signer()?.pubkey();
+            let instruction =
+                InitializeFareConfig::new(admin, currency_mint, bus_fare, train_fare, monthly_price, yearly_price)
+                    .refund_terms(cancellation_fee, non_refundable_period)
+                    .fee_payer(ctx.fee_payer()?)
+                    .instruction();
+            let mut result = Map::new();
+            result.insert("fare_config".into(), pda::fare_config().0.to_string().into());
+            result.insert("admin".into(), admin.to_string().into());
+            ctx.submit(&[instruction], result)
+        }
+        ConfigCommand::Update {
+            cancellation_fee,
+            non_refundable_period,
+            expiry_crank_reward,
+        } => {
+            if cancellation_fee.is_none() && non_refundable_period.is_none() && expiry_crank_reward.is_none() {
+                return Err("nothing to update".into());
+            }
+            let mut update = UpdateFareConfig::new(ctx.signer()?.pubkey()).fee_payer(ctx.fee_payer()?);
+            update.cancellation_fee = cancellation_fee;
+            update.non_refundable_period = non_refundable_period;
+            update.expiry_crank_reward = expiry_crank_reward;
+            ctx.submit(&[update.instruction()], Map::new())
+        }
+    }
+}
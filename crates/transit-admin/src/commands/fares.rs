@@ -0,0 +1,284 @@
+//! Transport modes, pass plans and the fare change queue
+
+use clap::{Args, Subcommand};
+use serde_json::{json, Map, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use transit_client::instructions::{ApplyFareChange, CancelFareChange, ScheduleFareChange, UpdateFareConfig};
+use transit_client::program::{
+    SUBSCRIPTION_MONTHLY, SUBSCRIPTION_YEARLY, TRANSPORT_MODE_BUS, TRANSPORT_MODE_COUNT, TRANSPORT_MODE_TRAIN,
+};
+use transit_client::FareConfig;
+
+use crate::context::{now, Context};
+use crate::output::{mode_name, plan_name};
+use crate::CliResult;
+
+// Slack added to the minimum notice so a default effective time still
+// satisfies it when the cluster clock runs ahead of ours
+const NOTICE_SLACK: i64 = 60;
+
+#[derive(Subcommand)]
+pub enum ModesCommand {
+    /// List transport modes with their current and queued fares
+    List,
+    /// Queue a new fare for a transport mode
+    SetFare {
+        /// bus, train or a mode number
+        #[arg(value_parser = parse_mode)]
+        mode: u8,
+        fare: u64,
+        #[command(flatten)]
+        schedule: Schedule,
+    },
+    /// Choose the transport modes passes are valid on
+    Allow {
+        /// bus, train or mode numbers
+        #[arg(required = true, value_parser = parse_mode)]
+        modes: Vec<u8>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PlansCommand {
+    /// List pass plans with their prices and ride limits
+    List,
+    /// Queue a new price for a pass plan
+    SetPrice {
+        /// monthly or yearly
+        #[arg(value_parser = parse_plan)]
+        plan: u8,
+        price: u64,
+        #[command(flatten)]
+        schedule: Schedule,
+    },
+    /// Cap pass rides per day and per pass period, 0 = unlimited
+    Limits {
+        #[arg(long)]
+        per_day: Option<u32>,
+        #[arg(long)]
+        per_period: Option<u32>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum FaresCommand {
+    /// Show the queued fare change
+    Pending,
+    /// Drop the queued fare change before it takes effect
+    Cancel,
+    /// Make a due fare change the live fares
+    Apply,
+}
+
+#[derive(Args)]
+pub struct Schedule {
+    /// Unix time the change takes effect. Defaults to the minimum fare
+    /// notice from now, or the time of an already queued change if later
+    #[arg(long)]
+    effective_at: Option<i64>,
+}
+
+pub fn parse_mode(value: &str) -> Result<u8, String> {
+    let mode = match value {
+        "bus" => TRANSPORT_MODE_BUS,
+        "train" => TRANSPORT_MODE_TRAIN,
+        value => value.parse().map_err(|_| format!("unknown transport mode {value}"))?,
+    };
+    if mode as usize >= TRANSPORT_MODE_COUNT {
+        return Err(format!("transport mode must be below {TRANSPORT_MODE_COUNT}"));
+    }
+    Ok(mode)
+}
+
+pub fn parse_plan(value: &str) -> Result<u8, String> {
+    match value {
+        "monthly" => Ok(SUBSCRIPTION_MONTHLY),
+        "yearly" => Ok(SUBSCRIPTION_YEARLY),
+        value => Err(format!("unknown plan {value}, expected monthly or yearly")),
+    }
+}
+
+fn queued(config: &FareConfig, current_time: i64) -> bool {
+    config.pending_fares.effective_at != 0 && current_time < config.pending_fares.effective_at
+}
+
+/// Schedule a fare change on top of the one already queued, since the
+/// program replaces the queue rather than merging into it
+fn fare_change(
+    config: &FareConfig,
+    fare_manager: Pubkey,
+    schedule: &Schedule,
+    current_time: i64,
+) -> ScheduleFareChange {
+    let pending = &config.pending_fares;
+    let earliest = current_time + config.fare_notice(current_time) + NOTICE_SLACK;
+    let mut change = ScheduleFareChange::new(fare_manager, earliest);
+    if queued(config, current_time) {
+        change.effective_at = earliest.max(pending.effective_at);
+        change.mode_0_fare = Some(pending.bus_fare);
+        change.mode_1_fare = Some(pending.train_fare);
+        change.monthly_pass_price = Some(pending.monthly_pass_price);
+        change.yearly_pass_price = Some(pending.yearly_pass_price);
+    }
+    if let Some(effective_at) = schedule.effective_at {
+        change.effective_at = effective_at;
+    }
+    change
+}
+
+fn submit_change(ctx: &Context, change: ScheduleFareChange) -> CliResult<()> {
+    let mut result = Map::new();
+    result.insert("effective_at".into(), change.effective_at.into());
+    let change = change.fee_payer(ctx.fee_payer()?);
+    ctx.submit(&[change.instruction()], result)
+}
+
+fn pending(config: &FareConfig, current_time: i64) -> Value {
+    if config.pending_fares.effective_at == 0 {
+        return Value::Null;
+    }
+    let fares = &config.pending_fares;
+    json!({
+        "effective_at": fares.effective_at,
+        "due": !queued(config, current_time),
+        "bus_fare": fares.bus_fare,
+        "train_fare": fares.train_fare,
+        "monthly_pass_price": fares.monthly_pass_price,
+        "yearly_pass_price": fares.yearly_pass_price,
+    })
+}
+
+pub fn run_modes(ctx: &Context, command: ModesCommand) -> CliResult<()> {
+    match command {
+        ModesCommand::List => {
+            let config = ctx.client.fare_config()?;
+            let queued_fares = queued(&config, now()).then_some(&config.pending_fares);
+            let modes: Vec<Value> = (0..TRANSPORT_MODE_COUNT as u8)
+                .map(|mode| {
+                    let (fare, queued_fare) = match mode {
+                        TRANSPORT_MODE_BUS => (config.bus_fare, queued_fares.map(|fares| fares.bus_fare)),
+                        _ => (config.train_fare, queued_fares.map(|fares| fares.train_fare)),
+                    };
+                    json!({
+                        "mode": mode,
+                        "name": mode_name(mode),
+                        "fare": fare,
+                        "queued_fare": queued_fare,
+                        "pass_allowed": transit_core::mode_allowed(config.subscription_allowed_modes, mode),
+                    })
+                })
+                .collect();
+            ctx.output.print(&Value::Array(modes));
+            Ok(())
+        }
+        ModesCommand::SetFare { mode, fare, schedule } => {
+            let config = ctx.client.fare_config()?;
+            let mut change = fare_change(&config, ctx.signer()?.pubkey(), &schedule, now());
+            match mode {
+                TRANSPORT_MODE_BUS => change.mode_0_fare = Some(fare),
+                _ => change.mode_1_fare = Some(fare),
+            }
+            submit_change(ctx, change)
+        }
+        ModesCommand::Allow { modes } => {
+            let allowed = modes.iter().fold(0u8, |mask, mode| mask | 1 << mode);
+            let mut update = UpdateFareConfig::new(ctx.signer()?.pubkey()).fee_payer(ctx.fee_payer()?);
+            update.subscription_allowed_modes = Some(allowed);
+            let mut result = Map::new();
+            result.insert("subscription_allowed_modes".into(), allowed.into());
+            ctx.submit(&[update.instruction()], result)
+        }
+    }
+}
+
+pub fn run_plans(ctx: &Context, command: PlansCommand) -> CliResult<()> {
+    match command {
+        PlansCommand::List => {
+            let config = ctx.client.fare_config()?;
+            let queued_fares = queued(&config, now()).then_some(&config.pending_fares);
+            let plans: Vec<Value> = [SUBSCRIPTION_MONTHLY, SUBSCRIPTION_YEARLY]
+                .into_iter()
+                .map(|plan| {
+                    let (price, queued_price) = match plan {
+                        SUBSCRIPTION_MONTHLY => (
+                            config.monthly_pass_price,
+                            queued_fares.map(|fares| fares.monthly_pass_price),
+                        ),
+                        _ => (
+                            config.yearly_pass_price,
+                            queued_fares.map(|fares| fares.yearly_pass_price),
+                        ),
+                    };
+                    json!({
+                        "plan": plan,
+                        "name": plan_name(plan),
+                        "price": price,
+                        "queued_price": queued_price,
+                        "duration": transit_core::pass_duration(plan).unwrap_or_default(),
+                        "max_rides_per_day": config.subscription_max_rides_per_day,
+                        "max_rides_per_period": config.subscription_max_rides_per_period,
+                    })
+                })
+                .collect();
+            ctx.output.print(&Value::Array(plans));
+            Ok(())
+        }
+        PlansCommand::SetPrice { plan, price, schedule } => {
+            let config = ctx.client.fare_config()?;
+            let mut change = fare_change(&config, ctx.signer()?.pubkey(), &schedule, now());
+            match plan {
+                SUBSCRIPTION_MONTHLY => change.monthly_pass_price = Some(price),
+                _ => change.yearly_pass_price = Some(price),
+            }
+            submit_change(ctx, change)
+        }
+        PlansCommand::Limits { per_day, per_period } => {
+            if per_day.is_none() && per_period.is_none() {
+                return Err("nothing to update".into());
+            }
+            let mut update = UpdateFareConfig::new(ctx.signer()?.pubkey()).fee_payer(ctx.fee_payer()?);
+            update.subscription_max_rides_per_day = per_day;
+            update.subscription_max_rides_per_period = per_period;
+            ctx.submit(&[update.instruction()], Map::new())
+        }
+    }
+}
+
+pub fn run_fares(ctx: &Context, command: FaresCommand) -> CliResult<()> {
+    match command {
+        FaresCommand::Pending => {
+            let config = ctx.client.fare_config()?;
+            ctx.output.print(&json!({ "pending_fares": pending(&config, now()) }));
+            Ok(())
+        }
+        FaresCommand::Cancel => {
+            let instruction = CancelFareChange::new(ctx.signer()?.pubkey())
+                .fee_payer(ctx.fee_payer()?)
+                .instruction();
+            ctx.submit(&[instruction], Map::new())
+        }
+        FaresCommand::Apply => ctx.submit(&[ApplyFareChange::new(ctx.fee_payer()?).instruction()], Map::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_parse_by_name_or_number() {
+        assert_eq!(parse_mode("bus"), Ok(TRANSPORT_MODE_BUS));
+        assert_eq!(parse_mode("train"), Ok(TRANSPORT_MODE_TRAIN));
+        assert_eq!(parse_mode("1"), Ok(1));
+        assert!(parse_mode("2").is_err());
+        assert!(parse_mode("ferry").is_err());
+    }
+
+    #[test]
+    fn plans_parse_by_name() {
+        assert_eq!(parse_plan("monthly"), Ok(SUBSCRIPTION_MONTHLY));
+        assert_eq!(parse_plan("yearly"), Ok(SUBSCRIPTION_YEARLY));
+        assert!(parse_plan("weekly").is_err());
+    }
+}
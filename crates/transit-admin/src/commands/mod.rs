@@ -0,0 +1,6 @@
+pub mod accounts;
+pub mod config;
+pub mod fares;
+pub mod proposals;
+pub mod report;
+pub mod treasury;
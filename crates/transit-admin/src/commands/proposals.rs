@@ -0,0 +1,69 @@
+//! Multisig proposals, for admin actions once the multisig holds the admin role
+
+use anchor_spl::token::TokenAccount;
+use clap::Subcommand;
+use serde_json::{Map, Value};
+use solana_sdk::signer::Signer;
+use transit_client::instructions::{ApproveProposal, ExecuteProposal};
+use transit_client::{decode, pda, Error, MultisigAction, Proposal};
+
+use crate::context::Context;
+use crate::{output, CliResult};
+
+#[derive(Subcommand)]
+pub enum ProposalsCommand {
+    /// List every proposal
+    List,
+    /// Show one proposal
+    Show { proposal_id: u64 },
+    /// Approve a proposal as a multisig owner
+    Approve { proposal_id: u64 },
+    /// Carry out a proposal that reached the threshold
+    Execute { proposal_id: u64 },
+}
+
+pub fn run(ctx: &Context, command: ProposalsCommand) -> CliResult<()> {
+    match command {
+        ProposalsCommand::List => {
+            let mut proposals = ctx.client.all::<Proposal>()?;
+            proposals.sort_by_key(|(_, proposal)| proposal.proposal_id);
+            let rows = proposals
+                .iter()
+                .map(|(address, proposal)| output::proposal(address, proposal));
+            ctx.output.print(&Value::Array(rows.collect()));
+            Ok(())
+        }
+        ProposalsCommand::Show { proposal_id } => {
+            let proposal = ctx.client.proposal(proposal_id)?;
+            ctx.output
+                .print(&output::proposal(&pda::proposal(proposal_id).0, &proposal));
+            Ok(())
+        }
+        ProposalsCommand::Approve { proposal_id } => {
+            let instruction = ApproveProposal::new(ctx.signer()?.pubkey(), proposal_id)
+                .fee_payer(ctx.fee_payer()?)
+                .instruction();
+            ctx.submit(&[instruction], Map::new())
+        }
+        ProposalsCommand::Execute { proposal_id } => {
+            let proposal = ctx.client.proposal(proposal_id)?;
+            let mut execute = ExecuteProposal::new(ctx.fee_payer()?, proposal_id, proposal.action.clone());
+            match &proposal.action {
+                MultisigAction::AcceptAdmin | MultisigAction::SetRole { .. } => {
+                    execute = execute.change_id(ctx.client.next_role_change_id()?);
+                }
+                MultisigAction::Withdraw { treasury, .. } => {
+                    let account = ctx
+                        .client
+                        .get_account(treasury)?
+                        .ok_or(Error::AccountNotFound(*treasury))?;
+                    execute = execute.mint(decode::<TokenAccount>(treasury, &account.data)?.mint);
+                }
+                _ => {}
+            }
+            let mut result = Map::new();
+            result.insert("action".into(), output::action(&proposal.action));
+            ctx.submit(&[execute.instruction()], result)
+        }
+    }
+}
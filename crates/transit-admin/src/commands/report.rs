@@ -0,0 +1,185 @@
+//! Exporting passengers, tickets and payments as CSV or JSON
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use serde_json::{json, Value};
+use transit_client::{Passenger, Payment, Ticket};
+
+use crate::context::{now, Context};
+use crate::{output, CliResult};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ReportKind {
+    Passengers,
+    Tickets,
+    Payments,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Args)]
+pub struct ReportArgs {
+    /// Accounts to export
+    #[arg(value_enum)]
+    kind: ReportKind,
+    #[arg(long, value_enum, default_value = "csv")]
+    format: ReportFormat,
+    /// Write to a file instead of standard output
+    #[arg(long, short)]
+    output: Option<PathBuf>,
+    /// Only tickets and payments at or after this unix time
+    #[arg(long)]
+    since: Option<i64>,
+    /// Only tickets and payments before this unix time
+    #[arg(long)]
+    until: Option<i64>,
+}
+
+impl ReportArgs {
+    fn in_range(&self, timestamp: i64) -> bool {
+        self.since.is_none_or(|since| timestamp >= since) && self.until.is_none_or(|until| timestamp < until)
+    }
+}
+
+pub fn run(ctx: &Context, args: ReportArgs) -> CliResult<()> {
+    let (rows, totals) = match args.kind {
+        ReportKind::Passengers => {
+            let mut passengers = ctx.client.all::<Passenger>()?;
+            passengers.sort_by_key(|(_, passenger)| passenger.user);
+            let totals = json!({
+                "passengers": passengers.len(),
+                "active_subscriptions": passengers.iter().filter(|(_, passenger)| passenger.subscription_end > now()).count(),
+            });
+            let rows = passengers
+                .iter()
+                .map(|(address, passenger)| output::passenger(address, passenger));
+            (rows.collect::<Vec<_>>(), totals)
+        }
+        ReportKind::Tickets => {
+            let mut tickets = ctx.client.all::<Ticket>()?;
+            tickets.retain(|(_, ticket)| args.in_range(ticket.purchase_timestamp));
+            tickets.sort_by_key(|(_, ticket)| (ticket.purchase_timestamp, ticket.user, ticket.ticket_id));
+            let rows: Vec<Value> = tickets
+                .iter()
+                .map(|(address, ticket)| output::ticket(address, ticket))
+                .collect();
+            let totals = json!({
+                "tickets": rows.len(),
+                "by_mode": count_by(&rows, "mode"),
+            });
+            (rows, totals)
+        }
+        ReportKind::Payments => {
+            let mut payments = ctx.client.all::<Payment>()?;
+            payments.retain(|(_, payment)| args.in_range(payment.payment_timestamp));
+            payments.sort_by_key(|(_, payment)| (payment.payment_timestamp, payment.user, payment.payment_id));
+            let mut amount_by_mint: BTreeMap<String, u64> = BTreeMap::new();
+            for (_, payment) in &payments {
+                *amount_by_mint.entry(payment.currency_mint.to_string()).or_default() += payment.amount;
+            }
+            let rows: Vec<Value> = payments
+                .iter()
+                .map(|(address, payment)| output::payment(address, payment))
+                .collect();
+            let totals = json!({
+                "payments": rows.len(),
+                "by_product": count_by(&rows, "product"),
+                "amount_by_mint": amount_by_mint,
+            });
+            (rows, totals)
+        }
+    };
+
+    let report = match args.format {
+        ReportFormat::Csv => csv(&rows),
+        ReportFormat::Json => {
+            let report = json!({
+                "generated_at": now(),
+                "since": args.since,
+                "until": args.until,
+                "totals": totals,
+                "rows": rows,
+            });
+            serde_json::to_string_pretty(&report)? + "\n"
+        }
+    };
+    match &args.output {
+        Some(path) => std::fs::write(path, report).map_err(|error| format!("writing {}: {error}", path.display()))?,
+        None => print!("{report}"),
+    }
+    Ok(())
+}
+
+fn count_by(rows: &[Value], field: &str) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for row in rows {
+        let key = row[field].as_str().unwrap_or_default().to_string();
+        *counts.entry(key).or_default() += 1;
+    }
+    counts
+}
+
+/// Render flat JSON records as CSV, with the first record's keys as header
+fn csv(rows: &[Value]) -> String {
+    let Some(Value::Object(first)) = rows.first() else {
+        return String::new();
+    };
+    let header: Vec<&String> = first.keys().collect();
+    let mut out = header.iter().map(|key| field(key)).collect::<Vec<_>>().join(",") + "\n";
+    for row in rows {
+        let line: Vec<String> = header
+            .iter()
+            .map(|key| match &row[key.as_str()] {
+                Value::String(value) => field(value),
+                Value::Null => String::new(),
+                value => value.to_string(),
+            })
+            .collect();
+        out += &(line.join(",") + "\n");
+    }
+    out
+}
+
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_only_when_needed() {
+        let rows = vec![
+            json!({ "user": "abc", "amount": 250, "product": "ticket" }),
+            json!({ "user": "a,b", "amount": 9000, "product": "say \"hi\"" }),
+        ];
+        assert_eq!(
+            csv(&rows),
+            "user,amount,product\nabc,250,ticket\n\"a,b\",9000,\"say \"\"hi\"\"\"\n"
+        );
+        assert_eq!(csv(&[]), "");
+    }
+
+    #[test]
+    fn rows_are_counted_by_field() {
+        let rows = vec![
+            json!({ "mode": "bus" }),
+            json!({ "mode": "train" }),
+            json!({ "mode": "bus" }),
+        ];
+        let counts = count_by(&rows, "mode");
+        assert_eq!(counts["bus"], 2);
+        assert_eq!(counts["train"], 1);
+    }
+}
@@ -0,0 +1,112 @@
+//! Treasury balance and withdrawals
+
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{spl_token, Mint, TokenAccount};
+use clap::Subcommand;
+use serde_json::{json, Map};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use transit_client::instructions::CreateProposal;
+use transit_client::{decode, pda, Error, FareConfig, MultisigAction};
+
+use crate::context::Context;
+use crate::CliResult;
+
+#[derive(Subcommand)]
+pub enum TreasuryCommand {
+    /// Show the treasury token balance
+    Balance {
+        /// Treasury token account, defaults to the treasury owner's associated
+        /// account for the fare currency
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+    },
+    /// Move funds out of the treasury. Signed by the treasury owner, or
+    /// proposed to the multisig when it owns the treasury
+    Withdraw {
+        #[arg(long)]
+        amount: u64,
+        /// Token account receiving the funds
+        #[arg(long)]
+        destination: Pubkey,
+        /// Treasury token account, defaults to the treasury owner's associated
+        /// account for the fare currency
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+    },
+}
+
+fn default_treasury(config: &FareConfig) -> Pubkey {
+    get_associated_token_address(&config.treasury, &config.currency_mint)
+}
+
+fn token_account(ctx: &Context, address: &Pubkey) -> CliResult<TokenAccount> {
+    let account = ctx
+        .client
+        .get_account(address)?
+        .ok_or(Error::AccountNotFound(*address))?;
+    Ok(decode(address, &account.data)?)
+}
+
+pub fn run(ctx: &Context, command: TreasuryCommand) -> CliResult<()> {
+    match command {
+        TreasuryCommand::Balance { treasury } => {
+            let config = ctx.client.fare_config()?;
+            let treasury = treasury.unwrap_or_else(|| default_treasury(&config));
+            let account = token_account(ctx, &treasury)?;
+            ctx.output.print(&json!({
+                "treasury": treasury.to_string(),
+                "owner": account.owner.to_string(),
+                "mint": account.mint.to_string(),
+                "amount": account.amount,
+            }));
+            Ok(())
+        }
+        TreasuryCommand::Withdraw {
+            amount,
+            destination,
+            treasury,
+        } => {
+            let config = ctx.client.fare_config()?;
+            let treasury = treasury.unwrap_or_else(|| default_treasury(&config));
+            let signer = ctx.signer()?.pubkey();
+            let mut result = Map::new();
+            result.insert("treasury".into(), treasury.to_string().into());
+            result.insert("destination".into(), destination.to_string().into());
+            result.insert("amount".into(), amount.into());
+
+            let source = token_account(ctx, &treasury)?;
+            // execute_proposal only withdraws from token accounts the multisig owns
+            if source.owner == pda::multisig().0 {
+                let proposal_id = ctx.client.next_proposal_id()?;
+                let action = MultisigAction::Withdraw {
+                    treasury,
+                    destination,
+                    amount,
+                };
+                let instruction = CreateProposal::new(signer, proposal_id, action)
+                    .fee_payer(ctx.fee_payer()?)
+                    .instruction();
+                result.insert("proposal_id".into(), proposal_id.into());
+                return ctx.submit(&[instruction], result);
+            }
+
+            let mint = ctx
+                .client
+                .get_account(&source.mint)?
+                .ok_or(Error::AccountNotFound(source.mint))?;
+            let decimals = decode::<Mint>(&source.mint, &mint.data)?.decimals;
+            let instruction = spl_token::instruction::transfer_checked(
+                &spl_token::ID,
+                &treasury,
+                &source.mint,
+                &destination,
+                &signer,
+                &[],
+                amount,
+                decimals,
+            )?;
+            ctx.submit(&[instruction], result)
+        }
+    }
+}
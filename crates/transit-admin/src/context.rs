@@ -0,0 +1,106 @@
+use std::cell::OnceCell;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair};
+use solana_sdk::signer::Signer;
+use transit_client::TransitClient;
+
+use crate::output::{self, Output};
+use crate::CliResult;
+
+/// Connection, keypairs and output mode shared by every command
+pub struct Context {
+    pub client: TransitClient,
+    pub output: Output,
+    dry_run: bool,
+    keypair_path: String,
+    fee_payer_path: Option<String>,
+    signer: OnceCell<Keypair>,
+    fee_payer: OnceCell<Keypair>,
+}
+
+impl Context {
+    pub fn new(url: &str, keypair_path: String, fee_payer_path: Option<String>, dry_run: bool, json: bool) -> Self {
+        Self {
+            client: TransitClient::new_with_url(url),
+            output: Output::new(json),
+            dry_run,
+            keypair_path,
+            fee_payer_path,
+            signer: OnceCell::new(),
+            fee_payer: OnceCell::new(),
+        }
+    }
+
+    /// Keypair signing admin actions, read on first use so read-only
+    /// commands work without one
+    pub fn signer(&self) -> CliResult<&Keypair> {
+        load(&self.signer, &self.keypair_path)
+    }
+
+    /// Pays fees and rent, the signer unless --fee-payer is given
+    pub fn fee_payer(&self) -> CliResult<Pubkey> {
+        match &self.fee_payer_path {
+            Some(path) => Ok(load(&self.fee_payer, path)?.pubkey()),
+            None => Ok(self.signer()?.pubkey()),
+        }
+    }
+
+    /// Send `instructions`, or print and simulate them with --dry-run, then
+    /// print `result` with the signature or simulation added
+    pub fn submit(&self, instructions: &[Instruction], mut result: Map<String, Value>) -> CliResult<()> {
+        let mut signers: Vec<&dyn Signer> = Vec::new();
+        if let Some(path) = &self.fee_payer_path {
+            signers.push(load(&self.fee_payer, path)?);
+        }
+        let signer = self.signer()?;
+        if signers.iter().all(|fee_payer| fee_payer.pubkey() != signer.pubkey()) {
+            signers.push(signer);
+        }
+
+        if !self.dry_run {
+            let signature = self.client.send(instructions, &signers)?;
+            result.insert("signature".into(), signature.to_string().into());
+            self.output.print(&Value::Object(result));
+            return Ok(());
+        }
+
+        result.insert("dry_run".into(), true.into());
+        result.insert(
+            "instructions".into(),
+            instructions.iter().map(output::instruction).collect::<Vec<_>>().into(),
+        );
+        let simulation = self.client.simulate(instructions, &signers);
+        let mut summary = Map::new();
+        summary.insert("success".into(), simulation.is_ok().into());
+        match &simulation {
+            Ok(logs) => summary.insert("logs".into(), logs.clone().into()),
+            Err(error) => summary.insert("error".into(), error.to_string().into()),
+        };
+        result.insert("simulation".into(), Value::Object(summary));
+        self.output.print(&Value::Object(result));
+        simulation.map(|_| ()).map_err(|_| "simulation failed".into())
+    }
+}
+
+fn load<'a>(cell: &'a OnceCell<Keypair>, path: &str) -> CliResult<&'a Keypair> {
+    if let Some(keypair) = cell.get() {
+        return Ok(keypair);
+    }
+    let path = match path.strip_prefix("~/") {
+        Some(rest) => format!("{}/{rest}", std::env::var("HOME").unwrap_or_default()),
+        None => path.to_string(),
+    };
+    let keypair = read_keypair_file(&path).map_err(|error| format!("reading keypair {path}: {error}"))?;
+    Ok(cell.get_or_init(|| keypair))
+}
+
+/// Current unix time from the local clock
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
@@ -0,0 +1,123 @@
+//! Command-line tool for operating the transit fare payment program
+//!
+//! Every command that changes state signs with --keypair and accepts
+//! --dry-run, which prints the instructions with their account lists and
+//! simulates them instead of sending. --json prints machine-readable output.
+
+use clap::{Parser, Subcommand};
+
+mod commands;
+mod context;
+mod output;
+
+use commands::accounts::{PassengersCommand, PaymentsCommand, TicketsCommand};
+use commands::config::ConfigCommand;
+use commands::fares::{FaresCommand, ModesCommand, PlansCommand};
+use commands::proposals::ProposalsCommand;
+use commands::report::ReportArgs;
+use commands::treasury::TreasuryCommand;
+use context::Context;
+
+pub type CliResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+#[derive(Parser)]
+#[command(name = "transit-admin", version, about = "Operate the transit fare payment program")]
+struct Cli {
+    /// RPC URL of the cluster
+    #[arg(
+        long,
+        short,
+        global = true,
+        env = "TRANSIT_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+    /// Keypair signing admin actions
+    #[arg(
+        long,
+        short,
+        global = true,
+        env = "TRANSIT_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+    /// Keypair paying fees and rent, defaults to --keypair
+    #[arg(long, global = true)]
+    fee_payer: Option<String>,
+    /// Print and simulate the instructions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Print JSON for scripting
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create, show and update the fare configuration
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// Transport modes and their fares
+    #[command(subcommand)]
+    Modes(ModesCommand),
+    /// Pass plans, their prices and ride limits
+    #[command(subcommand)]
+    Plans(PlansCommand),
+    /// The queued fare change
+    #[command(subcommand)]
+    Fares(FaresCommand),
+    /// Passenger accounts
+    #[command(subcommand)]
+    Passengers(PassengersCommand),
+    /// Tickets
+    #[command(subcommand)]
+    Tickets(TicketsCommand),
+    /// Payment receipts
+    #[command(subcommand)]
+    Payments(PaymentsCommand),
+    /// Treasury balance and withdrawals
+    #[command(subcommand)]
+    Treasury(TreasuryCommand),
+    /// Admin multisig proposals
+    #[command(subcommand)]
+    Proposals(ProposalsCommand),
+    /// Export passengers, tickets or payments as CSV or JSON
+    Report(ReportArgs),
+}
+
+fn run(cli: Cli) -> CliResult<()> {
+    let ctx = Context::new(&cli.url, cli.keypair, cli.fee_payer, cli.dry_run, cli.json);
+    match cli.command {
+        Command::Config(command) => commands::config::run(&ctx, command),
+        Command::Modes(command) => commands::fares::run_modes(&ctx, command),
+        Command::Plans(command) => commands::fares::run_plans(&ctx, command),
+        Command::Fares(command) => commands::fares::run_fares(&ctx, command),
+        Command::Passengers(command) => commands::accounts::run_passengers(&ctx, command),
+        Command::Tickets(command) => commands::accounts::run_tickets(&ctx, command),
+        Command::Payments(command) => commands::accounts::run_payments(&ctx, command),
+        Command::Treasury(command) => commands::treasury::run(&ctx, command),
+        Command::Proposals(command) => commands::proposals::run(&ctx, command),
+        Command::Report(args) => commands::report::run(&ctx, args),
+    }
+}
+
+fn main() {
+    if let Err(error) = run(Cli::parse()) {
+        eprintln!("error: {error}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn cli_is_well_formed() {
+        Cli::command().debug_assert();
+    }
+}
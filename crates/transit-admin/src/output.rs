@@ -0,0 +1,335 @@
+//! Printing results as text or JSON, and the JSON view of each account
+
+use serde_json::{json, Map, Value};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use transit_client::program::{
+    PAYMENT_PRODUCT_RECORDED, PAYMENT_PRODUCT_STORED_VALUE_FARE, PAYMENT_PRODUCT_SUBSCRIPTION, PAYMENT_PRODUCT_TICKET,
+    SUBSCRIPTION_MONTHLY, SUBSCRIPTION_YEARLY, TRANSPORT_MODE_BUS, TRANSPORT_MODE_TRAIN,
+};
+use transit_client::{FareConfig, MultisigAction, Passenger, Payment, Proposal, Ticket};
+
+pub struct Output {
+    json: bool,
+}
+
+impl Output {
+    pub fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    pub fn print(&self, value: &Value) {
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+        } else {
+            print!("{}", text(value));
+        }
+    }
+}
+
+/// Render `value` as indented `key: value` lines
+pub fn text(value: &Value) -> String {
+    let mut out = String::new();
+    write_text(&mut out, value, 0);
+    out
+}
+
+fn write_text(out: &mut String, value: &Value, indent: usize) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                if is_scalar(value) {
+                    out.push_str(&format!("{pad}{key}: {}\n", scalar(value)));
+                } else {
+                    out.push_str(&format!("{pad}{key}:\n"));
+                    write_text(out, value, indent + 2);
+                }
+            }
+        }
+        Value::Array(items) if items.is_empty() => out.push_str(&format!("{pad}(none)\n")),
+        Value::Array(items) => {
+            for item in items {
+                match item {
+                    // Flat records fit on one line
+                    Value::Object(map) if map.values().all(is_scalar) => {
+                        let fields: Vec<String> = map
+                            .iter()
+                            .map(|(key, value)| format!("{key}={}", scalar(value)))
+                            .collect();
+                        out.push_str(&format!("{pad}- {}\n", fields.join(" ")));
+                    }
+                    item if is_scalar(item) => out.push_str(&format!("{pad}- {}\n", scalar(item))),
+                    item => {
+                        out.push_str(&format!("{pad}-\n"));
+                        write_text(out, item, indent + 2);
+                    }
+                }
+            }
+        }
+        value => out.push_str(&format!("{pad}{}\n", scalar(value))),
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_))
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+pub fn mode_name(transport_mode: u8) -> String {
+    match transport_mode {
+        TRANSPORT_MODE_BUS => "bus".into(),
+        TRANSPORT_MODE_TRAIN => "train".into(),
+        mode => format!("mode {mode}"),
+    }
+}
+
+pub fn plan_name(subscription_type: u8) -> String {
+    match subscription_type {
+        0 => "none".into(),
+        SUBSCRIPTION_MONTHLY => "monthly".into(),
+        SUBSCRIPTION_YEARLY => "yearly".into(),
+        plan => format!("plan {plan}"),
+    }
+}
+
+fn product_name(product: u8) -> String {
+    match product {
+        PAYMENT_PRODUCT_TICKET => "ticket".into(),
+        PAYMENT_PRODUCT_SUBSCRIPTION => "subscription".into(),
+        PAYMENT_PRODUCT_STORED_VALUE_FARE => "stored_value_fare".into(),
+        PAYMENT_PRODUCT_RECORDED => "recorded".into(),
+        product => format!("product {product}"),
+    }
+}
+
+pub fn instruction(instruction: &Instruction) -> Value {
+    let accounts: Vec<Value> = instruction
+        .accounts
+        .iter()
+        .enumerate()
+        .map(|(index, meta)| {
+            json!({
+                "index": index,
+                "pubkey": meta.pubkey.to_string(),
+                "writable": meta.is_writable,
+                "signer": meta.is_signer,
+            })
+        })
+        .collect();
+    let data: String = instruction.data.iter().map(|byte| format!("{byte:02x}")).collect();
+    json!({
+        "program_id": instruction.program_id.to_string(),
+        "data": data,
+        "accounts": accounts,
+    })
+}
+
+pub fn fare_config(address: &Pubkey, config: &FareConfig) -> Value {
+    let pending = &config.pending_fares;
+    json!({
+        "address": address.to_string(),
+        "version": config.version,
+        "config_version": config.config_version,
+        "admin": config.admin.to_string(),
+        "treasury": config.treasury.to_string(),
+        "pending_admin": config.pending_admin.to_string(),
+        "guardian": config.guardian.to_string(),
+        "currency_mint": config.currency_mint.to_string(),
+        "bus_fare": config.bus_fare,
+        "train_fare": config.train_fare,
+        "monthly_pass_price": config.monthly_pass_price,
+        "yearly_pass_price": config.yearly_pass_price,
+        "pending_fares": {
+            "effective_at": pending.effective_at,
+            "bus_fare": pending.bus_fare,
+            "train_fare": pending.train_fare,
+            "monthly_pass_price": pending.monthly_pass_price,
+            "yearly_pass_price": pending.yearly_pass_price,
+        },
+        "min_fare_notice": config.min_fare_notice,
+        "pending_min_fare_notice": config.pending_min_fare_notice,
+        "min_fare_notice_effective_at": config.min_fare_notice_effective_at,
+        "cancellation_fee": config.cancellation_fee,
+        "non_refundable_period": config.non_refundable_period,
+        "subscription_allowed_modes": config.subscription_allowed_modes,
+        "subscription_max_rides_per_day": config.subscription_max_rides_per_day,
+        "subscription_max_rides_per_period": config.subscription_max_rides_per_period,
+        "expiry_crank_reward": config.expiry_crank_reward,
+        "ticket_validity_period": config.ticket_validity_period,
+        "payment_retention_period": config.payment_retention_period,
+        "price_feed": config.price_feed.to_string(),
+        "pending_price_feed": {
+            "effective_at": config.pending_price_feed.effective_at,
+            "price_feed": config.pending_price_feed.price_feed.to_string(),
+            "max_price_age": config.pending_price_feed.max_price_age,
+            "max_confidence_bps": config.pending_price_feed.max_confidence_bps,
+        },
+        "paused": config.paused,
+        "total_tickets_sold": config.total_tickets_sold,
+        "total_active_subscriptions": config.total_active_subscriptions,
+        "total_receipts": config.total_receipts,
+    })
+}
+
+pub fn passenger(address: &Pubkey, passenger: &Passenger) -> Value {
+    json!({
+        "address": address.to_string(),
+        "user": passenger.user.to_string(),
+        "total_spent": passenger.total_spent,
+        "ticket_count": passenger.ticket_count,
+        "outstanding_tickets": passenger.outstanding_tickets,
+        "payment_count": passenger.payment_count,
+        "subscription": plan_name(passenger.subscription_type),
+        "subscription_start": passenger.subscription_start,
+        "subscription_end": passenger.subscription_end,
+        "subscription_seats": passenger.subscription_seats,
+        "subscription_price_paid": passenger.subscription_price_paid,
+        "subscription_rides_used": passenger.subscription_rides_used,
+        "stored_value_balance": passenger.stored_value_balance,
+        "loyalty_points": passenger.loyalty_points,
+    })
+}
+
+pub fn ticket(address: &Pubkey, ticket: &Ticket) -> Value {
+    json!({
+        "address": address.to_string(),
+        "user": ticket.user.to_string(),
+        "ticket_id": ticket.ticket_id,
+        "mode": mode_name(ticket.transport_mode),
+        "fare_amount": ticket.fare_amount,
+        "currency_mint": ticket.currency_mint.to_string(),
+        "purchase_timestamp": ticket.purchase_timestamp,
+        "expires_at": ticket.expires_at,
+        "status": if ticket.status == 0 { "unused" } else { "used" },
+        "sponsor_escrow": ticket.sponsor_escrow.to_string(),
+    })
+}
+
+pub fn payment(address: &Pubkey, payment: &Payment) -> Value {
+    json!({
+        "address": address.to_string(),
+        "user": payment.user.to_string(),
+        "payment_id": payment.payment_id,
+        "product": product_name(payment.product),
+        "reference": payment.reference.to_string(),
+        "amount": payment.amount,
+        "currency_mint": payment.currency_mint.to_string(),
+        "slot": payment.slot,
+        "payment_timestamp": payment.payment_timestamp,
+    })
+}
+
+pub fn proposal(address: &Pubkey, proposal: &Proposal) -> Value {
+    json!({
+        "address": address.to_string(),
+        "proposal_id": proposal.proposal_id,
+        "proposer": proposal.proposer.to_string(),
+        "action": action(&proposal.action),
+        "approvals": proposal.approvals.count_ones(),
+        "executed": proposal.executed,
+        "created_at": proposal.created_at,
+    })
+}
+
+pub fn action(action: &MultisigAction) -> Value {
+    let mut fields = Map::new();
+    let kind = match action {
+        MultisigAction::AcceptAdmin => "accept_admin",
+        MultisigAction::ProposeAdmin { new_admin } => {
+            fields.insert("new_admin".into(), new_admin.to_string().into());
+            "propose_admin"
+        }
+        MultisigAction::SetRole { role, holder } => {
+            fields.insert("role".into(), (*role).into());
+            fields.insert("holder".into(), holder.to_string().into());
+            "set_role"
+        }
+        MultisigAction::ScheduleFareChange {
+            mode_0_fare,
+            mode_1_fare,
+            monthly_pass_price,
+            yearly_pass_price,
+            effective_at,
+        } => {
+            fields.insert("bus_fare".into(), json!(mode_0_fare));
+            fields.insert("train_fare".into(), json!(mode_1_fare));
+            fields.insert("monthly_pass_price".into(), json!(monthly_pass_price));
+            fields.insert("yearly_pass_price".into(), json!(yearly_pass_price));
+            fields.insert("effective_at".into(), (*effective_at).into());
+            "schedule_fare_change"
+        }
+        MultisigAction::CancelFareChange => "cancel_fare_change",
+        MultisigAction::Withdraw {
+            treasury,
+            destination,
+            amount,
+        } => {
+            fields.insert("treasury".into(), treasury.to_string().into());
+            fields.insert("destination".into(), destination.to_string().into());
+            fields.insert("amount".into(), (*amount).into());
+            "withdraw"
+        }
+        MultisigAction::Unpause { flags } => {
+            fields.insert("flags".into(), (*flags).into());
+            "unpause"
+        }
+        MultisigAction::SetGuardian { guardian } => {
+            fields.insert("guardian".into(), guardian.to_string().into());
+            "set_guardian"
+        }
+        MultisigAction::SetFareNoticePeriod { min_fare_notice } => {
+            fields.insert("min_fare_notice".into(), (*min_fare_notice).into());
+            "set_fare_notice_period"
+        }
+        MultisigAction::Pause { flags } => {
+            fields.insert("flags".into(), (*flags).into());
+            "pause"
+        }
+    };
+    let mut value = Map::new();
+    value.insert("kind".into(), kind.into());
+    value.extend(fields);
+    Value::Object(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_nests_objects_and_flattens_records() {
+        let value = json!({
+            "signature": "abc",
+            "simulation": { "success": true, "logs": ["one", "two"] },
+            "accounts": [{ "index": 0, "signer": true }],
+            "empty": [],
+        });
+        assert_eq!(
+            text(&value),
+            "signature: abc\nsimulation:\n  success: true\n  logs:\n    - one\n    - two\naccounts:\n  - index=0 signer=true\nempty:\n  (none)\n"
+        );
+    }
+
+    #[test]
+    fn instruction_lists_every_account() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[0xab, 0x01],
+            vec![solana_sdk::instruction::AccountMeta::new(payer, true)],
+        );
+        let value = super::instruction(&instruction);
+        assert_eq!(value["data"], "ab01");
+        assert_eq!(value["accounts"][0]["pubkey"], payer.to_string());
+        assert_eq!(value["accounts"][0]["writable"], true);
+        assert_eq!(value["accounts"][0]["signer"], true);
+    }
+}
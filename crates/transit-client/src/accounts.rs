@@ -0,0 +1,49 @@
+//! Decoding of transit program accounts
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+
+use transit_fare_payment::RideHistory;
+
+use crate::{Error, Result, ID};
+
+/// Decode the data of the program account at `address` as `T`
+pub fn decode<T: AccountDeserialize>(address: &Pubkey, data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..]).map_err(|error| Error::InvalidAccount {
+        address: *address,
+        reason: error.to_string(),
+    })
+}
+
+/// Decode `account` as `T`, checking it is owned by the transit program
+pub fn decode_account<T: AccountDeserialize>(address: &Pubkey, account: &Account) -> Result<T> {
+    if account.owner != ID {
+        return Err(Error::InvalidAccount {
+            address: *address,
+            reason: format!("owned by {}, not the transit program", account.owner),
+        });
+    }
+    decode(address, &account.data)
+}
+
+/// Whether `data` starts with the discriminator of `T`, for filtering
+/// accounts of one type out of a program-wide scan
+pub fn is_account<T: Discriminator>(data: &[u8]) -> bool {
+    data.len() >= 8 && data[..8] == T::DISCRIMINATOR
+}
+
+/// Decode a zero-copy RideHistory account
+pub fn decode_ride_history(address: &Pubkey, data: &[u8]) -> Result<RideHistory> {
+    let invalid = |reason: &str| Error::InvalidAccount {
+        address: *address,
+        reason: reason.to_string(),
+    };
+    if !is_account::<RideHistory>(data) {
+        return Err(invalid("not a RideHistory account"));
+    }
+    let body = data
+        .get(8..8 + std::mem::size_of::<RideHistory>())
+        .ok_or_else(|| invalid("account data too short"))?;
+    Ok(bytemuck::pod_read_unaligned(body))
+}
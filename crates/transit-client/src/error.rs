@@ -0,0 +1,172 @@
+//! Client errors, with program error codes mapped back to FarePaymentError
+
+use anchor_lang::error::ERROR_CODE_OFFSET;
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use transit_core::CoreError;
+use transit_fare_payment::FarePaymentError;
+
+/// Errors returned by the client
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The transit program rejected the transaction
+    #[error("transit program error {}: {}", u32::from(*.0), .0)]
+    Program(FarePaymentError),
+    /// A custom error code the program does not define, usually from a CPI
+    #[error("custom program error {0}")]
+    Custom(u32),
+    /// The transaction failed for a reason other than a custom program error
+    #[error("transaction failed: {0}")]
+    Transaction(TransactionError),
+    /// The RPC request itself failed
+    #[error("rpc error: {0}")]
+    Rpc(Box<ClientError>),
+    /// No account exists at the address
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    /// The account exists but is not the expected type
+    #[error("account {address} could not be decoded: {reason}")]
+    InvalidAccount { address: Pubkey, reason: String },
+    /// A fare rule was violated before anything was sent
+    #[error(transparent)]
+    Core(#[from] CoreError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// Every FarePaymentError in declaration order, so index + ERROR_CODE_OFFSET is its code
+const PROGRAM_ERRORS: &[FarePaymentError] = &[
+    FarePaymentError::InvalidTransportMode,
+    FarePaymentError::InvalidAmount,
+    FarePaymentError::Unauthorized,
+    FarePaymentError::TicketAlreadyUsed,
+    FarePaymentError::InsufficientFunds,
+    FarePaymentError::InvalidAccountData,
+    FarePaymentError::InvalidSubscriptionType,
+    FarePaymentError::SubscriptionAlreadyActive,
+    FarePaymentError::SubscriptionExpired,
+    FarePaymentError::SubscriptionNotFound,
+    FarePaymentError::CurrencyMismatch,
+    FarePaymentError::InvalidNonRefundablePeriod,
+    FarePaymentError::TransportModeNotAllowed,
+    FarePaymentError::DailyRideLimitReached,
+    FarePaymentError::PeriodRideLimitReached,
+    FarePaymentError::SubscriptionStillActive,
+    FarePaymentError::InvalidSeatCount,
+    FarePaymentError::HouseholdFull,
+    FarePaymentError::HouseholdMemberExists,
+    FarePaymentError::NotHouseholdMember,
+    FarePaymentError::HouseholdSeatsExceeded,
+    FarePaymentError::EmployeeNotEnrolled,
+    FarePaymentError::AllowanceExceeded,
+    FarePaymentError::InvalidEscrow,
+    FarePaymentError::InvalidTreasury,
+    FarePaymentError::RefundDestinationMismatch,
+    FarePaymentError::InvalidPromotionWindow,
+    FarePaymentError::InsufficientLoyaltyPoints,
+    FarePaymentError::InvalidLoyaltyReward,
+    FarePaymentError::InvalidPromotion,
+    FarePaymentError::InvalidPromoCode,
+    FarePaymentError::PromotionNotActive,
+    FarePaymentError::PromotionNotApplicable,
+    FarePaymentError::PromotionLimitReached,
+    FarePaymentError::InvalidGiftCardCode,
+    FarePaymentError::GiftCardExpired,
+    FarePaymentError::GiftCardNotExpired,
+    FarePaymentError::CurrencyDisabled,
+    FarePaymentError::InvalidExchangeRate,
+    FarePaymentError::InvalidPriceFeed,
+    FarePaymentError::StalePrice,
+    FarePaymentError::PriceConfidenceTooWide,
+    FarePaymentError::TicketExpired,
+    FarePaymentError::TicketStillValid,
+    FarePaymentError::RetentionPeriodNotElapsed,
+    FarePaymentError::InvalidRetentionPeriod,
+    FarePaymentError::StoredValueNotEmpty,
+    FarePaymentError::OutstandingTickets,
+    FarePaymentError::InvalidRole,
+    FarePaymentError::NotPendingAdmin,
+    FarePaymentError::ProgramPaused,
+    FarePaymentError::InvalidPauseFlags,
+    FarePaymentError::FareNoticeTooShort,
+    FarePaymentError::InvalidFareNotice,
+    FarePaymentError::NoPendingFareChange,
+    FarePaymentError::FareChangeAlreadyEffective,
+    FarePaymentError::FareChangeNotDue,
+    FarePaymentError::PriceAboveMaximum,
+    FarePaymentError::InvalidMultisig,
+    FarePaymentError::NotMultisigOwner,
+    FarePaymentError::ProposalAlreadyExecuted,
+    FarePaymentError::ProposalAlreadyApproved,
+    FarePaymentError::ThresholdNotReached,
+    FarePaymentError::InvalidActionAccounts,
+    FarePaymentError::AccountAlreadyMigrated,
+    FarePaymentError::UnsupportedAccountVersion,
+    FarePaymentError::MissingPaymentReceipt,
+    FarePaymentError::LoyaltyPointsSpent,
+];
+
+/// The program error with error code `code`, if the program defines one
+pub fn program_error(code: u32) -> Option<FarePaymentError> {
+    let index = code.checked_sub(ERROR_CODE_OFFSET)?;
+    PROGRAM_ERRORS.get(index as usize).copied()
+}
+
+impl From<TransactionError> for Error {
+    fn from(error: TransactionError) -> Self {
+        match error {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+                program_error(code).map_or(Error::Custom(code), Error::Program)
+            }
+            error => Error::Transaction(error),
+        }
+    }
+}
+
+impl From<ClientError> for Error {
+    fn from(error: ClientError) -> Self {
+        // Surface program errors from failed sends and preflight simulations
+        let transaction_error = match error.kind() {
+            ClientErrorKind::TransactionError(error) => Some(error.clone()),
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+                ..
+            }) => result.err.clone(),
+            _ => None,
+        };
+        match transaction_error {
+            Some(error) => error.into(),
+            None => Error::Rpc(Box::new(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_follow_declaration_order() {
+        for (index, error) in PROGRAM_ERRORS.iter().enumerate() {
+            assert_eq!(u32::from(*error), ERROR_CODE_OFFSET + index as u32, "{}", error.name());
+        }
+    }
+
+    #[test]
+    fn custom_codes_map_to_program_errors() {
+        let error = TransactionError::InstructionError(0, InstructionError::Custom(ERROR_CODE_OFFSET));
+        assert!(matches!(
+            Error::from(error),
+            Error::Program(FarePaymentError::InvalidTransportMode)
+        ));
+
+        let error = TransactionError::InstructionError(0, InstructionError::Custom(1));
+        assert!(matches!(Error::from(error), Error::Custom(1)));
+
+        let error = TransactionError::InstructionError(0, InstructionError::MissingAccount);
+        assert!(matches!(Error::from(error), Error::Transaction(_)));
+    }
+}
@@ -0,0 +1,542 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Create the fare config and role holders, all roles starting with `admin`
+#[derive(Clone, Debug)]
+pub struct InitializeFareConfig {
+    pub admin: Pubkey,
+    pub mode_0_fare: u64,
+    pub mode_1_fare: u64,
+    pub currency_mint: Pubkey,
+    pub monthly_pass_price: u64,
+    pub yearly_pass_price: u64,
+    pub cancellation_fee: u64,
+    pub non_refundable_period: i64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl InitializeFareConfig {
+    /// Fares and pass prices in `currency_mint`, with no cancellation fee or
+    /// non-refundable period
+    pub fn new(
+        admin: Pubkey,
+        currency_mint: Pubkey,
+        mode_0_fare: u64,
+        mode_1_fare: u64,
+        monthly_pass_price: u64,
+        yearly_pass_price: u64,
+    ) -> Self {
+        Self {
+            admin,
+            mode_0_fare,
+            mode_1_fare,
+            currency_mint,
+            monthly_pass_price,
+            yearly_pass_price,
+            cancellation_fee: 0,
+            non_refundable_period: 0,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn refund_terms(mut self, cancellation_fee: u64, non_refundable_period: i64) -> Self {
+        self.cancellation_fee = cancellation_fee;
+        self.non_refundable_period = non_refundable_period;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::InitializeFareConfig {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeFareConfig {
+                mode_0_fare: self.mode_0_fare,
+                mode_1_fare: self.mode_1_fare,
+                currency_mint: self.currency_mint,
+                monthly_pass_price: self.monthly_pass_price,
+                yearly_pass_price: self.yearly_pass_price,
+                cancellation_fee: self.cancellation_fee,
+                non_refundable_period: self.non_refundable_period,
+            },
+        )
+    }
+}
+
+/// Change refund terms and pass ride limits, fields left `None` keep their
+/// current value
+#[derive(Clone, Debug)]
+pub struct UpdateFareConfig {
+    pub fare_manager: Pubkey,
+    pub cancellation_fee: Option<u64>,
+    pub non_refundable_period: Option<i64>,
+    pub subscription_allowed_modes: Option<u8>,
+    pub subscription_max_rides_per_day: Option<u32>,
+    pub subscription_max_rides_per_period: Option<u32>,
+    pub expiry_crank_reward: Option<u64>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UpdateFareConfig {
+    pub fn new(fare_manager: Pubkey) -> Self {
+        Self {
+            fare_manager,
+            cancellation_fee: None,
+            non_refundable_period: None,
+            subscription_allowed_modes: None,
+            subscription_max_rides_per_day: None,
+            subscription_max_rides_per_period: None,
+            expiry_crank_reward: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::UpdateFareConfig {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+            },
+            instruction::UpdateFareConfig {
+                cancellation_fee: self.cancellation_fee,
+                non_refundable_period: self.non_refundable_period,
+                subscription_allowed_modes: self.subscription_allowed_modes,
+                subscription_max_rides_per_day: self.subscription_max_rides_per_day,
+                subscription_max_rides_per_period: self.subscription_max_rides_per_period,
+                expiry_crank_reward: self.expiry_crank_reward,
+            },
+        )
+    }
+}
+
+/// Price fares in fiat through `price_feed`, or in tokens with the default key
+#[derive(Clone, Debug)]
+pub struct SetPriceFeed {
+    pub fare_manager: Pubkey,
+    pub price_feed: Pubkey,
+    pub max_price_age: i64,
+    pub max_confidence_bps: u16,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetPriceFeed {
+    pub fn new(fare_manager: Pubkey, price_feed: Pubkey, max_price_age: i64, max_confidence_bps: u16) -> Self {
+        Self {
+            fare_manager,
+            price_feed,
+            max_price_age,
+            max_confidence_bps,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::SetPriceFeed {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+            },
+            instruction::SetPriceFeed {
+                price_feed: self.price_feed,
+                max_price_age: self.max_price_age,
+                max_confidence_bps: self.max_confidence_bps,
+            },
+        )
+    }
+}
+
+/// Set how long tickets stay valid and receipts are kept, 0 = forever
+#[derive(Clone, Debug)]
+pub struct SetRetentionPolicy {
+    pub operator: Pubkey,
+    pub ticket_validity_period: i64,
+    pub payment_retention_period: i64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetRetentionPolicy {
+    pub fn new(operator: Pubkey, ticket_validity_period: i64, payment_retention_period: i64) -> Self {
+        Self {
+            operator,
+            ticket_validity_period,
+            payment_retention_period,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::SetRetentionPolicy {
+                fee_payer: self.fee_payer.unwrap_or(self.operator),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                operator: self.operator,
+            },
+            instruction::SetRetentionPolicy {
+                ticket_validity_period: self.ticket_validity_period,
+                payment_retention_period: self.payment_retention_period,
+            },
+        )
+    }
+}
+
+/// Start handing the admin role to `new_admin`
+#[derive(Clone, Debug)]
+pub struct ProposeAdmin {
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl ProposeAdmin {
+    pub fn new(admin: Pubkey, new_admin: Pubkey) -> Self {
+        Self {
+            admin,
+            new_admin,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ProposeAdmin {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                admin: self.admin,
+            },
+            instruction::ProposeAdmin {
+                new_admin: self.new_admin,
+            },
+        )
+    }
+}
+
+/// Take over the admin role as the pending admin, recorded as role change
+/// `change_id`, the roles' `change_count`
+#[derive(Clone, Debug)]
+pub struct AcceptAdmin {
+    pub new_admin: Pubkey,
+    pub change_id: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl AcceptAdmin {
+    pub fn new(new_admin: Pubkey, change_id: u64) -> Self {
+        Self {
+            new_admin,
+            change_id,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::AcceptAdmin {
+                fee_payer: self.fee_payer.unwrap_or(self.new_admin),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                role_change: pda::role_change(self.change_id).0,
+                new_admin: self.new_admin,
+                system_program: system_program::ID,
+            },
+            instruction::AcceptAdmin {},
+        )
+    }
+}
+
+/// Give ROLE_* `role` to `holder`, recorded as role change `change_id`, the
+/// roles' `change_count`
+#[derive(Clone, Debug)]
+pub struct SetRole {
+    pub admin: Pubkey,
+    pub role: u8,
+    pub holder: Pubkey,
+    pub change_id: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetRole {
+    pub fn new(admin: Pubkey, role: u8, holder: Pubkey, change_id: u64) -> Self {
+        Self {
+            admin,
+            role,
+            holder,
+            change_id,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::SetRole {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                role_change: pda::role_change(self.change_id).0,
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::SetRole {
+                role: self.role,
+                holder: self.holder,
+            },
+        )
+    }
+}
+
+/// Let `guardian` pause, but not unpause, the program
+#[derive(Clone, Debug)]
+pub struct SetGuardian {
+    pub admin: Pubkey,
+    pub guardian: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetGuardian {
+    pub fn new(admin: Pubkey, guardian: Pubkey) -> Self {
+        Self {
+            admin,
+            guardian,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::SetGuardian {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                admin: self.admin,
+            },
+            instruction::SetGuardian {
+                guardian: self.guardian,
+            },
+        )
+    }
+}
+
+/// Pause the PAUSE_* instruction families in `flags`, signed by the admin or
+/// guardian
+#[derive(Clone, Debug)]
+pub struct Pause {
+    pub authority: Pubkey,
+    pub flags: u8,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl Pause {
+    pub fn new(authority: Pubkey, flags: u8) -> Self {
+        Self {
+            authority,
+            flags,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::Pause {
+                fee_payer: self.fee_payer.unwrap_or(self.authority),
+                fare_config: pda::fare_config().0,
+                authority: self.authority,
+            },
+            instruction::Pause { flags: self.flags },
+        )
+    }
+}
+
+/// Resume the PAUSE_* instruction families in `flags`
+#[derive(Clone, Debug)]
+pub struct Unpause {
+    pub admin: Pubkey,
+    pub flags: u8,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl Unpause {
+    pub fn new(admin: Pubkey, flags: u8) -> Self {
+        Self {
+            admin,
+            flags,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::Unpause {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                admin: self.admin,
+            },
+            instruction::Unpause { flags: self.flags },
+        )
+    }
+}
+
+/// Queue new fares taking effect at `effective_at`, fields left `None` keep
+/// their current value
+#[derive(Clone, Debug)]
+pub struct ScheduleFareChange {
+    pub fare_manager: Pubkey,
+    pub mode_0_fare: Option<u64>,
+    pub mode_1_fare: Option<u64>,
+    pub monthly_pass_price: Option<u64>,
+    pub yearly_pass_price: Option<u64>,
+    pub effective_at: i64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl ScheduleFareChange {
+    pub fn new(fare_manager: Pubkey, effective_at: i64) -> Self {
+        Self {
+            fare_manager,
+            mode_0_fare: None,
+            mode_1_fare: None,
+            monthly_pass_price: None,
+            yearly_pass_price: None,
+            effective_at,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ScheduleFareChange {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+            },
+            instruction::ScheduleFareChange {
+                mode_0_fare: self.mode_0_fare,
+                mode_1_fare: self.mode_1_fare,
+                monthly_pass_price: self.monthly_pass_price,
+                yearly_pass_price: self.yearly_pass_price,
+                effective_at: self.effective_at,
+            },
+        )
+    }
+}
+
+/// Drop the queued fare change
+#[derive(Clone, Debug)]
+pub struct CancelFareChange {
+    pub fare_manager: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CancelFareChange {
+    pub fn new(fare_manager: Pubkey) -> Self {
+        Self {
+            fare_manager,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::CancelFareChange {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+            },
+            instruction::CancelFareChange {},
+        )
+    }
+}
+
+/// Apply the queued fare change once it is due, permissionless
+#[derive(Clone, Debug)]
+pub struct ApplyFareChange {
+    pub fee_payer: Pubkey,
+}
+
+impl ApplyFareChange {
+    pub fn new(fee_payer: Pubkey) -> Self {
+        Self { fee_payer }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ApplyFareChange {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+            },
+            instruction::ApplyFareChange {},
+        )
+    }
+}
+
+/// Require at least `min_fare_notice` seconds between scheduling a fare
+/// change and its effective time. A shorter notice only takes effect once
+/// the current one has passed.
+#[derive(Clone, Debug)]
+pub struct SetFareNoticePeriod {
+    pub admin: Pubkey,
+    pub min_fare_notice: i64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetFareNoticePeriod {
+    pub fn new(admin: Pubkey, min_fare_notice: i64) -> Self {
+        Self {
+            admin,
+            min_fare_notice,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::SetFareNoticePeriod {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                admin: self.admin,
+            },
+            instruction::SetFareNoticePeriod {
+                min_fare_notice: self.min_fare_notice,
+            },
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Accept payment in `mint` at `rate_numerator` units per `rate_denominator`
+/// base fare units, paid into `treasury`
+#[derive(Clone, Debug)]
+pub struct AddAcceptedCurrency {
+    pub treasurer: Pubkey,
+    pub mint: Pubkey,
+    pub treasury: Pubkey,
+    pub rate_numerator: u64,
+    pub rate_denominator: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl AddAcceptedCurrency {
+    pub fn new(treasurer: Pubkey, mint: Pubkey, treasury: Pubkey, rate_numerator: u64, rate_denominator: u64) -> Self {
+        Self {
+            treasurer,
+            mint,
+            treasury,
+            rate_numerator,
+            rate_denominator,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::AddAcceptedCurrency {
+                fee_payer: self.fee_payer.unwrap_or(self.treasurer),
+                fare_config: pda::fare_config().0,
+                accepted_currency: pda::accepted_currency(&self.mint).0,
+                mint: self.mint,
+                treasury: self.treasury,
+                roles: pda::roles().0,
+                treasurer: self.treasurer,
+                system_program: system_program::ID,
+            },
+            instruction::AddAcceptedCurrency {
+                rate_numerator: self.rate_numerator,
+                rate_denominator: self.rate_denominator,
+            },
+        )
+    }
+}
+
+/// Change the rate, treasury or availability of the accepted currency `mint`,
+/// fields left `None` keep their current value
+#[derive(Clone, Debug)]
+pub struct UpdateAcceptedCurrency {
+    pub treasurer: Pubkey,
+    pub mint: Pubkey,
+    pub treasury: Option<Pubkey>,
+    pub rate_numerator: Option<u64>,
+    pub rate_denominator: Option<u64>,
+    pub enabled: Option<bool>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UpdateAcceptedCurrency {
+    pub fn new(treasurer: Pubkey, mint: Pubkey) -> Self {
+        Self {
+            treasurer,
+            mint,
+            treasury: None,
+            rate_numerator: None,
+            rate_denominator: None,
+            enabled: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::UpdateAcceptedCurrency {
+                fee_payer: self.fee_payer.unwrap_or(self.treasurer),
+                fare_config: pda::fare_config().0,
+                accepted_currency: pda::accepted_currency(&self.mint).0,
+                treasury: self.treasury,
+                roles: pda::roles().0,
+                treasurer: self.treasurer,
+            },
+            instruction::UpdateAcceptedCurrency {
+                rate_numerator: self.rate_numerator,
+                rate_denominator: self.rate_denominator,
+                enabled: self.enabled,
+            },
+        )
+    }
+}
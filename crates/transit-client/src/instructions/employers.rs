@@ -0,0 +1,199 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Register an employer administered by `admin`, funded in `currency_mint`
+#[derive(Clone, Debug)]
+pub struct CreateEmployer {
+    pub admin: Pubkey,
+    pub currency_mint: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreateEmployer {
+    pub fn new(admin: Pubkey, currency_mint: Pubkey) -> Self {
+        Self {
+            admin,
+            currency_mint,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let employer = pda::employer(&self.admin).0;
+        build(
+            accounts::CreateEmployer {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                employer,
+                escrow: pda::employer_escrow(&employer).0,
+                admin: self.admin,
+                currency_mint: self.currency_mint,
+                system_program: system_program::ID,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::CreateEmployer {},
+        )
+    }
+}
+
+/// Give `employee` a commuter benefit of `monthly_allowance` per month
+#[derive(Clone, Debug)]
+pub struct EnrollEmployee {
+    pub admin: Pubkey,
+    pub employee: Pubkey,
+    pub monthly_allowance: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl EnrollEmployee {
+    pub fn new(admin: Pubkey, employee: Pubkey, monthly_allowance: u64) -> Self {
+        Self {
+            admin,
+            employee,
+            monthly_allowance,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let employer = pda::employer(&self.admin).0;
+        build(
+            accounts::EnrollEmployee {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                employer,
+                employee_benefit: pda::employee_benefit(&employer, &self.employee).0,
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::EnrollEmployee {
+                employee: self.employee,
+                monthly_allowance: self.monthly_allowance,
+            },
+        )
+    }
+}
+
+/// End the commuter benefit of `employee`
+#[derive(Clone, Debug)]
+pub struct UnenrollEmployee {
+    pub admin: Pubkey,
+    pub employee: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UnenrollEmployee {
+    pub fn new(admin: Pubkey, employee: Pubkey) -> Self {
+        Self {
+            admin,
+            employee,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let employer = pda::employer(&self.admin).0;
+        build(
+            accounts::UnenrollEmployee {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                employer,
+                employee_benefit: pda::employee_benefit(&employer, &self.employee).0,
+                admin: self.admin,
+            },
+            instruction::UnenrollEmployee {},
+        )
+    }
+}
+
+/// Fund the escrow of the employer administered by `admin`
+#[derive(Clone, Debug)]
+pub struct TopUpEmployer {
+    pub admin: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    /// Owner or delegate of `source`
+    pub authority: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl TopUpEmployer {
+    pub fn new(admin: Pubkey, amount: u64, mint: Pubkey, source: Pubkey, authority: Pubkey) -> Self {
+        Self {
+            admin,
+            amount,
+            mint,
+            source,
+            authority,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let employer = pda::employer(&self.admin).0;
+        build(
+            accounts::TopUpEmployer {
+                fee_payer: self.fee_payer.unwrap_or(self.authority),
+                employer,
+                escrow: pda::employer_escrow(&employer).0,
+                source: self.source,
+                mint: self.mint,
+                authority: self.authority,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::TopUpEmployer { amount: self.amount },
+        )
+    }
+}
+
+/// Move `amount` out of the employer escrow to `destination`
+#[derive(Clone, Debug)]
+pub struct WithdrawEmployer {
+    pub admin: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl WithdrawEmployer {
+    pub fn new(admin: Pubkey, amount: u64, mint: Pubkey, destination: Pubkey) -> Self {
+        Self {
+            admin,
+            amount,
+            mint,
+            destination,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let employer = pda::employer(&self.admin).0;
+        build(
+            accounts::WithdrawEmployer {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                employer,
+                escrow: pda::employer_escrow(&employer).0,
+                destination: self.destination,
+                mint: self.mint,
+                admin: self.admin,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::WithdrawEmployer { amount: self.amount },
+        )
+    }
+}
@@ -0,0 +1,270 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Let `partner` issue gift cards
+#[derive(Clone, Debug)]
+pub struct AddRetailPartner {
+    pub eligibility_issuer: Pubkey,
+    pub partner: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl AddRetailPartner {
+    pub fn new(eligibility_issuer: Pubkey, partner: Pubkey) -> Self {
+        Self {
+            eligibility_issuer,
+            partner,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::AddRetailPartner {
+                fee_payer: self.fee_payer.unwrap_or(self.eligibility_issuer),
+                fare_config: pda::fare_config().0,
+                retail_partner: pda::retail_partner(&self.partner).0,
+                roles: pda::roles().0,
+                eligibility_issuer: self.eligibility_issuer,
+                system_program: system_program::ID,
+            },
+            instruction::AddRetailPartner { partner: self.partner },
+        )
+    }
+}
+
+/// Revoke the gift card issuing rights of `partner`
+#[derive(Clone, Debug)]
+pub struct RemoveRetailPartner {
+    pub eligibility_issuer: Pubkey,
+    pub partner: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl RemoveRetailPartner {
+    pub fn new(eligibility_issuer: Pubkey, partner: Pubkey) -> Self {
+        Self {
+            eligibility_issuer,
+            partner,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::RemoveRetailPartner {
+                fee_payer: self.fee_payer.unwrap_or(self.eligibility_issuer),
+                fare_config: pda::fare_config().0,
+                retail_partner: pda::retail_partner(&self.partner).0,
+                roles: pda::roles().0,
+                eligibility_issuer: self.eligibility_issuer,
+            },
+            instruction::RemoveRetailPartner {},
+        )
+    }
+}
+
+/// Load `value` of `mint` from `source` onto a gift card claimable with `code`
+#[derive(Clone, Debug)]
+pub struct IssueGiftCard {
+    pub issuer: Pubkey,
+    pub claim_key: Pubkey,
+    pub value: u64,
+    pub expires_at: i64,
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    /// Owner or delegate of `source`
+    pub authority: Pubkey,
+    /// Issuing as a registered retail partner rather than the eligibility issuer
+    pub retail_partner: bool,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl IssueGiftCard {
+    pub fn new(issuer: Pubkey, code: &str, value: u64, expires_at: i64, mint: Pubkey, source: Pubkey) -> Self {
+        Self {
+            issuer,
+            claim_key: crate::gift_card_claim(code).pubkey(),
+            value,
+            expires_at,
+            mint,
+            source,
+            authority: issuer,
+            retail_partner: false,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = authority;
+        self
+    }
+
+    pub fn retail_partner(mut self, retail_partner: bool) -> Self {
+        self.retail_partner = retail_partner;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let gift_card = pda::gift_card(&self.claim_key).0;
+        build(
+            accounts::IssueGiftCard {
+                fee_payer: self.fee_payer.unwrap_or(self.issuer),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                gift_card,
+                escrow: pda::gift_card_escrow(&gift_card).0,
+                issuer: self.issuer,
+                retail_partner: self.retail_partner.then(|| pda::retail_partner(&self.issuer).0),
+                source: self.source,
+                mint: self.mint,
+                authority: self.authority,
+                system_program: system_program::ID,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::IssueGiftCard {
+                claim_key: self.claim_key,
+                value: self.value,
+                expires_at: self.expires_at,
+            },
+        )
+    }
+}
+
+/// Claim the gift card issued to `claim_key` into the stored-value balance
+/// of `user`, optionally buying a pass with it
+///
+/// The transaction must also be signed by [`crate::gift_card_claim`] for the
+/// card's code.
+#[derive(Clone, Debug)]
+pub struct RedeemGiftCard {
+    pub user: Pubkey,
+    pub claim_key: Pubkey,
+    /// Issuer recorded on the card, receives the closed accounts' rent
+    pub issuer: Pubkey,
+    pub mint: Pubkey,
+    /// 0 = credit the balance only, otherwise the SUBSCRIPTION_* pass to buy
+    pub subscription_type: u8,
+    pub max_price: u64,
+    /// System treasury, required to buy a pass
+    pub destination: Option<Pubkey>,
+    /// Receipt id for the pass, the passenger's `payment_count`
+    pub payment_id: u64,
+    pub price_feed: Option<Pubkey>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl RedeemGiftCard {
+    pub fn new(user: Pubkey, claim_key: Pubkey, issuer: Pubkey, mint: Pubkey) -> Self {
+        Self {
+            user,
+            claim_key,
+            issuer,
+            mint,
+            subscription_type: 0,
+            max_price: 0,
+            destination: None,
+            payment_id: 0,
+            price_feed: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    /// Spend the card on a `subscription_type` pass paid into `destination`,
+    /// writing receipt `payment_id`
+    pub fn buy_pass(mut self, subscription_type: u8, max_price: u64, destination: Pubkey, payment_id: u64) -> Self {
+        self.subscription_type = subscription_type;
+        self.max_price = max_price;
+        self.destination = Some(destination);
+        self.payment_id = payment_id;
+        self
+    }
+
+    pub fn price_feed(mut self, price_feed: Pubkey) -> Self {
+        self.price_feed = Some(price_feed);
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        let gift_card = pda::gift_card(&self.claim_key).0;
+        build(
+            accounts::RedeemGiftCard {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                gift_card,
+                escrow: pda::gift_card_escrow(&gift_card).0,
+                issuer: self.issuer,
+                passenger: pda::passenger(&user).0,
+                stored_value: pda::stored_value(&user).0,
+                user,
+                claim: self.claim_key,
+                destination: self.destination,
+                mint: self.mint,
+                system_program: system_program::ID,
+                token_program: anchor_spl::token::ID,
+                price_feed: self.price_feed,
+                payment: (self.subscription_type != 0).then(|| pda::payment(&user, self.payment_id).0),
+            },
+            instruction::RedeemGiftCard {
+                subscription_type: self.subscription_type,
+                max_price: self.max_price,
+            },
+        )
+    }
+}
+
+/// Return the value of the expired gift card issued to `claim_key` to its issuer
+#[derive(Clone, Debug)]
+pub struct ReclaimGiftCard {
+    pub issuer: Pubkey,
+    pub claim_key: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl ReclaimGiftCard {
+    pub fn new(issuer: Pubkey, claim_key: Pubkey, mint: Pubkey, destination: Pubkey) -> Self {
+        Self {
+            issuer,
+            claim_key,
+            mint,
+            destination,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let gift_card = pda::gift_card(&self.claim_key).0;
+        build(
+            accounts::ReclaimGiftCard {
+                fee_payer: self.fee_payer.unwrap_or(self.issuer),
+                fare_config: pda::fare_config().0,
+                gift_card,
+                escrow: pda::gift_card_escrow(&gift_card).0,
+                issuer: self.issuer,
+                destination: self.destination,
+                mint: self.mint,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::ReclaimGiftCard {},
+        )
+    }
+}
@@ -0,0 +1,101 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Open a household on the pass of `user`
+#[derive(Clone, Debug)]
+pub struct CreateHousehold {
+    pub user: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreateHousehold {
+    pub fn new(user: Pubkey) -> Self {
+        Self { user, fee_payer: None }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::CreateHousehold {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                passenger: pda::passenger(&user).0,
+                household: pda::household(&user).0,
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::CreateHousehold {},
+        )
+    }
+}
+
+/// Give `member` a seat in the household of `user`
+#[derive(Clone, Debug)]
+pub struct AddHouseholdMember {
+    pub user: Pubkey,
+    pub member: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl AddHouseholdMember {
+    pub fn new(user: Pubkey, member: Pubkey) -> Self {
+        Self {
+            user,
+            member,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::AddHouseholdMember {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                passenger: pda::passenger(&user).0,
+                household: pda::household(&user).0,
+                user,
+            },
+            instruction::AddHouseholdMember { member: self.member },
+        )
+    }
+}
+
+/// Free the seat of `member` in the household of `user`
+#[derive(Clone, Debug)]
+pub struct RemoveHouseholdMember {
+    pub user: Pubkey,
+    pub member: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl RemoveHouseholdMember {
+    pub fn new(user: Pubkey, member: Pubkey) -> Self {
+        Self {
+            user,
+            member,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::RemoveHouseholdMember {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                household: pda::household(&user).0,
+                user,
+            },
+            instruction::RemoveHouseholdMember { member: self.member },
+        )
+    }
+}
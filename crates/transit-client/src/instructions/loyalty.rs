@@ -0,0 +1,196 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Change loyalty earning and redemption rules, fields left `None` keep
+/// their current value
+#[derive(Clone, Debug)]
+pub struct UpdateLoyaltyConfig {
+    pub fare_manager: Pubkey,
+    pub mode_0_rate: Option<u16>,
+    pub mode_1_rate: Option<u16>,
+    pub subscription_rate: Option<u16>,
+    pub bonus_multiplier: Option<u16>,
+    pub bonus_start: Option<i64>,
+    pub bonus_end: Option<i64>,
+    pub free_ticket_cost: Option<u64>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UpdateLoyaltyConfig {
+    pub fn new(fare_manager: Pubkey) -> Self {
+        Self {
+            fare_manager,
+            mode_0_rate: None,
+            mode_1_rate: None,
+            subscription_rate: None,
+            bonus_multiplier: None,
+            bonus_start: None,
+            bonus_end: None,
+            free_ticket_cost: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::UpdateLoyaltyConfig {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+            },
+            instruction::UpdateLoyaltyConfig {
+                mode_0_rate: self.mode_0_rate,
+                mode_1_rate: self.mode_1_rate,
+                subscription_rate: self.subscription_rate,
+                bonus_multiplier: self.bonus_multiplier,
+                bonus_start: self.bonus_start,
+                bonus_end: self.bonus_end,
+                free_ticket_cost: self.free_ticket_cost,
+            },
+        )
+    }
+}
+
+/// Spend `points` of `user` on a LOYALTY_REWARD_* reward. A free ticket is
+/// issued as ticket `ticket_id` for `transport_mode`
+#[derive(Clone, Debug)]
+pub struct RedeemPoints {
+    pub user: Pubkey,
+    pub reward: u8,
+    pub points: u64,
+    pub transport_mode: u8,
+    pub ticket_id: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl RedeemPoints {
+    /// Redeem the free ticket cost for ticket `ticket_id`
+    pub fn free_ticket(user: Pubkey, points: u64, transport_mode: u8, ticket_id: u64) -> Self {
+        Self {
+            user,
+            reward: transit_fare_payment::LOYALTY_REWARD_FREE_TICKET,
+            points,
+            transport_mode,
+            ticket_id,
+            fee_payer: None,
+        }
+    }
+
+    /// Bank `points` as a discount on the next pass, one fare unit per point
+    pub fn pass_discount(user: Pubkey, points: u64) -> Self {
+        Self {
+            user,
+            reward: transit_fare_payment::LOYALTY_REWARD_PASS_DISCOUNT,
+            points,
+            transport_mode: 0,
+            ticket_id: 0,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        let ticket = (self.reward == transit_fare_payment::LOYALTY_REWARD_FREE_TICKET)
+            .then(|| pda::ticket(&user, self.ticket_id).0);
+        build(
+            accounts::RedeemPoints {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                ticket,
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::RedeemPoints {
+                reward: self.reward,
+                points: self.points,
+                transport_mode: self.transport_mode,
+                ticket_id: self.ticket_id,
+            },
+        )
+    }
+}
+
+/// Create a promotion redeemable with the code hashing to `code_hash`
+#[derive(Clone, Debug)]
+pub struct CreatePromotion {
+    pub fare_manager: Pubkey,
+    pub code_hash: [u8; 32],
+    /// PROMO_DISCOUNT_* value
+    pub discount_type: u8,
+    /// Basis points for percentages, fare units off the price for fixed amounts
+    pub discount_value: u64,
+    /// Bitmask of transport modes (1 << mode)
+    pub applicable_modes: u8,
+    /// Bitmask of PROMO_PRODUCT_* values
+    pub applicable_products: u8,
+    pub valid_from: i64,
+    pub valid_until: i64,
+    /// 0 = unlimited
+    pub max_redemptions: u32,
+    /// 0 = unlimited
+    pub max_per_user: u32,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreatePromotion {
+    /// Promotion valid on every mode and product with no redemption limits
+    pub fn new(
+        fare_manager: Pubkey,
+        code: &str,
+        discount_type: u8,
+        discount_value: u64,
+        valid_from: i64,
+        valid_until: i64,
+    ) -> Self {
+        Self {
+            fare_manager,
+            code_hash: crate::code_hash(code),
+            discount_type,
+            discount_value,
+            applicable_modes: transit_fare_payment::ALL_TRANSPORT_MODES,
+            applicable_products: transit_fare_payment::ALL_PROMO_PRODUCTS,
+            valid_from,
+            valid_until,
+            max_redemptions: 0,
+            max_per_user: 0,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::CreatePromotion {
+                fee_payer: self.fee_payer.unwrap_or(self.fare_manager),
+                fare_config: pda::fare_config().0,
+                promotion: pda::promotion(&self.code_hash).0,
+                roles: pda::roles().0,
+                fare_manager: self.fare_manager,
+                system_program: system_program::ID,
+            },
+            instruction::CreatePromotion {
+                code_hash: self.code_hash,
+                discount_type: self.discount_type,
+                discount_value: self.discount_value,
+                applicable_modes: self.applicable_modes,
+                applicable_products: self.applicable_products,
+                valid_from: self.valid_from,
+                valid_until: self.valid_until,
+                max_redemptions: self.max_redemptions,
+                max_per_user: self.max_per_user,
+            },
+        )
+    }
+}
@@ -0,0 +1,287 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Open the ride history ring buffer of `user`
+#[derive(Clone, Debug)]
+pub struct CreateRideHistory {
+    pub user: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreateRideHistory {
+    pub fn new(user: Pubkey) -> Self {
+        Self { user, fee_payer: None }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::CreateRideHistory {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                ride_history: pda::ride_history(&user).0,
+                user,
+                system_program: system_program::ID,
+            },
+            instruction::CreateRideHistory {},
+        )
+    }
+}
+
+/// Close ticket `ticket_id` of `user`, returning its rent to `rent_payer`
+#[derive(Clone, Debug)]
+pub struct CloseTicket {
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    /// Fee payer recorded on the ticket
+    pub rent_payer: Pubkey,
+    /// Pass the owner's passenger account, required for unused tickets
+    pub passenger: bool,
+    pub fee_payer: Pubkey,
+}
+
+impl CloseTicket {
+    pub fn new(user: Pubkey, ticket_id: u64, rent_payer: Pubkey, fee_payer: Pubkey) -> Self {
+        Self {
+            user,
+            ticket_id,
+            rent_payer,
+            passenger: true,
+            fee_payer,
+        }
+    }
+
+    pub fn passenger(mut self, passenger: bool) -> Self {
+        self.passenger = passenger;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::CloseTicket {
+                fee_payer: self.fee_payer,
+                ticket: pda::ticket(&self.user, self.ticket_id).0,
+                rent_payer: self.rent_payer,
+                passenger: self.passenger.then(|| pda::passenger(&self.user).0),
+            },
+            instruction::CloseTicket {},
+        )
+    }
+}
+
+/// Close receipt `payment_id` of `user`, returning its rent to `rent_payer`
+#[derive(Clone, Debug)]
+pub struct ClosePayment {
+    pub user: Pubkey,
+    pub payment_id: u64,
+    /// Fee payer recorded on the receipt
+    pub rent_payer: Pubkey,
+    pub fee_payer: Pubkey,
+}
+
+impl ClosePayment {
+    pub fn new(user: Pubkey, payment_id: u64, rent_payer: Pubkey, fee_payer: Pubkey) -> Self {
+        Self {
+            user,
+            payment_id,
+            rent_payer,
+            fee_payer,
+        }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ClosePayment {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+                payment: pda::payment(&self.user, self.payment_id).0,
+                rent_payer: self.rent_payer,
+            },
+            instruction::ClosePayment {},
+        )
+    }
+}
+
+/// Delete the passenger account of `user`, along with their ride history,
+/// stored-value escrow and household for each one that is set
+#[derive(Clone, Debug)]
+pub struct ClosePassenger {
+    pub user: Pubkey,
+    pub ride_history: bool,
+    pub stored_value: bool,
+    pub household: bool,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl ClosePassenger {
+    pub fn new(user: Pubkey) -> Self {
+        Self {
+            user,
+            ride_history: false,
+            stored_value: false,
+            household: false,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn ride_history(mut self, ride_history: bool) -> Self {
+        self.ride_history = ride_history;
+        self
+    }
+
+    pub fn stored_value(mut self, stored_value: bool) -> Self {
+        self.stored_value = stored_value;
+        self
+    }
+
+    pub fn household(mut self, household: bool) -> Self {
+        self.household = household;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::ClosePassenger {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                ride_history: self.ride_history.then(|| pda::ride_history(&user).0),
+                stored_value: self.stored_value.then(|| pda::stored_value(&user).0),
+                household: self.household.then(|| pda::household(&user).0),
+                user,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::ClosePassenger {},
+        )
+    }
+}
+
+/// Upgrade an unversioned fare config to the current layout
+#[derive(Clone, Debug)]
+pub struct MigrateFareConfig {
+    pub admin: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl MigrateFareConfig {
+    pub fn new(admin: Pubkey) -> Self {
+        Self { admin, fee_payer: None }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::MigrateFareConfig {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                roles: pda::roles().0,
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::MigrateFareConfig {},
+        )
+    }
+}
+
+/// Upgrade the unversioned passenger account of `user` to the current layout
+#[derive(Clone, Debug)]
+pub struct MigratePassenger {
+    pub user: Pubkey,
+    pub fee_payer: Pubkey,
+}
+
+impl MigratePassenger {
+    pub fn new(user: Pubkey, fee_payer: Pubkey) -> Self {
+        Self { user, fee_payer }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::MigratePassenger {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&self.user).0,
+                user: self.user,
+                system_program: system_program::ID,
+            },
+            instruction::MigratePassenger {},
+        )
+    }
+}
+
+/// Upgrade the unversioned ticket `ticket_id` of `user` to the current layout
+#[derive(Clone, Debug)]
+pub struct MigrateTicket {
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    pub fee_payer: Pubkey,
+}
+
+impl MigrateTicket {
+    pub fn new(user: Pubkey, ticket_id: u64, fee_payer: Pubkey) -> Self {
+        Self {
+            user,
+            ticket_id,
+            fee_payer,
+        }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::MigrateTicket {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&self.user).0,
+                ticket: pda::ticket(&self.user, self.ticket_id).0,
+                user: self.user,
+                system_program: system_program::ID,
+            },
+            instruction::MigrateTicket {
+                ticket_id: self.ticket_id,
+            },
+        )
+    }
+}
+
+/// Upgrade the unversioned receipt `payment_id` of `user` to the current layout
+#[derive(Clone, Debug)]
+pub struct MigratePayment {
+    pub user: Pubkey,
+    pub payment_id: u64,
+    pub fee_payer: Pubkey,
+}
+
+impl MigratePayment {
+    pub fn new(user: Pubkey, payment_id: u64, fee_payer: Pubkey) -> Self {
+        Self {
+            user,
+            payment_id,
+            fee_payer,
+        }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::MigratePayment {
+                fee_payer: self.fee_payer,
+                passenger: pda::passenger(&self.user).0,
+                payment: pda::payment(&self.user, self.payment_id).0,
+                user: self.user,
+                system_program: system_program::ID,
+            },
+            instruction::MigratePayment {
+                payment_id: self.payment_id,
+            },
+        )
+    }
+}
@@ -0,0 +1,131 @@
+//! Typed builders for every transit program instruction
+//!
+//! Each builder takes the accounts and arguments that cannot be derived in
+//! its constructor, fills in program-derived addresses itself and produces a
+//! ready-to-sign [`Instruction`]. The fee payer defaults to the main signer of
+//! the instruction and can be overridden with `fee_payer`. Counters stored on
+//! chain, such as the next payment receipt or role change id, are passed in
+//! explicitly; the RPC clients read them for you.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{pda, ID};
+
+mod admin;
+mod currencies;
+mod employers;
+mod gift_cards;
+mod households;
+mod loyalty;
+mod maintenance;
+mod multisig;
+mod stored_value;
+mod subscriptions;
+mod tickets;
+
+pub use admin::*;
+pub use currencies::*;
+pub use employers::*;
+pub use gift_cards::*;
+pub use households::*;
+pub use loyalty::*;
+pub use maintenance::*;
+pub use multisig::*;
+pub use stored_value::*;
+pub use subscriptions::*;
+pub use tickets::*;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Adds the `fee_payer` override shared by every builder
+macro_rules! fee_payer {
+    () => {
+        /// Pay transaction fees and rent from `fee_payer` instead of the main signer
+        pub fn fee_payer(mut self, fee_payer: solana_sdk::pubkey::Pubkey) -> Self {
+            self.fee_payer = Some(fee_payer);
+            self
+        }
+    };
+}
+use fee_payer;
+
+/// SPL token transfer a paid instruction makes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenPayment {
+    /// Base fare currency of the fare config
+    pub currency_mint: Pubkey,
+    /// Mint the tokens move in, `currency_mint` unless paying in an accepted currency
+    pub mint: Pubkey,
+    /// Token account debited
+    pub source: Pubkey,
+    /// Token account credited
+    pub destination: Pubkey,
+    /// Owner or delegate of `source`, signs the transaction
+    pub authority: Pubkey,
+}
+
+impl TokenPayment {
+    /// Transfer in the base fare currency
+    pub fn new(currency_mint: Pubkey, source: Pubkey, destination: Pubkey, authority: Pubkey) -> Self {
+        Self {
+            currency_mint,
+            mint: currency_mint,
+            source,
+            destination,
+            authority,
+        }
+    }
+
+    /// Transfer in `mint`, an accepted currency other than the base fare currency
+    pub fn in_mint(mut self, mint: Pubkey) -> Self {
+        self.mint = mint;
+        self
+    }
+}
+
+/// Discounts and alternative payment sources a purchase can use
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PurchaseOptions {
+    /// Promo code entered by the rider
+    pub promo_code: Option<String>,
+    /// Admin of an employer paying through the rider's commuter benefit
+    pub employer_admin: Option<Pubkey>,
+    /// Mint of the accepted currency the rider pays in
+    pub accepted_currency: Option<Pubkey>,
+    /// Price feed of the fare config, when fares are priced through one
+    pub price_feed: Option<Pubkey>,
+}
+
+impl PurchaseOptions {
+    fn promotion(&self, user: &Pubkey) -> (Option<Pubkey>, Option<Pubkey>) {
+        match &self.promo_code {
+            Some(code) => {
+                let promotion = pda::promotion(&crate::code_hash(code)).0;
+                (Some(promotion), Some(pda::promotion_redemption(&promotion, user).0))
+            }
+            None => (None, None),
+        }
+    }
+
+    fn employer(&self, user: &Pubkey) -> (Option<Pubkey>, Option<Pubkey>, Option<Pubkey>) {
+        match &self.employer_admin {
+            Some(admin) => {
+                let employer = pda::employer(admin).0;
+                (
+                    Some(employer),
+                    Some(pda::employee_benefit(&employer, user).0),
+                    Some(pda::employer_escrow(&employer).0),
+                )
+            }
+            None => (None, None, None),
+        }
+    }
+}
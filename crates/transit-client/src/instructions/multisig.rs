@@ -0,0 +1,180 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction, MultisigAction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Create the admin multisig of `owners`, executing proposals once
+/// `threshold` of them approve
+#[derive(Clone, Debug)]
+pub struct CreateMultisig {
+    pub admin: Pubkey,
+    pub owners: Vec<Pubkey>,
+    pub threshold: u8,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreateMultisig {
+    pub fn new(admin: Pubkey, owners: Vec<Pubkey>, threshold: u8) -> Self {
+        Self {
+            admin,
+            owners,
+            threshold,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::CreateMultisig {
+                fee_payer: self.fee_payer.unwrap_or(self.admin),
+                fare_config: pda::fare_config().0,
+                multisig: pda::multisig().0,
+                admin: self.admin,
+                system_program: system_program::ID,
+            },
+            instruction::CreateMultisig {
+                owners: self.owners.clone(),
+                threshold: self.threshold,
+            },
+        )
+    }
+}
+
+/// Propose `action` as proposal `proposal_id`, the multisig's `proposal_count`
+#[derive(Clone, Debug)]
+pub struct CreateProposal {
+    pub proposer: Pubkey,
+    pub proposal_id: u64,
+    pub action: MultisigAction,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CreateProposal {
+    pub fn new(proposer: Pubkey, proposal_id: u64, action: MultisigAction) -> Self {
+        Self {
+            proposer,
+            proposal_id,
+            action,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::CreateProposal {
+                fee_payer: self.fee_payer.unwrap_or(self.proposer),
+                multisig: pda::multisig().0,
+                proposal: pda::proposal(self.proposal_id).0,
+                proposer: self.proposer,
+                system_program: system_program::ID,
+            },
+            instruction::CreateProposal {
+                action: self.action.clone(),
+            },
+        )
+    }
+}
+
+/// Approve proposal `proposal_id` as multisig owner `owner`
+#[derive(Clone, Debug)]
+pub struct ApproveProposal {
+    pub owner: Pubkey,
+    pub proposal_id: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl ApproveProposal {
+    pub fn new(owner: Pubkey, proposal_id: u64) -> Self {
+        Self {
+            owner,
+            proposal_id,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ApproveProposal {
+                fee_payer: self.fee_payer.unwrap_or(self.owner),
+                multisig: pda::multisig().0,
+                proposal: pda::proposal(self.proposal_id).0,
+                owner: self.owner,
+            },
+            instruction::ApproveProposal {},
+        )
+    }
+}
+
+/// Carry out approved proposal `proposal_id`, whose `action` decides the
+/// accounts passed
+#[derive(Clone, Debug)]
+pub struct ExecuteProposal {
+    pub proposal_id: u64,
+    pub action: MultisigAction,
+    /// Next role change id, the roles' `change_count`, for actions that
+    /// record a role change
+    pub change_id: u64,
+    /// Mint of the treasury, required for withdrawals
+    pub mint: Option<Pubkey>,
+    pub fee_payer: Pubkey,
+}
+
+impl ExecuteProposal {
+    pub fn new(fee_payer: Pubkey, proposal_id: u64, action: MultisigAction) -> Self {
+        Self {
+            proposal_id,
+            action,
+            change_id: 0,
+            mint: None,
+            fee_payer,
+        }
+    }
+
+    pub fn change_id(mut self, change_id: u64) -> Self {
+        self.change_id = change_id;
+        self
+    }
+
+    pub fn mint(mut self, mint: Pubkey) -> Self {
+        self.mint = Some(mint);
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let role_change = match self.action {
+            MultisigAction::AcceptAdmin | MultisigAction::SetRole { .. } => Some(pda::role_change(self.change_id).0),
+            _ => None,
+        };
+        let (treasury, destination, token_program) = match self.action {
+            MultisigAction::Withdraw {
+                treasury, destination, ..
+            } => (Some(treasury), Some(destination), Some(anchor_spl::token::ID)),
+            _ => (None, None, None),
+        };
+        build(
+            accounts::ExecuteProposal {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+                multisig: pda::multisig().0,
+                proposal: pda::proposal(self.proposal_id).0,
+                roles: pda::roles().0,
+                role_change,
+                treasury,
+                destination,
+                mint: treasury.and(self.mint),
+                system_program: system_program::ID,
+                token_program,
+            },
+            instruction::ExecuteProposal {},
+        )
+    }
+}
@@ -0,0 +1,198 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer};
+use crate::pda;
+
+/// Load `amount` of `mint` from `source` into the stored-value balance of `user`
+#[derive(Clone, Debug)]
+pub struct TopUpStoredValue {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub source: Pubkey,
+    /// Owner or delegate of `source`
+    pub authority: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl TopUpStoredValue {
+    pub fn new(user: Pubkey, amount: u64, mint: Pubkey, source: Pubkey) -> Self {
+        Self {
+            user,
+            amount,
+            mint,
+            source,
+            authority: user,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.authority = authority;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::TopUpStoredValue {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                stored_value: pda::stored_value(&user).0,
+                user,
+                source: self.source,
+                mint: self.mint,
+                authority: self.authority,
+                system_program: system_program::ID,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::TopUpStoredValue { amount: self.amount },
+        )
+    }
+}
+
+/// Cash out the whole stored-value balance of `user` to `destination`
+#[derive(Clone, Debug)]
+pub struct WithdrawStoredValue {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl WithdrawStoredValue {
+    pub fn new(user: Pubkey, mint: Pubkey, destination: Pubkey) -> Self {
+        Self {
+            user,
+            mint,
+            destination,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::WithdrawStoredValue {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                stored_value: pda::stored_value(&user).0,
+                user,
+                destination: self.destination,
+                mint: self.mint,
+                token_program: anchor_spl::token::ID,
+            },
+            instruction::WithdrawStoredValue {},
+        )
+    }
+}
+
+/// Pay a `transport_mode` fare out of the stored-value balance of `user`
+#[derive(Clone, Debug)]
+pub struct PayFareFromBalance {
+    pub user: Pubkey,
+    pub transport_mode: u8,
+    /// Next receipt id, the passenger's `payment_count`
+    pub payment_id: u64,
+    pub max_price: u64,
+    pub mint: Pubkey,
+    /// Treasury token account owned by the admin
+    pub destination: Pubkey,
+    pub price_feed: Option<Pubkey>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl PayFareFromBalance {
+    pub fn new(
+        user: Pubkey,
+        transport_mode: u8,
+        payment_id: u64,
+        max_price: u64,
+        mint: Pubkey,
+        destination: Pubkey,
+    ) -> Self {
+        Self {
+            user,
+            transport_mode,
+            payment_id,
+            max_price,
+            mint,
+            destination,
+            price_feed: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn price_feed(mut self, price_feed: Pubkey) -> Self {
+        self.price_feed = Some(price_feed);
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::PayFareFromBalance {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                payment: pda::payment(&user, self.payment_id).0,
+                stored_value: pda::stored_value(&user).0,
+                user,
+                destination: self.destination,
+                mint: self.mint,
+                system_program: system_program::ID,
+                token_program: anchor_spl::token::ID,
+                price_feed: self.price_feed,
+            },
+            instruction::PayFareFromBalance {
+                transport_mode: self.transport_mode,
+                max_price: self.max_price,
+            },
+        )
+    }
+}
+
+/// Warn `user` once their stored-value balance drops below `threshold`
+#[derive(Clone, Debug)]
+pub struct SetLowBalanceThreshold {
+    pub user: Pubkey,
+    pub threshold: u64,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl SetLowBalanceThreshold {
+    pub fn new(user: Pubkey, threshold: u64) -> Self {
+        Self {
+            user,
+            threshold,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::SetLowBalanceThreshold {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                passenger: pda::passenger(&user).0,
+                user,
+            },
+            instruction::SetLowBalanceThreshold {
+                threshold: self.threshold,
+            },
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer, PurchaseOptions, TokenPayment};
+use crate::pda;
+
+/// Buy a monthly or yearly pass covering `seat_count` household seats
+#[derive(Clone, Debug)]
+pub struct PurchaseSubscription {
+    pub user: Pubkey,
+    pub subscription_type: u8,
+    pub seat_count: u8,
+    /// Next receipt id, the passenger's `payment_count` or the fare config's `total_receipts` for a new rider
+    pub payment_id: u64,
+    pub max_price: u64,
+    pub payment: TokenPayment,
+    pub options: PurchaseOptions,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl PurchaseSubscription {
+    pub fn new(user: Pubkey, subscription_type: u8, payment_id: u64, max_price: u64, payment: TokenPayment) -> Self {
+        Self {
+            user,
+            subscription_type,
+            seat_count: 1,
+            payment_id,
+            max_price,
+            payment,
+            options: PurchaseOptions::default(),
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn options(mut self, options: PurchaseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn seat_count(mut self, seat_count: u8) -> Self {
+        self.seat_count = seat_count;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        let (promotion, promotion_redemption) = self.options.promotion(&user);
+        let (employer, employee_benefit, employer_escrow) = self.options.employer(&user);
+        build(
+            accounts::PurchaseSubscription {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                payment: pda::payment(&user, self.payment_id).0,
+                user,
+                user_token_account: self.payment.source,
+                system_token_account: self.payment.destination,
+                currency_mint: self.payment.currency_mint,
+                system_program: system_program::ID,
+                source: self.payment.source,
+                mint: self.payment.mint,
+                destination: self.payment.destination,
+                authority: self.payment.authority,
+                token_program: anchor_spl::token::ID,
+                employer,
+                employee_benefit,
+                employer_escrow,
+                promotion,
+                promotion_redemption,
+                accepted_currency: self
+                    .options
+                    .accepted_currency
+                    .map(|mint| pda::accepted_currency(&mint).0),
+                price_feed: self.options.price_feed,
+            },
+            instruction::PurchaseSubscription {
+                subscription_type: self.subscription_type,
+                seat_count: self.seat_count,
+                max_price: self.max_price,
+                promo_code: self.options.promo_code.clone(),
+            },
+        )
+    }
+}
+
+/// Ride on `transport_mode` with the pass of `user`, or of `household_holder`
+/// when `user` rides as a household member
+#[derive(Clone, Debug)]
+pub struct UseSubscriptionRide {
+    pub user: Pubkey,
+    pub transport_mode: u8,
+    pub station_id: u32,
+    pub household_holder: Option<Pubkey>,
+    pub ride_history: bool,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UseSubscriptionRide {
+    pub fn new(user: Pubkey, transport_mode: u8, station_id: u32) -> Self {
+        Self {
+            user,
+            transport_mode,
+            station_id,
+            household_holder: None,
+            ride_history: false,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn household(mut self, holder: Pubkey) -> Self {
+        self.household_holder = Some(holder);
+        self
+    }
+
+    pub fn ride_history(mut self, ride_history: bool) -> Self {
+        self.ride_history = ride_history;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        let pass_holder = self.household_holder.unwrap_or(user);
+        build(
+            accounts::UseSubscriptionRide {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&pass_holder).0,
+                user,
+                household: self.household_holder.map(|holder| pda::household(&holder).0),
+                ride_history: self.ride_history.then(|| pda::ride_history(&user).0),
+            },
+            instruction::UseSubscriptionRide {
+                transport_mode: self.transport_mode,
+                station_id: self.station_id,
+            },
+        )
+    }
+}
+
+/// Cancel the active pass of `user` and refund the unused share
+#[derive(Clone, Debug)]
+pub struct CancelSubscription {
+    pub user: Pubkey,
+    /// Treasury to rider transfer, authorized by the treasury owner
+    pub refund: TokenPayment,
+    /// Mint the pass was paid in, when not the base currency
+    pub accepted_currency: Option<Pubkey>,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl CancelSubscription {
+    pub fn new(user: Pubkey, refund: TokenPayment) -> Self {
+        Self {
+            user,
+            refund,
+            accepted_currency: None,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn accepted_currency(mut self, mint: Pubkey) -> Self {
+        self.accepted_currency = Some(mint);
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::CancelSubscription {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                user,
+                user_token_account: self.refund.destination,
+                system_token_account: self.refund.source,
+                currency_mint: self.refund.currency_mint,
+                source: self.refund.source,
+                mint: self.refund.mint,
+                destination: self.refund.destination,
+                authority: self.refund.authority,
+                token_program: anchor_spl::token::ID,
+                accepted_currency: self.accepted_currency.map(|mint| pda::accepted_currency(&mint).0),
+            },
+            instruction::CancelSubscription {},
+        )
+    }
+}
+
+/// Clear the lapsed pass of `user`, paying the crank reward to `fee_payer`
+#[derive(Clone, Debug)]
+pub struct ExpireSubscription {
+    pub user: Pubkey,
+    pub fee_payer: Pubkey,
+}
+
+impl ExpireSubscription {
+    pub fn new(user: Pubkey, fee_payer: Pubkey) -> Self {
+        Self { user, fee_payer }
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        build(
+            accounts::ExpireSubscription {
+                fee_payer: self.fee_payer,
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&self.user).0,
+            },
+            instruction::ExpireSubscription {},
+        )
+    }
+}
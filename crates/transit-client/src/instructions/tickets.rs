@@ -0,0 +1,198 @@
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use transit_fare_payment::{accounts, instruction};
+
+use super::{build, fee_payer, PurchaseOptions, TokenPayment};
+use crate::pda;
+
+/// Buy single-ride ticket `ticket_id` for `transport_mode`
+#[derive(Clone, Debug)]
+pub struct PurchaseTicket {
+    pub user: Pubkey,
+    pub transport_mode: u8,
+    pub ticket_id: u64,
+    /// Next receipt id, the passenger's `payment_count` or the fare config's `total_receipts` for a new rider
+    pub payment_id: u64,
+    pub max_price: u64,
+    pub payment: TokenPayment,
+    pub options: PurchaseOptions,
+    /// Log the purchase in the rider's ride history
+    pub ride_history: bool,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl PurchaseTicket {
+    pub fn new(
+        user: Pubkey,
+        transport_mode: u8,
+        ticket_id: u64,
+        payment_id: u64,
+        max_price: u64,
+        payment: TokenPayment,
+    ) -> Self {
+        Self {
+            user,
+            transport_mode,
+            ticket_id,
+            payment_id,
+            max_price,
+            payment,
+            options: PurchaseOptions::default(),
+            ride_history: false,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn options(mut self, options: PurchaseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn ride_history(mut self, ride_history: bool) -> Self {
+        self.ride_history = ride_history;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        let (promotion, promotion_redemption) = self.options.promotion(&user);
+        let (employer, employee_benefit, employer_escrow) = self.options.employer(&user);
+        build(
+            accounts::PurchaseTicket {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                ticket: pda::ticket(&user, self.ticket_id).0,
+                payment: pda::payment(&user, self.payment_id).0,
+                user,
+                user_token_account: self.payment.source,
+                system_token_account: self.payment.destination,
+                currency_mint: self.payment.currency_mint,
+                system_program: system_program::ID,
+                source: self.payment.source,
+                mint: self.payment.mint,
+                destination: self.payment.destination,
+                authority: self.payment.authority,
+                token_program: anchor_spl::token::ID,
+                employer,
+                employee_benefit,
+                employer_escrow,
+                promotion,
+                promotion_redemption,
+                accepted_currency: self
+                    .options
+                    .accepted_currency
+                    .map(|mint| pda::accepted_currency(&mint).0),
+                price_feed: self.options.price_feed,
+                ride_history: self.ride_history.then(|| pda::ride_history(&user).0),
+            },
+            instruction::PurchaseTicket {
+                transport_mode: self.transport_mode,
+                ticket_id: self.ticket_id,
+                max_price: self.max_price,
+                promo_code: self.options.promo_code.clone(),
+            },
+        )
+    }
+}
+
+/// Board with ticket `ticket_id` at `station_id`
+#[derive(Clone, Debug)]
+pub struct UseTicket {
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    pub station_id: u32,
+    pub ride_history: bool,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl UseTicket {
+    pub fn new(user: Pubkey, ticket_id: u64, station_id: u32) -> Self {
+        Self {
+            user,
+            ticket_id,
+            station_id,
+            ride_history: false,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn ride_history(mut self, ride_history: bool) -> Self {
+        self.ride_history = ride_history;
+        self
+    }
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::UseTicket {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                ticket: pda::ticket(&user, self.ticket_id).0,
+                passenger: pda::passenger(&user).0,
+                user,
+                ride_history: self.ride_history.then(|| pda::ride_history(&user).0),
+            },
+            instruction::UseTicket {
+                ticket_id: self.ticket_id,
+                station_id: self.station_id,
+            },
+        )
+    }
+}
+
+/// Refund unused ticket `ticket_id` from the treasury to the rider
+#[derive(Clone, Debug)]
+pub struct RefundTicket {
+    pub user: Pubkey,
+    pub ticket_id: u64,
+    /// Fee payer recorded on the ticket, receives its rent
+    pub rent_payer: Pubkey,
+    /// Treasury to rider transfer, authorized by the treasury owner
+    pub refund: TokenPayment,
+    pub fee_payer: Option<Pubkey>,
+}
+
+impl RefundTicket {
+    pub fn new(user: Pubkey, ticket_id: u64, rent_payer: Pubkey, refund: TokenPayment) -> Self {
+        Self {
+            user,
+            ticket_id,
+            rent_payer,
+            refund,
+            fee_payer: None,
+        }
+    }
+
+    fee_payer!();
+
+    pub fn instruction(&self) -> Instruction {
+        let user = self.user;
+        build(
+            accounts::RefundTicket {
+                fee_payer: self.fee_payer.unwrap_or(user),
+                fare_config: pda::fare_config().0,
+                passenger: pda::passenger(&user).0,
+                ticket: pda::ticket(&user, self.ticket_id).0,
+                user,
+                user_token_account: self.refund.destination,
+                system_token_account: self.refund.source,
+                currency_mint: self.refund.currency_mint,
+                source: self.refund.source,
+                mint: self.refund.mint,
+                destination: self.refund.destination,
+                authority: self.refund.authority,
+                token_program: anchor_spl::token::ID,
+                rent_payer: self.rent_payer,
+            },
+            instruction::RefundTicket {
+                ticket_id: self.ticket_id,
+            },
+        )
+    }
+}
@@ -0,0 +1,41 @@
+//! Rust client for the transit fare payment program
+//!
+//! - [`instructions`]: typed builders producing ready-to-sign instructions
+//! - [`pda`]: addresses of every program account
+//! - [`decode`], [`decode_account`]: account deserialization from raw data
+//! - [`TransitClient`], [`AsyncTransitClient`]: account reads and transaction
+//!   sending over blocking or async RPC
+//! - [`Error`]: failures, with program error codes mapped back to
+//!   [`FarePaymentError`]
+//!
+//! Builders and decoders need no cluster, so they work the same against a
+//! local test validator, LiteSVM or a deployed program.
+
+mod accounts;
+mod error;
+pub mod instructions;
+pub mod pda;
+mod rpc;
+
+pub use accounts::{decode, decode_account, decode_ride_history, is_account};
+pub use error::{program_error, Error, Result};
+pub use rpc::{AsyncTransitClient, TransitClient};
+
+// Program types and constants, so most callers need no direct dependency on
+// the program crate
+pub use transit_fare_payment::{
+    self as program, AcceptedCurrency, EmployeeBenefit, Employer, FareConfig, FarePaymentError, FareSchedule, GiftCard,
+    Household, Multisig, MultisigAction, Passenger, Payment, Promotion, PromotionRedemption, Proposal, RetailPartner,
+    RideHistory, RoleChange, Roles, Ticket, ID,
+};
+
+/// Hash of a promo or gift card code, as stored on chain
+pub fn code_hash(code: &str) -> [u8; 32] {
+    solana_sdk::hash::hash(code.as_bytes()).to_bytes()
+}
+
+/// Keypair a gift card with claim code `code` is issued to; it signs the
+/// redemption alongside the rider
+pub fn gift_card_claim(code: &str) -> solana_sdk::signature::Keypair {
+    solana_sdk::signer::keypair::keypair_from_seed(&code_hash(code)).expect("hash is a 32 byte seed")
+}
@@ -0,0 +1,109 @@
+//! Program-derived addresses of every transit program account
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::ID;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &ID)
+}
+
+/// Global fare configuration
+pub fn fare_config() -> (Pubkey, u8) {
+    find(&[b"fare_config"])
+}
+
+/// Role holders
+pub fn roles() -> (Pubkey, u8) {
+    find(&[b"roles"])
+}
+
+/// Record of the `change_id`-th admin or role change
+pub fn role_change(change_id: u64) -> (Pubkey, u8) {
+    find(&[b"role_change", &change_id.to_le_bytes()])
+}
+
+/// Rider account of `user`
+pub fn passenger(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"passenger", user.as_ref()])
+}
+
+/// Ticket `ticket_id` of `user`
+pub fn ticket(user: &Pubkey, ticket_id: u64) -> (Pubkey, u8) {
+    find(&[b"ticket", user.as_ref(), &ticket_id.to_le_bytes()])
+}
+
+/// Payment receipt `payment_id` of `user`
+pub fn payment(user: &Pubkey, payment_id: u64) -> (Pubkey, u8) {
+    find(&[b"payment", user.as_ref(), &payment_id.to_le_bytes()])
+}
+
+/// Stored-value token escrow of `user`
+pub fn stored_value(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"stored_value", user.as_ref()])
+}
+
+/// Ride history ring buffer of `user`
+pub fn ride_history(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"ride_history", user.as_ref()])
+}
+
+/// Household of the pass holder `user`
+pub fn household(user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"household", user.as_ref()])
+}
+
+/// Employer account administered by `admin`
+pub fn employer(admin: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"employer", admin.as_ref()])
+}
+
+/// Token escrow funding `employer`'s benefits
+pub fn employer_escrow(employer: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"employer_escrow", employer.as_ref()])
+}
+
+/// Benefit of `employee` at `employer`
+pub fn employee_benefit(employer: &Pubkey, employee: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"employee_benefit", employer.as_ref(), employee.as_ref()])
+}
+
+/// Promotion for the code hashing to `code_hash`
+pub fn promotion(code_hash: &[u8; 32]) -> (Pubkey, u8) {
+    find(&[b"promotion", code_hash])
+}
+
+/// Redemptions of `promotion` by `user`
+pub fn promotion_redemption(promotion: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"promotion_redemption", promotion.as_ref(), user.as_ref()])
+}
+
+/// Gift card claimed by `claim_key`, see [`crate::gift_card_claim`]
+pub fn gift_card(claim_key: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"gift_card", claim_key.as_ref()])
+}
+
+/// Token escrow holding the value of `gift_card`
+pub fn gift_card_escrow(gift_card: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"gift_card_escrow", gift_card.as_ref()])
+}
+
+/// Retail partner registration of `partner`
+pub fn retail_partner(partner: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"retail_partner", partner.as_ref()])
+}
+
+/// Registry entry of the payment currency `mint`
+pub fn accepted_currency(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"accepted_currency", mint.as_ref()])
+}
+
+/// Admin multisig
+pub fn multisig() -> (Pubkey, u8) {
+    find(&[b"multisig"])
+}
+
+/// Multisig proposal `proposal_id`
+pub fn proposal(proposal_id: u64) -> (Pubkey, u8) {
+    find(&[b"proposal", &proposal_id.to_le_bytes()])
+}
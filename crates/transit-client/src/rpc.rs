@@ -0,0 +1,326 @@
+//! Blocking and async RPC clients for reading program accounts and sending
+//! transactions
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient as AsyncRpcClient;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use transit_fare_payment::{FareConfig, Multisig, Passenger, Payment, Proposal, RideHistory, Roles, Ticket};
+
+use crate::accounts::{decode, decode_account, decode_ride_history};
+use crate::{pda, Error, Result, ID};
+
+// Byte offset of the `user` field in Passenger, Ticket and Payment: the
+// discriminator and the version byte come first
+const USER_OFFSET: usize = 9;
+
+fn program_accounts_config<T: Discriminator>(user: Option<&Pubkey>) -> RpcProgramAccountsConfig {
+    let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &T::DISCRIMINATOR))];
+    if let Some(user) = user {
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            USER_OFFSET,
+            user.as_ref(),
+        )));
+    }
+    RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    }
+}
+
+fn decode_all<T: AccountDeserialize>(accounts: Vec<(Pubkey, Account)>) -> Result<Vec<(Pubkey, T)>> {
+    accounts
+        .into_iter()
+        .map(|(address, account)| Ok((address, decode_account(&address, &account)?)))
+        .collect()
+}
+
+fn found(address: &Pubkey, account: Option<Account>) -> Result<Account> {
+    account.ok_or(Error::AccountNotFound(*address))
+}
+
+fn transaction(instructions: &[Instruction], signers: &[&dyn Signer], blockhash: Hash) -> Transaction {
+    let payer = signers.first().map(|signer| signer.pubkey());
+    Transaction::new_signed_with_payer(instructions, payer.as_ref(), signers, blockhash)
+}
+
+/// Blocking client for the transit program
+pub struct TransitClient {
+    rpc: RpcClient,
+}
+
+impl TransitClient {
+    pub fn new(rpc: RpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// Client for the cluster at `url` with confirmed commitment
+    pub fn new_with_url(url: impl ToString) -> Self {
+        Self::new(RpcClient::new_with_commitment(
+            url.to_string(),
+            CommitmentConfig::confirmed(),
+        ))
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    /// Raw account at `address`, `None` when it does not exist
+    pub fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value)
+    }
+
+    /// Program account at `address` decoded as `T`
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        decode_account(address, &found(address, self.get_account(address)?)?)
+    }
+
+    pub fn fare_config(&self) -> Result<FareConfig> {
+        self.account(&pda::fare_config().0)
+    }
+
+    pub fn roles(&self) -> Result<Roles> {
+        self.account(&pda::roles().0)
+    }
+
+    pub fn passenger(&self, user: &Pubkey) -> Result<Passenger> {
+        self.account(&pda::passenger(user).0)
+    }
+
+    pub fn ticket(&self, user: &Pubkey, ticket_id: u64) -> Result<Ticket> {
+        self.account(&pda::ticket(user, ticket_id).0)
+    }
+
+    pub fn payment(&self, user: &Pubkey, payment_id: u64) -> Result<Payment> {
+        self.account(&pda::payment(user, payment_id).0)
+    }
+
+    pub fn ride_history(&self, user: &Pubkey) -> Result<RideHistory> {
+        let address = pda::ride_history(user).0;
+        decode_ride_history(&address, &found(&address, self.get_account(&address)?)?.data)
+    }
+
+    pub fn multisig(&self) -> Result<Multisig> {
+        self.account(&pda::multisig().0)
+    }
+
+    pub fn proposal(&self, proposal_id: u64) -> Result<Proposal> {
+        self.account(&pda::proposal(proposal_id).0)
+    }
+
+    /// Id of the next payment receipt of `user`; before their first purchase,
+    /// the fare config's receipt total
+    pub fn next_payment_id(&self, user: &Pubkey) -> Result<u64> {
+        let address = pda::passenger(user).0;
+        match self.get_account(&address)? {
+            Some(account) => Ok(decode::<Passenger>(&address, &account.data)?.payment_count),
+            None => Ok(self.fare_config()?.total_receipts),
+        }
+    }
+
+    /// Id of the next role change record
+    pub fn next_role_change_id(&self) -> Result<u64> {
+        Ok(self.roles()?.change_count)
+    }
+
+    /// Id of the next multisig proposal
+    pub fn next_proposal_id(&self) -> Result<u64> {
+        Ok(self.multisig()?.proposal_count)
+    }
+
+    /// Every program account of type `T`
+    pub fn all<T: AccountDeserialize + Discriminator>(&self) -> Result<Vec<(Pubkey, T)>> {
+        self.scan::<T>(None)
+    }
+
+    /// Tickets of `user`
+    pub fn tickets_of(&self, user: &Pubkey) -> Result<Vec<(Pubkey, Ticket)>> {
+        self.scan::<Ticket>(Some(user))
+    }
+
+    /// Payment receipts of `user`
+    pub fn payments_of(&self, user: &Pubkey) -> Result<Vec<(Pubkey, Payment)>> {
+        self.scan::<Payment>(Some(user))
+    }
+
+    fn scan<T: AccountDeserialize + Discriminator>(&self, user: Option<&Pubkey>) -> Result<Vec<(Pubkey, T)>> {
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(&ID, program_accounts_config::<T>(user))?;
+        decode_all(accounts)
+    }
+
+    /// Sign `instructions` with `signers`, the first paying the fees, send
+    /// and wait for confirmation
+    pub fn send(&self, instructions: &[Instruction], signers: &[&dyn Signer]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = transaction(instructions, signers, blockhash);
+        Ok(self.rpc.send_and_confirm_transaction(&transaction)?)
+    }
+
+    /// Simulate `instructions` signed by `signers` and return the program logs
+    pub fn simulate(&self, instructions: &[Instruction], signers: &[&dyn Signer]) -> Result<Vec<String>> {
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let result = self
+            .rpc
+            .simulate_transaction(&transaction(instructions, signers, blockhash))?
+            .value;
+        match result.err {
+            Some(error) => Err(error.into()),
+            None => Ok(result.logs.unwrap_or_default()),
+        }
+    }
+}
+
+/// Async client for the transit program
+pub struct AsyncTransitClient {
+    rpc: AsyncRpcClient,
+}
+
+impl AsyncTransitClient {
+    pub fn new(rpc: AsyncRpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// Client for the cluster at `url` with confirmed commitment
+    pub fn new_with_url(url: impl ToString) -> Self {
+        Self::new(AsyncRpcClient::new_with_commitment(
+            url.to_string(),
+            CommitmentConfig::confirmed(),
+        ))
+    }
+
+    pub fn rpc(&self) -> &AsyncRpcClient {
+        &self.rpc
+    }
+
+    /// Raw account at `address`, `None` when it does not exist
+    pub async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>> {
+        Ok(self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())
+            .await?
+            .value)
+    }
+
+    /// Program account at `address` decoded as `T`
+    pub async fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        decode_account(address, &found(address, self.get_account(address).await?)?)
+    }
+
+    pub async fn fare_config(&self) -> Result<FareConfig> {
+        self.account(&pda::fare_config().0).await
+    }
+
+    pub async fn roles(&self) -> Result<Roles> {
+        self.account(&pda::roles().0).await
+    }
+
+    pub async fn passenger(&self, user: &Pubkey) -> Result<Passenger> {
+        self.account(&pda::passenger(user).0).await
+    }
+
+    pub async fn ticket(&self, user: &Pubkey, ticket_id: u64) -> Result<Ticket> {
+        self.account(&pda::ticket(user, ticket_id).0).await
+    }
+
+    pub async fn payment(&self, user: &Pubkey, payment_id: u64) -> Result<Payment> {
+        self.account(&pda::payment(user, payment_id).0).await
+    }
+
+    pub async fn ride_history(&self, user: &Pubkey) -> Result<RideHistory> {
+        let address = pda::ride_history(user).0;
+        decode_ride_history(&address, &found(&address, self.get_account(&address).await?)?.data)
+    }
+
+    pub async fn multisig(&self) -> Result<Multisig> {
+        self.account(&pda::multisig().0).await
+    }
+
+    pub async fn proposal(&self, proposal_id: u64) -> Result<Proposal> {
+        self.account(&pda::proposal(proposal_id).0).await
+    }
+
+    /// Id of the next payment receipt of `user`; before their first purchase,
+    /// the fare config's receipt total
+    pub async fn next_payment_id(&self, user: &Pubkey) -> Result<u64> {
+        let address = pda::passenger(user).0;
+        match self.get_account(&address).await? {
+            Some(account) => Ok(decode::<Passenger>(&address, &account.data)?.payment_count),
+            None => Ok(self.fare_config().await?.total_receipts),
+        }
+    }
+
+    /// Id of the next role change record
+    pub async fn next_role_change_id(&self) -> Result<u64> {
+        Ok(self.roles().await?.change_count)
+    }
+
+    /// Id of the next multisig proposal
+    pub async fn next_proposal_id(&self) -> Result<u64> {
+        Ok(self.multisig().await?.proposal_count)
+    }
+
+    /// Every program account of type `T`
+    pub async fn all<T: AccountDeserialize + Discriminator>(&self) -> Result<Vec<(Pubkey, T)>> {
+        self.scan::<T>(None).await
+    }
+
+    /// Tickets of `user`
+    pub async fn tickets_of(&self, user: &Pubkey) -> Result<Vec<(Pubkey, Ticket)>> {
+        self.scan::<Ticket>(Some(user)).await
+    }
+
+    /// Payment receipts of `user`
+    pub async fn payments_of(&self, user: &Pubkey) -> Result<Vec<(Pubkey, Payment)>> {
+        self.scan::<Payment>(Some(user)).await
+    }
+
+    async fn scan<T: AccountDeserialize + Discriminator>(&self, user: Option<&Pubkey>) -> Result<Vec<(Pubkey, T)>> {
+        let accounts = self
+            .rpc
+            .get_program_accounts_with_config(&ID, program_accounts_config::<T>(user))
+            .await?;
+        decode_all(accounts)
+    }
+
+    /// Sign `instructions` with `signers`, the first paying the fees, send
+    /// and wait for confirmation
+    pub async fn send(&self, instructions: &[Instruction], signers: &[&dyn Signer]) -> Result<Signature> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let transaction = transaction(instructions, signers, blockhash);
+        Ok(self.rpc.send_and_confirm_transaction(&transaction).await?)
+    }
+
+    /// Simulate `instructions` signed by `signers` and return the program logs
+    pub async fn simulate(&self, instructions: &[Instruction], signers: &[&dyn Signer]) -> Result<Vec<String>> {
+        let blockhash = self.rpc.get_latest_blockhash().await?;
+        let result = self
+            .rpc
+            .simulate_transaction(&transaction(instructions, signers, blockhash))
+            .await?
+            .value;
+        match result.err {
+            Some(error) => Err(error.into()),
+            None => Ok(result.logs.unwrap_or_default()),
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use anchor_lang::{AccountSerialize, Discriminator};
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::state::{Account as TokenState, AccountState, Mint};
+use litesvm::LiteSVM;
+use solana_sdk::account::Account;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use transit_client::instructions::{
+    ApproveProposal, CreateMultisig, CreateProposal, ExecuteProposal, InitializeFareConfig, ProposeAdmin,
+    PurchaseOptions, PurchaseTicket, TokenPayment, UpdateFareConfig,
+};
+use transit_client::{
+    decode_account, pda, program, Error, FareConfig, FarePaymentError, MultisigAction, Passenger, Proposal, Ticket, ID,
+};
+
+fn ticket(user: Pubkey) -> Ticket {
+    Ticket {
+        version: Ticket::VERSION,
+        user,
+        ticket_id: 7,
+        transport_mode: program::TRANSPORT_MODE_TRAIN,
+        fare_amount: 350,
+        currency_mint: Pubkey::new_unique(),
+        purchase_timestamp: 1_700_000_000,
+        status: 0,
+        sponsor_escrow: Pubkey::default(),
+        loyalty_points_awarded: 3,
+        expires_at: 0,
+        rent_payer: user,
+        bump: pda::ticket(&user, 7).1,
+    }
+}
+
+fn program_account(svm: &LiteSVM, value: &impl AccountSerialize) -> Account {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[test]
+fn purchase_ticket_accounts() {
+    let user = Pubkey::new_unique();
+    let payment = TokenPayment::new(Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), user);
+    let instruction = PurchaseTicket::new(user, program::TRANSPORT_MODE_BUS, 7, 2, 300, payment)
+        .options(PurchaseOptions {
+            promo_code: Some("SPRING".to_string()),
+            ..PurchaseOptions::default()
+        })
+        .instruction();
+
+    assert_eq!(instruction.program_id, ID);
+    assert_eq!(
+        instruction.data[..8],
+        program::instruction::PurchaseTicket::DISCRIMINATOR
+    );
+
+    let fee_payer = &instruction.accounts[0];
+    assert_eq!(fee_payer.pubkey, user);
+    assert!(fee_payer.is_signer && fee_payer.is_writable);
+    assert_eq!(instruction.accounts[3].pubkey, pda::ticket(&user, 7).0);
+    assert_eq!(instruction.accounts[4].pubkey, pda::payment(&user, 2).0);
+
+    // Unused optional accounts are passed as the program id
+    let promotion = pda::promotion(&transit_client::code_hash("SPRING")).0;
+    let optional: Vec<Pubkey> = instruction.accounts[15..].iter().map(|meta| meta.pubkey).collect();
+    let redemption = pda::promotion_redemption(&promotion, &user).0;
+    assert_eq!(optional, vec![ID, ID, ID, promotion, redemption, ID, ID, ID]);
+}
+
+#[test]
+fn accounts_decode_from_litesvm() {
+    let mut svm = LiteSVM::new();
+    let user = Pubkey::new_unique();
+    let address = pda::ticket(&user, 7).0;
+    let stored = ticket(user);
+    svm.set_account(address, program_account(&svm, &stored)).unwrap();
+
+    let account = svm.get_account(&address).unwrap();
+    let decoded: Ticket = decode_account(&address, &account).unwrap();
+    assert_eq!(decoded.user, user);
+    assert_eq!(decoded.fare_amount, 350);
+    assert!(transit_client::is_account::<Ticket>(&account.data));
+
+    assert!(matches!(
+        decode_account::<Passenger>(&address, &account),
+        Err(Error::InvalidAccount { .. })
+    ));
+    let foreign = Account {
+        owner: Pubkey::new_unique(),
+        ..account
+    };
+    assert!(matches!(
+        decode_account::<Ticket>(&address, &foreign),
+        Err(Error::InvalidAccount { .. })
+    ));
+}
+
+fn load_program() -> LiteSVM {
+    let mut svm = LiteSVM::new();
+    svm.add_program_from_file(
+        ID,
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../target/deploy/transit_fare_payment.so"
+        ),
+    )
+    .unwrap();
+    svm
+}
+
+/// Send `instructions` paid by the first signer, on a fresh blockhash so a
+/// repeated instruction is not rejected as a duplicate transaction
+fn send(svm: &mut LiteSVM, instructions: &[Instruction], signers: &[&Keypair]) -> Result<(), Error> {
+    svm.expire_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&signers[0].pubkey()),
+        signers,
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(transaction)
+        .map(|_| ())
+        .map_err(|failed| failed.err.into())
+}
+
+fn token_account(svm: &mut LiteSVM, mint: Pubkey, owner: Pubkey, amount: u64) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let mut data = vec![0; TokenState::LEN];
+    TokenState {
+        mint,
+        owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenState::default()
+    }
+    .pack_into_slice(&mut data);
+    let account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(address, account).unwrap();
+    address
+}
+
+fn token_balance(svm: &LiteSVM, address: &Pubkey) -> u64 {
+    TokenState::unpack(&svm.get_account(address).unwrap().data).unwrap().amount
+}
+
+/// Initialize the program and hand the admin role to a 2-of-3 multisig of
+/// the returned owners
+fn multisig_admin(svm: &mut LiteSVM) -> [Keypair; 3] {
+    let admin = Keypair::new();
+    let owners = [Keypair::new(), Keypair::new(), Keypair::new()];
+    for signer in std::iter::once(&admin).chain(&owners) {
+        svm.airdrop(&signer.pubkey(), 10_000_000_000).unwrap();
+    }
+    let multisig = pda::multisig().0;
+
+    let initialize = InitializeFareConfig::new(admin.pubkey(), Pubkey::new_unique(), 250, 400, 9_000, 90_000);
+    let create = CreateMultisig::new(admin.pubkey(), owners.iter().map(Keypair::pubkey).collect(), 2);
+    let propose = ProposeAdmin::new(admin.pubkey(), multisig);
+    send(
+        svm,
+        &[initialize.instruction(), create.instruction(), propose.instruction()],
+        &[&admin],
+    )
+    .unwrap();
+
+    let accept = CreateProposal::new(owners[0].pubkey(), 0, MultisigAction::AcceptAdmin);
+    let approve = ApproveProposal::new(owners[1].pubkey(), 0);
+    let execute = ExecuteProposal::new(owners[0].pubkey(), 0, MultisigAction::AcceptAdmin).change_id(0);
+    send(svm, &[accept.instruction()], &[&owners[0]]).unwrap();
+    send(svm, &[approve.instruction()], &[&owners[1]]).unwrap();
+    send(svm, &[execute.instruction()], &[&owners[0]]).unwrap();
+    assert_eq!(fare_config(svm).admin, multisig);
+
+    owners
+}
+
+fn fare_config(svm: &LiteSVM) -> FareConfig {
+    let address = pda::fare_config().0;
+    decode_account(&address, &svm.get_account(&address).unwrap()).unwrap()
+}
+
+fn proposal(svm: &LiteSVM, proposal_id: u64) -> Proposal {
+    let address = pda::proposal(proposal_id).0;
+    decode_account(&address, &svm.get_account(&address).unwrap()).unwrap()
+}
+
+#[test]
+#[ignore = "needs target/deploy/transit_fare_payment.so from anchor build"]
+fn program_errors_map_to_fare_payment_errors() {
+    let mut svm = load_program();
+    let admin = Keypair::new();
+    let stranger = Keypair::new();
+    svm.airdrop(&admin.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&stranger.pubkey(), 10_000_000_000).unwrap();
+
+    let initialize = InitializeFareConfig::new(admin.pubkey(), Pubkey::new_unique(), 250, 400, 9_000, 90_000);
+    let transaction = Transaction::new_signed_with_payer(
+        &[initialize.instruction()],
+        Some(&admin.pubkey()),
+        &[&admin],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(transaction).unwrap();
+    let fare_config = pda::fare_config().0;
+    let config: transit_client::FareConfig =
+        decode_account(&fare_config, &svm.get_account(&fare_config).unwrap()).unwrap();
+    assert_eq!(config.admin, admin.pubkey());
+    assert_eq!(config.train_fare, 400);
+
+    let mut update = UpdateFareConfig::new(stranger.pubkey());
+    update.cancellation_fee = Some(100);
+    let transaction = Transaction::new_signed_with_payer(
+        &[update.instruction()],
+        Some(&stranger.pubkey()),
+        &[&stranger],
+        svm.latest_blockhash(),
+    );
+    let failed = svm.send_transaction(transaction).unwrap_err();
+    assert!(matches!(
+        Error::from(failed.err),
+        Error::Program(FarePaymentError::Unauthorized)
+    ));
+}
+
+#[test]
+#[ignore = "needs target/deploy/transit_fare_payment.so from anchor build"]
+fn proposals_execute_once_after_the_threshold() {
+    let mut svm = load_program();
+    let owners = multisig_admin(&mut svm);
+    let guardian = Pubkey::new_unique();
+    let action = MultisigAction::SetGuardian { guardian };
+    let execute = [ExecuteProposal::new(owners[2].pubkey(), 1, action.clone()).instruction()];
+
+    // The proposer's own approval is one short of the threshold
+    let create = CreateProposal::new(owners[0].pubkey(), 1, action);
+    send(&mut svm, &[create.instruction()], &[&owners[0]]).unwrap();
+    assert!(matches!(
+        send(&mut svm, &execute, &[&owners[2]]),
+        Err(Error::Program(FarePaymentError::ThresholdNotReached))
+    ));
+
+    // Approving twice does not count twice
+    let again = ApproveProposal::new(owners[0].pubkey(), 1).instruction();
+    assert!(matches!(
+        send(&mut svm, &[again], &[&owners[0]]),
+        Err(Error::Program(FarePaymentError::ProposalAlreadyApproved))
+    ));
+    assert_eq!(proposal(&svm, 1).approvals.count_ones(), 1);
+    assert_eq!(fare_config(&svm).guardian, Pubkey::default());
+
+    let approve = ApproveProposal::new(owners[1].pubkey(), 1).instruction();
+    send(&mut svm, &[approve], &[&owners[1]]).unwrap();
+    send(&mut svm, &execute, &[&owners[2]]).unwrap();
+    assert_eq!(fare_config(&svm).guardian, guardian);
+    assert!(proposal(&svm, 1).executed);
+
+    // An executed proposal can neither run again nor collect approvals
+    assert!(matches!(
+        send(&mut svm, &execute, &[&owners[2]]),
+        Err(Error::Program(FarePaymentError::ProposalAlreadyExecuted))
+    ));
+    let late = ApproveProposal::new(owners[2].pubkey(), 1).instruction();
+    assert!(matches!(
+        send(&mut svm, &[late], &[&owners[2]]),
+        Err(Error::Program(FarePaymentError::ProposalAlreadyExecuted))
+    ));
+}
+
+#[test]
+#[ignore = "needs target/deploy/transit_fare_payment.so from anchor build"]
+fn withdrawals_use_the_approved_accounts() {
+    let mut svm = load_program();
+    let owners = multisig_admin(&mut svm);
+    let multisig = pda::multisig().0;
+
+    let mint = Pubkey::new_unique();
+    let mut data = vec![0; Mint::LEN];
+    Mint {
+        supply: 1_000,
+        decimals: 6,
+        is_initialized: true,
+        ..Mint::default()
+    }
+    .pack_into_slice(&mut data);
+    let account = Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(mint, account).unwrap();
+    let treasury = token_account(&mut svm, mint, multisig, 1_000);
+    let destination = token_account(&mut svm, mint, Pubkey::new_unique(), 0);
+    let other = token_account(&mut svm, mint, Pubkey::new_unique(), 0);
+
+    let action = MultisigAction::Withdraw {
+        treasury,
+        destination,
+        amount: 400,
+    };
+    let create = CreateProposal::new(owners[0].pubkey(), 1, action.clone());
+    let approve = ApproveProposal::new(owners[1].pubkey(), 1);
+    send(&mut svm, &[create.instruction()], &[&owners[0]]).unwrap();
+    send(&mut svm, &[approve.instruction()], &[&owners[1]]).unwrap();
+
+    // Swapping in a destination the owners did not approve is refused
+    let execute = ExecuteProposal::new(owners[0].pubkey(), 1, action).mint(mint).instruction();
+    let mut redirected = execute.clone();
+    let index = redirected
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == destination)
+        .unwrap();
+    redirected.accounts[index].pubkey = other;
+    assert!(matches!(
+        send(&mut svm, &[redirected], &[&owners[0]]),
+        Err(Error::Program(FarePaymentError::InvalidActionAccounts))
+    ));
+    assert!(!proposal(&svm, 1).executed);
+
+    send(&mut svm, &[execute], &[&owners[0]]).unwrap();
+    assert_eq!(token_balance(&svm, &treasury), 600);
+    assert_eq!(token_balance(&svm, &destination), 400);
+    assert_eq!(token_balance(&svm, &other), 0);
+}
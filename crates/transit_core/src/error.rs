@@ -0,0 +1,43 @@
+use std::fmt;
+
+/// Reasons a fare computation or validation fails
+///
+/// Each variant has a `FarePaymentError` counterpart of the same name in the
+/// on-chain program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoreError {
+	InvalidTransportMode,
+	InvalidSubscriptionType,
+	InvalidSeatCount,
+	InvalidAmount,
+	InvalidPriceFeed,
+	StalePrice,
+	PriceConfidenceTooWide,
+	FareNoticeTooShort,
+	TransportModeNotAllowed,
+	PeriodRideLimitReached,
+	DailyRideLimitReached,
+}
+
+pub type Result<T> = std::result::Result<T, CoreError>;
+
+impl fmt::Display for CoreError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let msg = match self {
+			CoreError::InvalidTransportMode => "Invalid transport mode.",
+			CoreError::InvalidSubscriptionType => "Invalid subscription type. Must be 1 (monthly) or 2 (yearly).",
+			CoreError::InvalidSeatCount => "Invalid seat count for a subscription.",
+			CoreError::InvalidAmount => "Invalid payment amount.",
+			CoreError::InvalidPriceFeed => "Price feed account is missing or malformed.",
+			CoreError::StalePrice => "Oracle price is stale.",
+			CoreError::PriceConfidenceTooWide => "Oracle price confidence interval is too wide.",
+			CoreError::FareNoticeTooShort => "Fare change does not give the minimum notice.",
+			CoreError::TransportModeNotAllowed => "Subscription is not valid on this transport mode.",
+			CoreError::PeriodRideLimitReached => "Subscription ride limit reached for this period.",
+			CoreError::DailyRideLimitReached => "Daily subscription ride limit reached.",
+		};
+		f.write_str(msg)
+	}
+}
+
+impl std::error::Error for CoreError {}
@@ -0,0 +1,184 @@
+use crate::{CoreError, Result};
+
+/// Fares and pass prices in force at some point in time
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FareTable {
+	pub bus_fare: u64,
+	pub train_fare: u64,
+	pub monthly_pass_price: u64,
+	pub yearly_pass_price: u64,
+}
+
+impl FareTable {
+	/// Single-ride fare for `transport_mode`
+	pub fn fare(&self, transport_mode: u8) -> Result<u64> {
+		validate_transport_mode(transport_mode)?;
+		Ok(if transport_mode == crate::TRANSPORT_MODE_BUS {
+			self.bus_fare
+		} else {
+			self.train_fare
+		})
+	}
+
+	/// Price and duration of one seat on a pass of `subscription_type`
+	pub fn pass(&self, subscription_type: u8) -> Result<(u64, i64)> {
+		let price = match subscription_type {
+			crate::SUBSCRIPTION_MONTHLY => self.monthly_pass_price,
+			crate::SUBSCRIPTION_YEARLY => self.yearly_pass_price,
+			_ => return Err(CoreError::InvalidSubscriptionType),
+		};
+		Ok((price, pass_duration(subscription_type)?))
+	}
+
+	/// Price and duration of a pass covering `seat_count` riders, every seat
+	/// costing one pass
+	pub fn household_pass(&self, subscription_type: u8, seat_count: u8) -> Result<(u64, i64)> {
+		validate_seat_count(seat_count)?;
+		let (seat_price, duration) = self.pass(subscription_type)?;
+		let price = seat_price
+			.checked_mul(seat_count as u64)
+			.ok_or(CoreError::InvalidAmount)?;
+		Ok((price, duration))
+	}
+}
+
+/// Fail unless `transport_mode` is bus or train
+pub fn validate_transport_mode(transport_mode: u8) -> Result<()> {
+	if (transport_mode as usize) < crate::TRANSPORT_MODE_COUNT {
+		Ok(())
+	} else {
+		Err(CoreError::InvalidTransportMode)
+	}
+}
+
+/// Fail unless `subscription_type` is monthly or yearly
+pub fn validate_subscription_type(subscription_type: u8) -> Result<()> {
+	pass_duration(subscription_type).map(|_| ())
+}
+
+/// Fail unless a pass can cover `seat_count` riders
+pub fn validate_seat_count(seat_count: u8) -> Result<()> {
+	if (1..=crate::MAX_HOUSEHOLD_SEATS).contains(&seat_count) {
+		Ok(())
+	} else {
+		Err(CoreError::InvalidSeatCount)
+	}
+}
+
+/// Seconds a pass of `subscription_type` lasts
+pub fn pass_duration(subscription_type: u8) -> Result<i64> {
+	match subscription_type {
+		crate::SUBSCRIPTION_MONTHLY => Ok(crate::SECONDS_PER_MONTH),
+		crate::SUBSCRIPTION_YEARLY => Ok(crate::SECONDS_PER_YEAR),
+		_ => Err(CoreError::InvalidSubscriptionType),
+	}
+}
+
+/// Whether a pass restricted to the `allowed_modes` bitmask covers `transport_mode`
+pub fn mode_allowed(allowed_modes: u8, transport_mode: u8) -> bool {
+	(transport_mode as usize) < crate::TRANSPORT_MODE_COUNT && allowed_modes & (1 << transport_mode) != 0
+}
+
+/// Expiry timestamp for a ticket bought at `current_time`, 0 when tickets
+/// never expire
+pub fn ticket_expiry(validity_period: i64, current_time: i64) -> i64 {
+	if validity_period == 0 {
+		0
+	} else {
+		current_time + validity_period
+	}
+}
+
+/// Fail unless a fare change at `effective_at` is in the future and gives
+/// at least `min_notice` seconds of notice
+pub fn validate_fare_notice(effective_at: i64, current_time: i64, min_notice: i64) -> Result<()> {
+	if effective_at > current_time && effective_at - current_time >= min_notice {
+		Ok(())
+	} else {
+		Err(CoreError::FareNoticeTooShort)
+	}
+}
+
+/// Price after a promotion of `discount_type` worth `discount_value`.
+/// Percentages are in basis points, fixed amounts in the same units as
+/// `price`, and free rides cost nothing.
+pub fn promo_price(price: u64, discount_type: u8, discount_value: u64) -> u64 {
+	match discount_type {
+		crate::PROMO_DISCOUNT_PERCENTAGE => {
+			let discount = price as u128 * discount_value as u128 / crate::BASIS_POINTS as u128;
+			price.saturating_sub(discount.min(u64::MAX as u128) as u64)
+		}
+		crate::PROMO_DISCOUNT_FIXED => price.saturating_sub(discount_value),
+		_ => 0,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FARES: FareTable = FareTable {
+		bus_fare: 250,
+		train_fare: 400,
+		monthly_pass_price: 9_000,
+		yearly_pass_price: 90_000,
+	};
+
+	#[test]
+	fn fare_by_mode() {
+		assert_eq!(FARES.fare(crate::TRANSPORT_MODE_BUS), Ok(250));
+		assert_eq!(FARES.fare(crate::TRANSPORT_MODE_TRAIN), Ok(400));
+		assert_eq!(FARES.fare(2), Err(CoreError::InvalidTransportMode));
+	}
+
+	#[test]
+	fn pass_price_and_duration() {
+		assert_eq!(FARES.pass(crate::SUBSCRIPTION_MONTHLY), Ok((9_000, crate::SECONDS_PER_MONTH)));
+		assert_eq!(FARES.pass(crate::SUBSCRIPTION_YEARLY), Ok((90_000, crate::SECONDS_PER_YEAR)));
+		assert_eq!(FARES.pass(0), Err(CoreError::InvalidSubscriptionType));
+		assert_eq!(FARES.pass(3), Err(CoreError::InvalidSubscriptionType));
+	}
+
+	#[test]
+	fn household_pass_charges_every_seat() {
+		assert_eq!(FARES.household_pass(crate::SUBSCRIPTION_MONTHLY, 3), Ok((27_000, crate::SECONDS_PER_MONTH)));
+		assert_eq!(FARES.household_pass(crate::SUBSCRIPTION_MONTHLY, 0), Err(CoreError::InvalidSeatCount));
+		assert_eq!(
+			FARES.household_pass(crate::SUBSCRIPTION_MONTHLY, crate::MAX_HOUSEHOLD_SEATS + 1),
+			Err(CoreError::InvalidSeatCount)
+		);
+
+		let expensive = FareTable { monthly_pass_price: u64::MAX, ..FARES };
+		assert_eq!(expensive.household_pass(crate::SUBSCRIPTION_MONTHLY, 2), Err(CoreError::InvalidAmount));
+	}
+
+	#[test]
+	fn allowed_modes() {
+		assert!(mode_allowed(crate::ALL_TRANSPORT_MODES, crate::TRANSPORT_MODE_TRAIN));
+		assert!(mode_allowed(1 << crate::TRANSPORT_MODE_BUS, crate::TRANSPORT_MODE_BUS));
+		assert!(!mode_allowed(1 << crate::TRANSPORT_MODE_BUS, crate::TRANSPORT_MODE_TRAIN));
+		assert!(!mode_allowed(u8::MAX, 7));
+	}
+
+	#[test]
+	fn fare_notice() {
+		assert_eq!(validate_fare_notice(1_100, 1_000, 100), Ok(()));
+		assert_eq!(validate_fare_notice(1_099, 1_000, 100), Err(CoreError::FareNoticeTooShort));
+		assert_eq!(validate_fare_notice(1_000, 1_000, 0), Err(CoreError::FareNoticeTooShort));
+	}
+
+	#[test]
+	fn ticket_expiry_is_optional() {
+		assert_eq!(ticket_expiry(0, 1_000), 0);
+		assert_eq!(ticket_expiry(3_600, 1_000), 4_600);
+	}
+
+	#[test]
+	fn promotions() {
+		assert_eq!(promo_price(1_000, crate::PROMO_DISCOUNT_PERCENTAGE, 2_500), 750);
+		assert_eq!(promo_price(1_000, crate::PROMO_DISCOUNT_PERCENTAGE, 20_000), 0);
+		assert_eq!(promo_price(1_000, crate::PROMO_DISCOUNT_FIXED, 300), 700);
+		assert_eq!(promo_price(1_000, crate::PROMO_DISCOUNT_FIXED, 3_000), 0);
+		assert_eq!(promo_price(1_000, crate::PROMO_DISCOUNT_FREE_RIDE, 0), 0);
+	}
+}
@@ -0,0 +1,45 @@
+//! Fare computation, proration and validation for the transit fare system.
+//!
+//! Everything here is plain Rust with no Anchor or Solana dependency, so the
+//! on-chain program, off-chain clients and tests all price fares the same way.
+
+mod error;
+mod fares;
+mod loyalty;
+mod pricing;
+mod proration;
+mod rides;
+
+pub use error::*;
+pub use fares::*;
+pub use loyalty::*;
+pub use pricing::*;
+pub use proration::*;
+pub use rides::*;
+
+// Transport mode constants
+pub const TRANSPORT_MODE_BUS: u8 = 0;
+pub const TRANSPORT_MODE_TRAIN: u8 = 1;
+pub const TRANSPORT_MODE_COUNT: usize = 2;
+
+// Bitmask covering every transport mode, used as the default for subscriptions
+pub const ALL_TRANSPORT_MODES: u8 = (1 << TRANSPORT_MODE_COUNT) - 1;
+
+// Subscription types
+pub const SUBSCRIPTION_MONTHLY: u8 = 1;
+pub const SUBSCRIPTION_YEARLY: u8 = 2;
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const SECONDS_PER_MONTH: i64 = 30 * SECONDS_PER_DAY;
+pub const SECONDS_PER_YEAR: i64 = 365 * SECONDS_PER_DAY;
+
+// Household passes cover the holder plus up to MAX_HOUSEHOLD_SEATS - 1 members
+pub const MAX_HOUSEHOLD_SEATS: u8 = 6;
+
+// Denominator for rates and multipliers expressed in basis points
+pub const BASIS_POINTS: u64 = 10_000;
+
+// Promotion discount types
+pub const PROMO_DISCOUNT_PERCENTAGE: u8 = 0;
+pub const PROMO_DISCOUNT_FIXED: u8 = 1;
+pub const PROMO_DISCOUNT_FREE_RIDE: u8 = 2;
@@ -0,0 +1,29 @@
+/// Loyalty points earned for paying `amount` at `rate` points per
+/// BASIS_POINTS fare units, multiplied by `bonus_multiplier` basis points
+/// while `current_time` is in the bonus window [bonus_start, bonus_end)
+pub fn loyalty_points(amount: u64, rate: u16, bonus_multiplier: u16, bonus_start: i64, bonus_end: i64, current_time: i64) -> u64 {
+	let mut points = amount as u128 * rate as u128 / crate::BASIS_POINTS as u128;
+	if current_time >= bonus_start && current_time < bonus_end {
+		points = points * bonus_multiplier as u128 / crate::BASIS_POINTS as u128;
+	}
+	points.min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn base_rate() {
+		// 1% of the amount paid
+		assert_eq!(loyalty_points(25_000, 100, 20_000, 0, 0, 1_000), 250);
+		assert_eq!(loyalty_points(25_000, 0, 20_000, 0, 0, 1_000), 0);
+	}
+
+	#[test]
+	fn bonus_window_is_half_open() {
+		assert_eq!(loyalty_points(25_000, 100, 20_000, 1_000, 2_000, 999), 250);
+		assert_eq!(loyalty_points(25_000, 100, 20_000, 1_000, 2_000, 1_000), 500);
+		assert_eq!(loyalty_points(25_000, 100, 20_000, 1_000, 2_000, 2_000), 250);
+	}
+}
@@ -0,0 +1,147 @@
+use crate::{CoreError, Result};
+
+/// Oracle price of one whole currency token in fiat fare units
+///
+/// The account data uses a fixed little-endian layout so any oracle adapter,
+/// or a local test stand-in, can write it:
+///
+/// | offset | size | field            |
+/// |--------|------|------------------|
+/// | 0      | 4    | magic "TPF1"     |
+/// | 4      | 4    | exponent i32     |
+/// | 8      | 8    | price i64        |
+/// | 16     | 8    | confidence u64   |
+/// | 24     | 8    | publish_time i64 |
+///
+/// The token price is `price * 10^exponent` fare units, +/- the confidence
+/// scaled the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeed {
+	pub exponent: i32,
+	pub price: i64,
+	pub confidence: u64,
+	pub publish_time: i64,
+}
+
+impl PriceFeed {
+	pub const MAGIC: [u8; 4] = *b"TPF1";
+	pub const LEN: usize = 32;
+
+	/// Parse a price feed from raw account data
+	pub fn unpack(data: &[u8]) -> Result<Self> {
+		if data.len() < Self::LEN || data[0..4] != Self::MAGIC {
+			return Err(CoreError::InvalidPriceFeed);
+		}
+
+		Ok(Self {
+			exponent: i32::from_le_bytes(data[4..8].try_into().unwrap()),
+			price: i64::from_le_bytes(data[8..16].try_into().unwrap()),
+			confidence: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+			publish_time: i64::from_le_bytes(data[24..32].try_into().unwrap()),
+		})
+	}
+
+	/// Serialize the price feed into its account layout
+	pub fn pack(&self) -> [u8; Self::LEN] {
+		let mut data = [0u8; Self::LEN];
+		data[0..4].copy_from_slice(&Self::MAGIC);
+		data[4..8].copy_from_slice(&self.exponent.to_le_bytes());
+		data[8..16].copy_from_slice(&self.price.to_le_bytes());
+		data[16..24].copy_from_slice(&self.confidence.to_le_bytes());
+		data[24..32].copy_from_slice(&self.publish_time.to_le_bytes());
+		data
+	}
+
+	/// Convert `fiat_amount` fare units into token base units, rounding up.
+	/// Fails when the price is older than `max_age` seconds or its confidence
+	/// interval is wider than `max_confidence_bps` of the price.
+	pub fn fiat_to_tokens(&self, fiat_amount: u64, decimals: u8, current_time: i64, max_age: i64, max_confidence_bps: u16) -> Result<u64> {
+		if self.price <= 0 {
+			return Err(CoreError::InvalidPriceFeed);
+		}
+		if current_time.saturating_sub(self.publish_time) > max_age {
+			return Err(CoreError::StalePrice);
+		}
+		if self.confidence as u128 * crate::BASIS_POINTS as u128 > self.price as u128 * max_confidence_bps as u128 {
+			return Err(CoreError::PriceConfidenceTooWide);
+		}
+
+		// tokens = fiat_amount * 10^decimals / (price * 10^exponent)
+		let pow10 = |exp: u32| 10u128.checked_pow(exp).ok_or(CoreError::InvalidPriceFeed);
+		let mut numerator = (fiat_amount as u128)
+			.checked_mul(pow10(decimals as u32)?)
+			.ok_or(CoreError::InvalidAmount)?;
+		let mut denominator = self.price as u128;
+		if self.exponent < 0 {
+			numerator = numerator
+				.checked_mul(pow10(self.exponent.unsigned_abs())?)
+				.ok_or(CoreError::InvalidAmount)?;
+		} else {
+			denominator = denominator
+				.checked_mul(pow10(self.exponent as u32)?)
+				.ok_or(CoreError::InvalidPriceFeed)?;
+		}
+
+		u64::try_from(numerator.div_ceil(denominator)).map_err(|_| CoreError::InvalidAmount)
+	}
+}
+
+/// Convert `base_amount` fare units at `rate_numerator / rate_denominator`,
+/// rounding up so the fare system is never underpaid
+pub fn convert_at_rate(base_amount: u64, rate_numerator: u64, rate_denominator: u64) -> Result<u64> {
+	if rate_denominator == 0 {
+		return Err(CoreError::InvalidAmount);
+	}
+	let amount = (base_amount as u128 * rate_numerator as u128).div_ceil(rate_denominator as u128);
+	u64::try_from(amount).map_err(|_| CoreError::InvalidAmount)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FEED: PriceFeed = PriceFeed {
+		exponent: -2,
+		price: 150,  // 1.50 fare units per token
+		confidence: 1,
+		publish_time: 1_000,
+	};
+
+	#[test]
+	fn pack_round_trip() {
+		assert_eq!(PriceFeed::unpack(&FEED.pack()), Ok(FEED));
+		assert_eq!(PriceFeed::unpack(&FEED.pack()[..31]), Err(CoreError::InvalidPriceFeed));
+
+		let mut data = FEED.pack();
+		data[0] = b'X';
+		assert_eq!(PriceFeed::unpack(&data), Err(CoreError::InvalidPriceFeed));
+	}
+
+	#[test]
+	fn fiat_to_tokens_rounds_up() {
+		// 3 fare units at 1.50 per token is 2 tokens, with 6 decimals
+		assert_eq!(FEED.fiat_to_tokens(3, 6, 1_000, 60, 100), Ok(2_000_000));
+		// 1 fare unit is 0.666... tokens, rounded up in base units
+		assert_eq!(FEED.fiat_to_tokens(1, 2, 1_000, 60, 100), Ok(67));
+
+		let positive = PriceFeed { exponent: 1, price: 3, ..FEED };
+		assert_eq!(positive.fiat_to_tokens(60, 0, 1_000, 60, 10_000), Ok(2));
+	}
+
+	#[test]
+	fn fiat_to_tokens_rejects_bad_prices() {
+		assert_eq!(FEED.fiat_to_tokens(3, 6, 1_061, 60, 100), Err(CoreError::StalePrice));
+		assert_eq!(FEED.fiat_to_tokens(3, 6, 1_000, 60, 50), Err(CoreError::PriceConfidenceTooWide));
+
+		let negative = PriceFeed { price: -150, ..FEED };
+		assert_eq!(negative.fiat_to_tokens(3, 6, 1_000, 60, 100), Err(CoreError::InvalidPriceFeed));
+	}
+
+	#[test]
+	fn rate_conversion_rounds_up() {
+		assert_eq!(convert_at_rate(1_000, 3, 2), Ok(1_500));
+		assert_eq!(convert_at_rate(1, 1, 3), Ok(1));
+		assert_eq!(convert_at_rate(1, 1, 0), Err(CoreError::InvalidAmount));
+		assert_eq!(convert_at_rate(u64::MAX, 2, 1), Err(CoreError::InvalidAmount));
+	}
+}
@@ -0,0 +1,68 @@
+/// Refund for cancelling a pass that ran from `start` to `end` at
+/// `current_time`: the unused share of `price_paid`, less `cancellation_fee`
+/// in the same currency. At least `non_refundable_period` seconds are always
+/// charged.
+pub fn subscription_refund(price_paid: u64, start: i64, end: i64, current_time: i64, non_refundable_period: i64, cancellation_fee: u64) -> u64 {
+	let total_duration = end - start;
+	if total_duration <= 0 {
+		return 0;
+	}
+
+	let elapsed = (current_time - start)
+		.max(non_refundable_period)
+		.min(total_duration);
+	let remaining_duration = (total_duration - elapsed).max(0);
+
+	let refund = remaining_duration as u128 * price_paid as u128 / total_duration as u128;
+	(refund as u64).saturating_sub(cancellation_fee)
+}
+
+/// Loyalty points to take back when `refund` of `price_paid` is returned,
+/// in proportion to the `points_awarded` for the purchase
+pub fn points_clawback(points_awarded: u64, refund: u64, price_paid: u64) -> u64 {
+	if price_paid == 0 {
+		return 0;
+	}
+	(points_awarded as u128 * refund as u128 / price_paid as u128).min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const START: i64 = 1_000_000;
+	const END: i64 = START + crate::SECONDS_PER_MONTH;
+
+	#[test]
+	fn unused_share_is_refunded() {
+		assert_eq!(subscription_refund(9_000, START, END, START, 0, 0), 9_000);
+		assert_eq!(subscription_refund(9_000, START, END, START + 10 * crate::SECONDS_PER_DAY, 0, 0), 6_000);
+		assert_eq!(subscription_refund(9_000, START, END, END, 0, 0), 0);
+		assert_eq!(subscription_refund(9_000, START, END, END + 1, 0, 0), 0);
+	}
+
+	#[test]
+	fn non_refundable_period_is_always_charged() {
+		let period = 15 * crate::SECONDS_PER_DAY;
+		assert_eq!(subscription_refund(9_000, START, END, START, period, 0), 4_500);
+		assert_eq!(subscription_refund(9_000, START, END, START + 20 * crate::SECONDS_PER_DAY, period, 0), 3_000);
+	}
+
+	#[test]
+	fn cancellation_fee_never_goes_negative() {
+		assert_eq!(subscription_refund(9_000, START, END, START, 0, 500), 8_500);
+		assert_eq!(subscription_refund(9_000, START, END, END - 1, 0, 500), 0);
+	}
+
+	#[test]
+	fn empty_pass_refunds_nothing() {
+		assert_eq!(subscription_refund(9_000, START, START, START, 0, 0), 0);
+	}
+
+	#[test]
+	fn clawback_follows_refund() {
+		assert_eq!(points_clawback(90, 6_000, 9_000), 60);
+		assert_eq!(points_clawback(90, 0, 9_000), 0);
+		assert_eq!(points_clawback(90, 0, 0), 0);
+	}
+}
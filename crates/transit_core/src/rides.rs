@@ -0,0 +1,70 @@
+use crate::{CoreError, Result};
+
+/// Ride counters kept for each rider on a pass
+pub struct RideCounter<'a> {
+	pub rides_used: &'a mut u32,     // Rides in the current pass period
+	pub ride_day: &'a mut i64,       // Day index (unix_timestamp / SECONDS_PER_DAY) of rides_today
+	pub rides_today: &'a mut u32,    // Rides on ride_day
+}
+
+impl RideCounter<'_> {
+	/// Enforce the ride caps, 0 meaning unlimited, and count one more ride at
+	/// `current_time`. Returns the rider's rides today and in the period.
+	pub fn record(&mut self, current_time: i64, max_rides_per_day: u32, max_rides_per_period: u32) -> Result<(u32, u32)> {
+		if max_rides_per_period != 0 && *self.rides_used >= max_rides_per_period {
+			return Err(CoreError::PeriodRideLimitReached);
+		}
+
+		// Start a new daily counter when the first ride of the day is taken
+		let today = current_time / crate::SECONDS_PER_DAY;
+		if *self.ride_day != today {
+			*self.ride_day = today;
+			*self.rides_today = 0;
+		}
+
+		if max_rides_per_day != 0 && *self.rides_today >= max_rides_per_day {
+			return Err(CoreError::DailyRideLimitReached);
+		}
+
+		*self.rides_used += 1;
+		*self.rides_today += 1;
+
+		Ok((*self.rides_today, *self.rides_used))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DAY: i64 = crate::SECONDS_PER_DAY;
+
+	#[test]
+	fn daily_cap_resets_each_day() {
+		let (mut used, mut day, mut today) = (0, 0, 0);
+		let mut counter = RideCounter { rides_used: &mut used, ride_day: &mut day, rides_today: &mut today };
+
+		assert_eq!(counter.record(10 * DAY, 2, 0), Ok((1, 1)));
+		assert_eq!(counter.record(10 * DAY + 60, 2, 0), Ok((2, 2)));
+		assert_eq!(counter.record(10 * DAY + 120, 2, 0), Err(CoreError::DailyRideLimitReached));
+		assert_eq!(counter.record(11 * DAY, 2, 0), Ok((1, 3)));
+	}
+
+	#[test]
+	fn period_cap_is_checked_first() {
+		let (mut used, mut day, mut today) = (5, 10, 0);
+		let mut counter = RideCounter { rides_used: &mut used, ride_day: &mut day, rides_today: &mut today };
+
+		assert_eq!(counter.record(11 * DAY, 0, 5), Err(CoreError::PeriodRideLimitReached));
+		// A rejected ride does not touch the counters
+		assert_eq!((used, day), (5, 10));
+	}
+
+	#[test]
+	fn zero_caps_are_unlimited() {
+		let (mut used, mut day, mut today) = (1_000, 3, 1_000);
+		let mut counter = RideCounter { rides_used: &mut used, ride_day: &mut day, rides_today: &mut today };
+
+		assert_eq!(counter.record(3 * DAY, 0, 0), Ok((1_001, 1_001)));
+	}
+}
@@ -1,4 +0,0 @@
-use anchor_lang::prelude::*;
-
-pub const MAX_TRANSPORT_MODE: u8 = 1;
-pub const MAX_TICKET_STATUS: u8 = 1;
\ No newline at end of file
@@ -1,14 +0,0 @@
-
-pub mod initialize_fare_config;
-pub mod update_fare_config;
-pub mod purchase_ticket;
-pub mod use_ticket;
-pub mod refund_ticket;
-pub mod record_payment;
-
-pub use initialize_fare_config::*;
-pub use update_fare_config::*;
-pub use purchase_ticket::*;
-pub use use_ticket::*;
-pub use refund_ticket::*;
-pub use record_payment::*;
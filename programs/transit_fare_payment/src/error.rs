@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use transit_core::CoreError;
 
 #[error_code]
 pub enum FarePaymentError {
@@ -22,4 +23,138 @@ pub enum FarePaymentError {
     SubscriptionExpired,
     #[msg("No active subscription found.")]
     SubscriptionNotFound,
-}
\ No newline at end of file
+    #[msg("Currency mint does not match the one used for payment.")]
+    CurrencyMismatch,
+    #[msg("Invalid non-refundable period.")]
+    InvalidNonRefundablePeriod,
+    #[msg("Subscription is not valid on this transport mode.")]
+    TransportModeNotAllowed,
+    #[msg("Daily subscription ride limit reached.")]
+    DailyRideLimitReached,
+    #[msg("Subscription ride limit reached for this period.")]
+    PeriodRideLimitReached,
+    #[msg("Subscription has not expired yet.")]
+    SubscriptionStillActive,
+    #[msg("Invalid seat count for a subscription.")]
+    InvalidSeatCount,
+    #[msg("All household seats are taken.")]
+    HouseholdFull,
+    #[msg("Wallet is already a household member.")]
+    HouseholdMemberExists,
+    #[msg("Wallet is not a member of this household.")]
+    NotHouseholdMember,
+    #[msg("Household has more members than the subscription has seats.")]
+    HouseholdSeatsExceeded,
+    #[msg("Employee is not enrolled with this employer.")]
+    EmployeeNotEnrolled,
+    #[msg("Monthly employer allowance exceeded.")]
+    AllowanceExceeded,
+    #[msg("Escrow account does not belong to the employer.")]
+    InvalidEscrow,
+    #[msg("Destination is not a fare system treasury account.")]
+    InvalidTreasury,
+    #[msg("Refund destination must be the account that paid.")]
+    RefundDestinationMismatch,
+    #[msg("Promotion window ends before it starts.")]
+    InvalidPromotionWindow,
+    #[msg("Not enough loyalty points.")]
+    InsufficientLoyaltyPoints,
+    #[msg("Loyalty reward is not available.")]
+    InvalidLoyaltyReward,
+    #[msg("Invalid promotion settings.")]
+    InvalidPromotion,
+    #[msg("Promo code does not match the promotion.")]
+    InvalidPromoCode,
+    #[msg("Promotion is not active.")]
+    PromotionNotActive,
+    #[msg("Promotion does not apply to this purchase.")]
+    PromotionNotApplicable,
+    #[msg("Promotion redemption limit reached.")]
+    PromotionLimitReached,
+    #[msg("Claim code does not match the gift card.")]
+    InvalidGiftCardCode,
+    #[msg("Gift card has expired.")]
+    GiftCardExpired,
+    #[msg("Gift card has not expired yet.")]
+    GiftCardNotExpired,
+    #[msg("Payment currency is not enabled.")]
+    CurrencyDisabled,
+    #[msg("Invalid currency conversion rate.")]
+    InvalidExchangeRate,
+    #[msg("Price feed account is missing or malformed.")]
+    InvalidPriceFeed,
+    #[msg("Oracle price is stale.")]
+    StalePrice,
+    #[msg("Oracle price confidence interval is too wide.")]
+    PriceConfidenceTooWide,
+    #[msg("Ticket has expired.")]
+    TicketExpired,
+    #[msg("Ticket is unused and has not expired.")]
+    TicketStillValid,
+    #[msg("Payment is still within its retention period.")]
+    RetentionPeriodNotElapsed,
+    #[msg("Invalid retention period.")]
+    InvalidRetentionPeriod,
+    #[msg("Stored-value balance must be withdrawn first.")]
+    StoredValueNotEmpty,
+    #[msg("Passenger still has unused tickets.")]
+    OutstandingTickets,
+    #[msg("Invalid role.")]
+    InvalidRole,
+    #[msg("Signer is not the proposed admin.")]
+    NotPendingAdmin,
+    #[msg("This instruction is paused.")]
+    ProgramPaused,
+    #[msg("Invalid pause flags.")]
+    InvalidPauseFlags,
+    #[msg("Fare change does not give the minimum notice.")]
+    FareNoticeTooShort,
+    #[msg("Invalid fare notice period.")]
+    InvalidFareNotice,
+    #[msg("No fare change is pending.")]
+    NoPendingFareChange,
+    #[msg("Fare change has already taken effect.")]
+    FareChangeAlreadyEffective,
+    #[msg("Fare change is not due yet.")]
+    FareChangeNotDue,
+    #[msg("Current price is above the maximum price accepted.")]
+    PriceAboveMaximum,
+    #[msg("Invalid multisig owners or threshold.")]
+    InvalidMultisig,
+    #[msg("Signer is not a multisig owner.")]
+    NotMultisigOwner,
+    #[msg("Proposal has already been executed.")]
+    ProposalAlreadyExecuted,
+    #[msg("Owner has already approved this proposal.")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal does not have enough approvals.")]
+    ThresholdNotReached,
+    #[msg("An account required by the proposal action is missing or does not match.")]
+    InvalidActionAccounts,
+    #[msg("Account already uses the current layout.")]
+    AccountAlreadyMigrated,
+    #[msg("Account layout version is not supported.")]
+    UnsupportedAccountVersion,
+    #[msg("A payment receipt account is required for this purchase.")]
+    MissingPaymentReceipt,
+    #[msg("Loyalty points earned by this purchase have already been redeemed.")]
+    LoyaltyPointsSpent,
+}
+
+impl From<CoreError> for FarePaymentError {
+    fn from(error: CoreError) -> Self {
+        match error {
+            CoreError::InvalidTransportMode => FarePaymentError::InvalidTransportMode,
+            CoreError::InvalidSubscriptionType => FarePaymentError::InvalidSubscriptionType,
+            CoreError::InvalidSeatCount => FarePaymentError::InvalidSeatCount,
+            CoreError::InvalidAmount => FarePaymentError::InvalidAmount,
+            CoreError::InvalidPriceFeed => FarePaymentError::InvalidPriceFeed,
+            CoreError::StalePrice => FarePaymentError::StalePrice,
+            CoreError::PriceConfidenceTooWide => FarePaymentError::PriceConfidenceTooWide,
+            CoreError::FareNoticeTooShort => FarePaymentError::FareNoticeTooShort,
+            CoreError::TransportModeNotAllowed => FarePaymentError::TransportModeNotAllowed,
+            CoreError::PeriodRideLimitReached => FarePaymentError::PeriodRideLimitReached,
+            CoreError::DailyRideLimitReached => FarePaymentError::DailyRideLimitReached,
+        }
+    }
+}
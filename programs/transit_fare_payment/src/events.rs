@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::{FareSchedule, Payment};
+
+// Every event starts with `version`, set to EVENT_SCHEMA_VERSION. Fields are
+// only ever appended; removing or reordering a field bumps the version.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+#[event]
+pub struct TicketPurchased {
+    pub version: u8,
+    pub user: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub transport_mode: u8,
+    pub amount: u64,                // Amount actually transferred
+    pub currency_mint: Pubkey,
+    pub payment_id: u64,            // Receipt written for the purchase, NO_PAYMENT_RECEIPT for free tickets
+    pub sponsor_escrow: Pubkey,     // Employer escrow that paid, default = rider paid
+    pub expires_at: i64,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketUsed {
+    pub version: u8,
+    pub user: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub transport_mode: u8,
+    pub station_id: u32,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketRefunded {
+    pub version: u8,
+    pub user: Pubkey,
+    pub ticket: Pubkey,
+    pub ticket_id: u64,
+    pub transport_mode: u8,
+    pub amount: u64,                // Amount refunded
+    pub currency_mint: Pubkey,
+    pub destination: Pubkey,        // Token account the refund was paid to
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionPurchased {
+    pub version: u8,
+    pub user: Pubkey,
+    pub subscription_type: u8,
+    pub seat_count: u8,
+    pub price: u64,                 // Amount actually transferred
+    pub currency_mint: Pubkey,
+    pub payment_id: u64,            // Receipt written for the purchase
+    pub sponsor_escrow: Pubkey,     // Employer escrow that paid, default = rider paid
+    pub subscription_start: i64,
+    pub subscription_end: i64,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionRideUsed {
+    pub version: u8,
+    pub user: Pubkey,               // Pass holder
+    pub rider: Pubkey,              // Household member riding, or the holder
+    pub subscription_type: u8,
+    pub transport_mode: u8,
+    pub station_id: u32,
+    pub rides_today: u32,
+    pub rides_this_period: u32,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub version: u8,
+    pub user: Pubkey,
+    pub subscription_type: u8,
+    pub price_paid: u64,
+    pub refund_amount: u64,
+    pub currency_mint: Pubkey,
+    pub destination: Pubkey,        // Token account the refund was paid to
+    pub subscription_start: i64,
+    pub subscription_end: i64,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionExpired {
+    pub version: u8,
+    pub user: Pubkey,
+    pub subscription_type: u8,
+    pub subscription_start: i64,
+    pub subscription_end: i64,
+    pub expired_by: Pubkey,         // Fee payer of the expiring transaction
+    pub reward: u64,                // Lamports actually paid to expired_by
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StoredValueCredited {
+    pub version: u8,
+    pub user: Pubkey,
+    pub amount: u64,                // Amount added to the balance
+    pub balance: u64,               // Stored-value balance after the credit
+    pub currency_mint: Pubkey,
+    pub gift_card: Pubkey,          // Gift card the value came from, default = top-up
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FareConfigUpdated {
+    pub version: u8,
+    pub updated_by: Pubkey,
+    pub fare_config_version: u64,   // Version after the update
+    pub admin: Pubkey,
+    pub bus_fare: u64,
+    pub train_fare: u64,
+    pub monthly_pass_price: u64,
+    pub yearly_pass_price: u64,
+    pub pending_fares: FareSchedule,
+    pub cancellation_fee: u64,
+    pub non_refundable_period: i64,
+    pub paused: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentRecorded {
+    pub version: u8,
+    pub user: Pubkey,
+    pub payment: Pubkey,
+    pub payment_id: u64,
+    pub product: u8,
+    pub reference: Pubkey,
+    pub amount: u64,
+    pub currency_mint: Pubkey,
+    pub slot: u64,
+    pub fare_config_version: u64,
+    pub timestamp: i64,
+}
+
+impl PaymentRecorded {
+    /// Event for the receipt `payment` stored at `payment_key`
+    pub fn new(payment_key: Pubkey, payment: &Payment, fare_config_version: u64) -> Self {
+        Self {
+            version: EVENT_SCHEMA_VERSION,
+            user: payment.user,
+            payment: payment_key,
+            payment_id: payment.payment_id,
+            product: payment.product,
+            reference: payment.reference,
+            amount: payment.amount,
+            currency_mint: payment.currency_mint,
+            slot: payment.slot,
+            fare_config_version,
+            timestamp: payment.payment_timestamp,
+        }
+    }
+}
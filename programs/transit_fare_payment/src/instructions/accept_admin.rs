@@ -0,0 +1,75 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        init,
+        space = 8 + RoleChange::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"role_change",
+            roles.change_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub role_change: Account<'info, RoleChange>,
+
+    #[account(
+        address = fare_config.pending_admin @ FarePaymentError::NotPendingAdmin,
+    )]
+    pub new_admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Complete an admin transfer started with propose_admin
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[writable]` roles: [Roles]
+/// 3. `[writable]` role_change: [RoleChange] Record of the transfer
+/// 4. `[signer]` new_admin: [AccountInfo] The proposed admin
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<AcceptAdmin>,
+) -> Result<()> {
+    let new_admin = ctx.accounts.new_admin.key();
+    let previous = ctx.accounts.fare_config.accept_admin(new_admin)?;
+
+    ctx.accounts.roles.record_change(
+        &mut ctx.accounts.role_change,
+        crate::ROLE_ADMIN,
+        previous,
+        new_admin,
+        new_admin,
+        ctx.bumps.role_change,
+    )?;
+
+    ctx.accounts.fare_config.record_update(new_admin)?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct AddAcceptedCurrency<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        init,
+        space = 8 + AcceptedCurrency::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"accepted_currency",
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub accepted_currency: Account<'info, AcceptedCurrency>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        constraint = treasury.owner == fare_config.treasury @ FarePaymentError::InvalidTreasury,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.treasurer @ FarePaymentError::Unauthorized,
+    )]
+    pub treasurer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accept an additional payment currency at a fixed conversion rate
+///
+/// Prices are converted as `base_amount * rate_numerator / rate_denominator`,
+/// rounded up.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` accepted_currency: [AcceptedCurrency]
+/// 3. `[]` mint: [Mint] Mint to accept
+/// 4. `[]` treasury: [TokenAccount] Token account receiving payments in this mint
+/// 5. `[]` roles: [Roles] Role holders
+/// 6. `[signer]` treasurer: [AccountInfo] Treasurer role holder
+/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - rate_numerator: [u64] Units of the mint per rate_denominator base fare units
+/// - rate_denominator: [u64] Base fare units the numerator is quoted against
+pub fn handler(
+    ctx: Context<AddAcceptedCurrency>,
+    rate_numerator: u64,
+    rate_denominator: u64,
+) -> Result<()> {
+    require!(rate_numerator > 0 && rate_denominator > 0, FarePaymentError::InvalidExchangeRate);
+
+    ctx.accounts.accepted_currency.set_inner(AcceptedCurrency {
+        version: AcceptedCurrency::VERSION,
+        mint: ctx.accounts.mint.key(),
+        treasury: ctx.accounts.treasury.key(),
+        rate_numerator,
+        rate_denominator,
+        pending_rate_numerator: 0,
+        pending_rate_denominator: 0,
+        rate_effective_at: 0,
+        enabled: true,
+        bump: ctx.bumps.accepted_currency,
+    });
+
+    Ok(())
+}
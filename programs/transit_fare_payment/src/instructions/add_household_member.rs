@@ -0,0 +1,51 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct AddHouseholdMember<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"household",
+            user.key().as_ref(),
+        ],
+        bump = household.bump,
+    )]
+    pub household: Account<'info, Household>,
+
+    pub user: Signer<'info>,
+}
+
+/// Add a member wallet to a household pass
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` passenger: [Passenger] Primary passenger holding the subscription
+/// 2. `[writable]` household: [Household]
+/// 3. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+///
+/// Data:
+/// - member: [Pubkey] Wallet to add to the household
+pub fn handler(
+    ctx: Context<AddHouseholdMember>,
+    member: Pubkey,
+) -> Result<()> {
+    let passenger = &ctx.accounts.passenger;
+    let household = &mut ctx.accounts.household;
+    household.start_period(passenger.subscription_start);
+
+    household.add_member(member, passenger.subscription_seats)?;
+
+    Ok(())
+}
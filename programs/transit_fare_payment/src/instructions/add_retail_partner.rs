@@ -0,0 +1,70 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(
+    partner: Pubkey,
+)]
+pub struct AddRetailPartner<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        init,
+        space = 8 + RetailPartner::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"retail_partner",
+            partner.as_ref(),
+        ],
+        bump,
+    )]
+    pub retail_partner: Account<'info, RetailPartner>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.eligibility_issuer @ FarePaymentError::Unauthorized,
+    )]
+    pub eligibility_issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Allow a retail partner to issue gift cards
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` retail_partner: [RetailPartner]
+/// 3. `[]` roles: [Roles] Role holders
+/// 4. `[signer]` eligibility_issuer: [AccountInfo] Eligibility issuer role holder
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - partner: [Pubkey] Retail partner wallet
+pub fn handler(
+    ctx: Context<AddRetailPartner>,
+    partner: Pubkey,
+) -> Result<()> {
+    ctx.accounts.retail_partner.set_inner(RetailPartner {
+        version: RetailPartner::VERSION,
+        partner,
+        bump: ctx.bumps.retail_partner,
+    });
+
+    Ok(())
+}
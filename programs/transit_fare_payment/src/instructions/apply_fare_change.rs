@@ -0,0 +1,39 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApplyFareChange<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+}
+
+/// Write a due fare or price feed change into the live settings
+///
+/// Anyone may call this. Fare instructions already charge a due change
+/// before it is applied, so this only tidies up the stored settings.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ApplyFareChange>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let fares_applied = ctx.accounts.fare_config.apply_due_fare_change(current_time);
+    let price_feed_applied = ctx.accounts.fare_config.apply_due_price_feed_change(current_time);
+    require!(fares_applied || price_feed_applied, FarePaymentError::FareChangeNotDue);
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fee_payer.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"multisig",
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Approve a pending multisig proposal
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` multisig: [Multisig]
+/// 2. `[writable]` proposal: [Proposal]
+/// 3. `[signer]` owner: [AccountInfo] Multisig owner approving the proposal
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ApproveProposal>,
+) -> Result<()> {
+    let index = ctx.accounts.multisig.owner_index(&ctx.accounts.owner.key())?;
+    ctx.accounts.proposal.approve(index)
+}
@@ -0,0 +1,49 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CancelFareChange<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
+}
+
+/// Cancel a queued fare change before it takes effect
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<CancelFareChange>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.fare_config.cancel_fare_change(current_time)?;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fare_manager.key())?;
+
+    Ok(())
+}
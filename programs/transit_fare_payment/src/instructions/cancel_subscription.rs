@@ -1,5 +1,4 @@
 use crate::*;
-use anchor_lang::prelude::*;
 
 use anchor_spl::{
     token::{Mint, Token},
@@ -62,6 +61,15 @@ pub struct CancelSubscription<'info> {
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+
+    #[account(
+        seeds = [
+            b"accepted_currency",
+            mint.key().as_ref(),
+        ],
+        bump = accepted_currency.bump,
+    )]
+    pub accepted_currency: Option<Account<'info, AcceptedCurrency>>,
 }
 
 /// Cancel an active subscription and process pro-rated refund
@@ -79,11 +87,14 @@ pub struct CancelSubscription<'info> {
 /// 9. `[writable]` destination: [AccountInfo] The destination account.
 /// 10. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 11. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 12. `[optional]` accepted_currency: [AcceptedCurrency] Required when the pass was paid in another accepted currency
 ///
 /// Data: None
 pub fn handler(
     ctx: Context<CancelSubscription>,
 ) -> Result<()> {
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_SUBSCRIPTION_CANCEL)?;
+
     // Verify that the passenger has an active subscription
     require!(
         ctx.accounts.passenger.subscription_type > 0,
@@ -92,31 +103,40 @@ pub fn handler(
 
     let current_time = Clock::get()?.unix_timestamp;
 
-    // Calculate the pro-rated refund
+    // Refunds are paid in the currency the subscription was bought with
+    require!(
+        ctx.accounts.mint.key() == ctx.accounts.passenger.subscription_currency_mint,
+        FarePaymentError::CurrencyMismatch
+    );
+
+    // Employer-paid passes are refunded to the employer escrow
+    if ctx.accounts.passenger.subscription_sponsor_escrow != Pubkey::default() {
+        require_keys_eq!(ctx.accounts.destination.key(), ctx.accounts.passenger.subscription_sponsor_escrow, FarePaymentError::RefundDestinationMismatch);
+    }
+
+    // Refund the unused share of the price actually paid, not the current pass price.
+    // The non-refundable period is always charged, even when cancelling early.
     let subscription_start = ctx.accounts.passenger.subscription_start;
     let subscription_end = ctx.accounts.passenger.subscription_end;
-    let subscription_type = ctx.accounts.passenger.subscription_type;
-
-    let total_duration = subscription_end - subscription_start;
-    let remaining_duration = if current_time < subscription_end {
-        subscription_end - current_time
-    } else {
-        0
-    };
+    let original_price = ctx.accounts.passenger.subscription_price_paid;
 
-    // Determine original price based on subscription type
-    let original_price = if subscription_type == 1 {
-        ctx.accounts.fare_config.monthly_pass_price
+    // The fee is set in base-mint units; passes paid in another accepted
+    // currency are charged its value at that currency's rate
+    let cancellation_fee = if ctx.accounts.mint.key() == ctx.accounts.fare_config.currency_mint {
+        ctx.accounts.fare_config.cancellation_fee
     } else {
-        ctx.accounts.fare_config.yearly_pass_price
+        let currency = ctx.accounts.accepted_currency.as_ref().ok_or(FarePaymentError::CurrencyMismatch)?;
+        currency.convert(ctx.accounts.fare_config.cancellation_fee, current_time)?
     };
 
-    // Calculate pro-rated refund: (remaining_time / total_time) * original_price
-    let refund_amount = if total_duration > 0 {
-        (remaining_duration as u128 * original_price as u128 / total_duration as u128) as u64
-    } else {
-        0
-    };
+    let refund_amount = transit_core::subscription_refund(
+        original_price,
+        subscription_start,
+        subscription_end,
+        current_time,
+        ctx.accounts.fare_config.non_refundable_period,
+        cancellation_fee,
+    );
 
     // Transfer refund back to user if there's an amount to refund
     if refund_amount > 0 {
@@ -130,15 +150,32 @@ pub fn handler(
                 }
             ),
             refund_amount,
-            9,
+            ctx.accounts.mint.decimals,
         )?;
     }
 
+    // Claw back the loyalty points for the refunded share of the pass
+    let clawback = transit_core::points_clawback(ctx.accounts.passenger.subscription_points_awarded, refund_amount, original_price);
+    ctx.accounts.passenger.loyalty_points = ctx.accounts.passenger.loyalty_points
+        .checked_sub(clawback)
+        .ok_or(FarePaymentError::LoyaltyPointsSpent)?;
+
+    emit!(SubscriptionCancelled {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.passenger.user,
+        subscription_type: ctx.accounts.passenger.subscription_type,
+        price_paid: original_price,
+        refund_amount,
+        currency_mint: ctx.accounts.passenger.subscription_currency_mint,
+        destination: ctx.accounts.destination.key(),
+        subscription_start,
+        subscription_end,
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: current_time,
+    });
+
     // Reset subscription fields
-    ctx.accounts.passenger.subscription_type = 0;
-    ctx.accounts.passenger.subscription_start = 0;
-    ctx.accounts.passenger.subscription_end = 0;
-    ctx.accounts.passenger.subscription_rides_used = 0;
+    ctx.accounts.passenger.clear_subscription();
 
     // Decrement active subscriptions counter
     ctx.accounts.fare_config.total_active_subscriptions = ctx.accounts.fare_config.total_active_subscriptions.saturating_sub(1);
@@ -0,0 +1,123 @@
+use crate::*;
+
+use anchor_spl::token::{Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ClosePassenger<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"ride_history",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ride_history: Option<AccountLoader<'info, RideHistory>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stored_value",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stored_value: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"household",
+            user.key().as_ref(),
+        ],
+        bump = household.bump,
+    )]
+    pub household: Option<Account<'info, Household>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Delete a passenger account and return its rent to the rider
+///
+/// The rider's ride history, empty stored-value escrow and household are
+/// deleted along with it when passed in, their rent going to the rider too.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger]
+/// 3. `[writable, optional]` ride_history: [RideHistory] Rider's ride history to delete
+/// 4. `[writable, optional]` stored_value: [TokenAccount] Rider's stored-value escrow to close
+/// 5. `[writable, optional]` household: [Household] Rider's household to delete
+/// 6. `[writable, signer]` user: [AccountInfo] User's wallet address
+/// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ClosePassenger>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Refuse while the rider still has something of value on the account
+    require!(
+        ctx.accounts.passenger.subscription_type == 0 || ctx.accounts.passenger.subscription_end <= current_time,
+        FarePaymentError::SubscriptionStillActive
+    );
+    require!(ctx.accounts.passenger.stored_value_balance == 0, FarePaymentError::StoredValueNotEmpty);
+    require!(ctx.accounts.passenger.outstanding_tickets == 0, FarePaymentError::OutstandingTickets);
+
+    // A lapsed pass that was never expired is still counted as active
+    if ctx.accounts.passenger.subscription_type > 0 {
+        ctx.accounts.fare_config.total_active_subscriptions = ctx.accounts.fare_config.total_active_subscriptions.saturating_sub(1);
+    }
+
+    // The escrow belongs to the fare config, which signs to close it
+    if let Some(stored_value) = &ctx.accounts.stored_value {
+        require!(stored_value.amount == 0, FarePaymentError::StoredValueNotEmpty);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[ctx.accounts.fare_config.bump]]];
+        anchor_spl::token::close_account(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+                anchor_spl::token::CloseAccount {
+                    account: stored_value.to_account_info(),
+                    destination: ctx.accounts.user.to_account_info(),
+                    authority: ctx.accounts.fare_config.to_account_info()
+                },
+                signer_seeds,
+            ),
+        )?;
+    }
+
+    // Wipe the rider's data; the accounts themselves are closed on exit
+    ctx.accounts.passenger.set_inner(Passenger::default());
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ClosePayment<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"payment",
+            payment.user.as_ref(),
+            payment.payment_id.to_le_bytes().as_ref(),
+        ],
+        bump = payment.bump,
+    )]
+    pub payment: Account<'info, Payment>,
+
+    #[account(
+        mut,
+        address = payment.rent_payer,
+    )]
+    /// CHECK: receives the rent of the closed receipt
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+/// Close a payment receipt older than the retention period
+///
+/// Anyone may call this; the rent always goes to the account that paid for
+/// the receipt.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` payment: [Payment]
+/// 3. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the receipt
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ClosePayment>,
+) -> Result<()> {
+    let retention_period = ctx.accounts.fare_config.payment_retention_period;
+    let age = Clock::get()?.unix_timestamp - ctx.accounts.payment.payment_timestamp;
+
+    // A zero retention period keeps receipts forever
+    require!(
+        retention_period > 0 && age >= retention_period,
+        FarePaymentError::RetentionPeriodNotElapsed
+    );
+
+    Ok(())
+}
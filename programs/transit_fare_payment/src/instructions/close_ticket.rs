@@ -0,0 +1,67 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CloseTicket<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+            b"ticket",
+            ticket.user.as_ref(),
+            ticket.ticket_id.to_le_bytes().as_ref(),
+        ],
+        bump = ticket.bump,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    #[account(
+        mut,
+        address = ticket.rent_payer,
+    )]
+    /// CHECK: receives the rent of the closed ticket
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            ticket.user.as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Option<Account<'info, Passenger>>,
+}
+
+/// Close a used or expired ticket and return its rent to the original fee payer
+///
+/// Anyone may call this; the rent always goes to the account that paid for
+/// the ticket.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` ticket: [Ticket]
+/// 2. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the ticket
+/// 3. `[writable, optional]` passenger: [Passenger] Ticket owner, required for unused tickets
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<CloseTicket>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let ticket = &ctx.accounts.ticket;
+
+    // Only used tickets and expired tickets can be closed
+    let expired = ticket.expires_at != 0 && current_time >= ticket.expires_at;
+    require!(ticket.status == 1 || expired, FarePaymentError::TicketStillValid);
+
+    // An expired ticket that was never used no longer counts as outstanding
+    if ticket.status == 0 {
+        let passenger = ctx.accounts.passenger.as_mut().ok_or(FarePaymentError::InvalidAccountData)?;
+        passenger.outstanding_tickets = passenger.outstanding_tickets.saturating_sub(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct CreateEmployer<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        init,
+        space = 8 + Employer::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"employer",
+            admin.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub employer: Account<'info, Employer>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [
+            b"employer_escrow",
+            employer.key().as_ref(),
+        ],
+        bump,
+        token::mint = currency_mint,
+        token::authority = employer,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        address = fare_config.currency_mint,
+    )]
+    pub currency_mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Create an employer benefits account and its token escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` employer: [Employer]
+/// 3. `[writable]` escrow: [TokenAccount] Escrow token account owned by the employer PDA
+/// 4. `[signer]` admin: [AccountInfo] Employer administrator
+/// 5. `[]` currency_mint: [Mint] Fare currency mint
+/// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<CreateEmployer>,
+) -> Result<()> {
+    ctx.accounts.employer.set_inner(Employer {
+        version: Employer::VERSION,
+        admin: ctx.accounts.admin.key(),
+        currency_mint: ctx.accounts.currency_mint.key(),
+        escrow: ctx.accounts.escrow.key(),
+        enrolled_employees: 0,
+        bump: ctx.bumps.employer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateHousehold<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        init,
+        space = 8 + Household::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"household",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub household: Account<'info, Household>,
+
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the household account that lists the members sharing a pass
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` passenger: [Passenger] Primary passenger holding the subscription
+/// 2. `[writable]` household: [Household]
+/// 3. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<CreateHousehold>,
+) -> Result<()> {
+    ctx.accounts.household.set_inner(Household {
+        version: Household::VERSION,
+        primary: ctx.accounts.user.key(),
+        subscription_start: ctx.accounts.passenger.subscription_start,
+        members: Vec::new(),
+        bump: ctx.bumps.household,
+    });
+
+    Ok(())
+}
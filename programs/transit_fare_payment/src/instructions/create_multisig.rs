@@ -0,0 +1,66 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        init,
+        space = 8 + Multisig::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"multisig",
+        ],
+        bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the M-of-N multisig that can take over administration
+///
+/// The multisig only gains authority once the admin proposes it with
+/// propose_admin and a multisig proposal accepts the role.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` multisig: [Multisig]
+/// 3. `[signer]` admin: [AccountInfo] Administrator account
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - owners: [Vec<Pubkey>] Owners allowed to propose and approve, at most 10
+/// - threshold: [u8] Approvals needed to execute a proposal
+pub fn handler(
+    ctx: Context<CreateMultisig>,
+    owners: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    Multisig::validate(&owners, threshold)?;
+
+    ctx.accounts.multisig.set_inner(Multisig {
+        version: Multisig::VERSION,
+        owners,
+        threshold,
+        proposal_count: 0,
+        bump: ctx.bumps.multisig,
+    });
+
+    Ok(())
+}
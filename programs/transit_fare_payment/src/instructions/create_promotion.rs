@@ -0,0 +1,119 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(
+    code_hash: [u8; 32],
+)]
+pub struct CreatePromotion<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        init,
+        space = 8 + Promotion::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"promotion",
+            code_hash.as_ref(),
+        ],
+        bump,
+    )]
+    pub promotion: Account<'info, Promotion>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a promo code campaign
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` promotion: [Promotion]
+/// 3. `[]` roles: [Roles] Role holders
+/// 4. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - code_hash: [[u8; 32]] sha256 of the promo code
+/// - discount_type: [u8] Discount type (0 = percentage, 1 = fixed amount, 2 = free ride)
+/// - discount_value: [u64] Basis points for percentage, fare units off the price before currency conversion for fixed, unused for free ride
+/// - applicable_modes: [u8] Bitmask of transport modes the promotion covers
+/// - applicable_products: [u8] Bitmask of products (1 = ticket, 2 = monthly pass, 4 = yearly pass)
+/// - valid_from: [i64] Start of the validity window
+/// - valid_until: [i64] End of the validity window
+/// - max_redemptions: [u32] Global redemption limit, 0 = unlimited
+/// - max_per_user: [u32] Redemptions allowed per rider, 0 = unlimited
+pub fn handler(
+    ctx: Context<CreatePromotion>,
+    code_hash: [u8; 32],
+    discount_type: u8,
+    discount_value: u64,
+    applicable_modes: u8,
+    applicable_products: u8,
+    valid_from: i64,
+    valid_until: i64,
+    max_redemptions: u32,
+    max_per_user: u32,
+) -> Result<()> {
+    match discount_type {
+        crate::PROMO_DISCOUNT_PERCENTAGE => {
+            require!(discount_value > 0 && discount_value <= crate::BASIS_POINTS, FarePaymentError::InvalidPromotion);
+        }
+        crate::PROMO_DISCOUNT_FIXED => {
+            require!(discount_value > 0, FarePaymentError::InvalidPromotion);
+        }
+        crate::PROMO_DISCOUNT_FREE_RIDE => {
+            // A free ride only makes sense for single tickets
+            require!(applicable_products == crate::PROMO_PRODUCT_TICKET, FarePaymentError::InvalidPromotion);
+        }
+        _ => return Err(FarePaymentError::InvalidPromotion.into()),
+    }
+
+    require!(
+        applicable_modes != 0 && applicable_modes & !crate::ALL_TRANSPORT_MODES == 0,
+        FarePaymentError::InvalidTransportMode
+    );
+    require!(
+        applicable_products != 0 && applicable_products & !crate::ALL_PROMO_PRODUCTS == 0,
+        FarePaymentError::InvalidPromotion
+    );
+    require!(valid_until > valid_from, FarePaymentError::InvalidPromotionWindow);
+
+    ctx.accounts.promotion.set_inner(Promotion {
+        version: Promotion::VERSION,
+        code_hash,
+        discount_type,
+        discount_value,
+        applicable_modes,
+        applicable_products,
+        valid_from,
+        valid_until,
+        max_redemptions,
+        redemptions: 0,
+        max_per_user,
+        bump: ctx.bumps.promotion,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"multisig",
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        space = 8 + Proposal::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"proposal",
+            multisig.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a privileged action for the multisig owners to approve
+///
+/// The proposer's approval is recorded with the proposal.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` multisig: [Multisig]
+/// 2. `[writable]` proposal: [Proposal]
+/// 3. `[signer]` proposer: [AccountInfo] Multisig owner making the proposal
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - action: [MultisigAction] Action to carry out once approved
+pub fn handler(
+    ctx: Context<CreateProposal>,
+    action: MultisigAction,
+) -> Result<()> {
+    let index = ctx.accounts.multisig.owner_index(&ctx.accounts.proposer.key())?;
+
+    ctx.accounts.proposal.set_inner(Proposal {
+        version: Proposal::VERSION,
+        proposal_id: ctx.accounts.multisig.proposal_count,
+        proposer: ctx.accounts.proposer.key(),
+        action,
+        approvals: 0,
+        executed: false,
+        created_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.proposal,
+    });
+    ctx.accounts.proposal.approve(index)?;
+
+    ctx.accounts.multisig.proposal_count += 1;
+
+    Ok(())
+}
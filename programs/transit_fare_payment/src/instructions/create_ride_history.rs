@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct CreateRideHistory<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        init,
+        space = 8 + std::mem::size_of::<RideHistory>(),
+        payer = fee_payer,
+        seeds = [
+            b"ride_history",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ride_history: AccountLoader<'info, RideHistory>,
+
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the rider's ride and payment history ring buffer
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` ride_history: [RideHistory]
+/// 2. `[signer]` user: [AccountInfo] User's wallet address
+/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<CreateRideHistory>,
+) -> Result<()> {
+    let mut ride_history = ctx.accounts.ride_history.load_init()?;
+    ride_history.user = ctx.accounts.user.key();
+    ride_history.bump = ctx.bumps.ride_history;
+    ride_history.version = RideHistory::VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(
+    employee: Pubkey,
+)]
+pub struct EnrollEmployee<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"employer",
+            admin.key().as_ref(),
+        ],
+        bump = employer.bump,
+    )]
+    pub employer: Account<'info, Employer>,
+
+    #[account(
+        init,
+        space = 8 + EmployeeBenefit::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"employee_benefit",
+            employer.key().as_ref(),
+            employee.as_ref(),
+        ],
+        bump,
+    )]
+    pub employee_benefit: Account<'info, EmployeeBenefit>,
+
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Enroll an employee wallet in the employer's transit benefits
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` employer: [Employer]
+/// 2. `[writable]` employee_benefit: [EmployeeBenefit]
+/// 3. `[signer]` admin: [AccountInfo] Employer administrator
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - employee: [Pubkey] Employee wallet to enroll
+/// - monthly_allowance: [u64] Maximum the employee may draw from the escrow per month
+pub fn handler(
+    ctx: Context<EnrollEmployee>,
+    employee: Pubkey,
+    monthly_allowance: u64,
+) -> Result<()> {
+    ctx.accounts.employee_benefit.set_inner(EmployeeBenefit {
+        version: EmployeeBenefit::VERSION,
+        employer: ctx.accounts.employer.key(),
+        employee,
+        monthly_allowance,
+        allowance_month: 0,
+        spent_this_month: 0,
+        bump: ctx.bumps.employee_benefit,
+    });
+
+    ctx.accounts.employer.enrolled_employees += 1;
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"multisig",
+        ],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"proposal",
+            proposal.proposal_id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        init,
+        space = 8 + RoleChange::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"role_change",
+            roles.change_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub role_change: Option<Account<'info, RoleChange>>,
+
+    #[account(
+        mut,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+    )]
+    pub destination: Option<Account<'info, TokenAccount>>,
+
+    pub mint: Option<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+impl<'info> ExecuteProposal<'info> {
+    /// Write the role change record for an admin or role change made by the multisig
+    pub fn record_role_change(&mut self, role: u8, previous: Pubkey, new: Pubkey, bump: u8) -> Result<()> {
+        let change = self.role_change.as_mut().ok_or(FarePaymentError::InvalidActionAccounts)?;
+        self.roles.record_change(change, role, previous, new, self.multisig.key(), bump)
+    }
+
+    /// Transfer `amount` out of a treasury token account owned by the multisig
+    pub fn withdraw(&self, treasury: Pubkey, destination: Pubkey, amount: u64) -> Result<()> {
+        let treasury_account = self.treasury.as_ref().ok_or(FarePaymentError::InvalidActionAccounts)?;
+        let destination_account = self.destination.as_ref().ok_or(FarePaymentError::InvalidActionAccounts)?;
+        let mint = self.mint.as_ref().ok_or(FarePaymentError::InvalidActionAccounts)?;
+        let token_program = self.token_program.as_ref().ok_or(FarePaymentError::InvalidActionAccounts)?;
+
+        // The executor must pass exactly the accounts the owners approved
+        require_keys_eq!(treasury_account.key(), treasury, FarePaymentError::InvalidActionAccounts);
+        require_keys_eq!(destination_account.key(), destination, FarePaymentError::InvalidActionAccounts);
+        require_keys_eq!(treasury_account.owner, self.multisig.key(), FarePaymentError::InvalidTreasury);
+        require_keys_eq!(treasury_account.mint, mint.key(), FarePaymentError::CurrencyMismatch);
+        require!(amount > 0, FarePaymentError::InvalidAmount);
+        require!(amount <= treasury_account.amount, FarePaymentError::InsufficientFunds);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[b"multisig", &[self.multisig.bump]]];
+        anchor_spl::token::transfer_checked(
+            CpiContext::new_with_signer(token_program.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: treasury_account.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: destination_account.to_account_info(),
+                    authority: self.multisig.to_account_info()
+                },
+                signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+}
+
+/// Carry out a multisig proposal that reached its approval threshold
+///
+/// Anyone may call this. Every action except AcceptAdmin and Withdraw
+/// requires the multisig to be the admin; Withdraw requires the multisig to
+/// own the treasury token account. Role and admin changes need role_change;
+/// withdrawals need treasury, destination, mint and token_program.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` multisig: [Multisig]
+/// 3. `[writable]` proposal: [Proposal]
+/// 4. `[writable]` roles: [Roles]
+/// 5. `[writable, optional]` role_change: [RoleChange] Record of an admin or role change
+/// 6. `[writable, optional]` treasury: [TokenAccount] Multisig-owned token account to withdraw from
+/// 7. `[writable, optional]` destination: [TokenAccount] Token account receiving a withdrawal
+/// 8. `[optional]` mint: [Mint] Mint of the treasury
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[optional]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ExecuteProposal>,
+) -> Result<()> {
+    require!(!ctx.accounts.proposal.executed, FarePaymentError::ProposalAlreadyExecuted);
+    require!(
+        ctx.accounts.proposal.is_approved(ctx.accounts.multisig.threshold),
+        FarePaymentError::ThresholdNotReached
+    );
+
+    let multisig_key = ctx.accounts.multisig.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    let bump = ctx.bumps.role_change;
+
+    let action = ctx.accounts.proposal.action.clone();
+    if action.requires_admin() {
+        require_keys_eq!(ctx.accounts.fare_config.admin, multisig_key, FarePaymentError::Unauthorized);
+    }
+
+    match action {
+        MultisigAction::AcceptAdmin => {
+            let previous = ctx.accounts.fare_config.accept_admin(multisig_key)?;
+            ctx.accounts.record_role_change(crate::ROLE_ADMIN, previous, multisig_key, bump)?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::ProposeAdmin { new_admin } => {
+            ctx.accounts.fare_config.pending_admin = new_admin;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::SetRole { role, holder } => {
+            let previous = ctx.accounts.fare_config.assign_role(&mut ctx.accounts.roles, role, holder)?;
+            ctx.accounts.record_role_change(role, previous, holder, bump)?;
+            if role == crate::ROLE_TREASURY {
+                ctx.accounts.fare_config.record_update(multisig_key)?;
+            }
+        }
+        MultisigAction::ScheduleFareChange {
+            mode_0_fare,
+            mode_1_fare,
+            monthly_pass_price,
+            yearly_pass_price,
+            effective_at,
+        } => {
+            ctx.accounts.fare_config.schedule_fare_change(
+                mode_0_fare,
+                mode_1_fare,
+                monthly_pass_price,
+                yearly_pass_price,
+                effective_at,
+                current_time,
+            )?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::CancelFareChange => {
+            ctx.accounts.fare_config.cancel_fare_change(current_time)?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::Withdraw { treasury, destination, amount } => {
+            ctx.accounts.withdraw(treasury, destination, amount)?;
+        }
+        MultisigAction::Unpause { flags } => {
+            ctx.accounts.fare_config.unpause(flags)?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::SetGuardian { guardian } => {
+            ctx.accounts.fare_config.guardian = guardian;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::SetFareNoticePeriod { min_fare_notice } => {
+            ctx.accounts.fare_config.set_fare_notice(min_fare_notice, current_time)?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+        MultisigAction::Pause { flags } => {
+            ctx.accounts.fare_config.pause(flags)?;
+            ctx.accounts.fare_config.record_update(multisig_key)?;
+        }
+    }
+
+    ctx.accounts.proposal.executed = true;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ExpireSubscription<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            passenger.user.as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+}
+
+/// Clear a lapsed subscription so it stops counting as active
+///
+/// Anyone may call this once `subscription_end` has passed. If the fare
+/// config holds enough spare lamports, the caller receives
+/// `expiry_crank_reward` lamports for doing so.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Caller, receives the crank reward
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger] Passenger whose subscription has lapsed
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ExpireSubscription>,
+) -> Result<()> {
+    // Verify that the passenger still has a subscription on record
+    require!(
+        ctx.accounts.passenger.subscription_type > 0,
+        FarePaymentError::SubscriptionNotFound
+    );
+
+    // Verify that the subscription has actually expired
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= ctx.accounts.passenger.subscription_end,
+        FarePaymentError::SubscriptionStillActive
+    );
+
+    let subscription_type = ctx.accounts.passenger.subscription_type;
+    let subscription_start = ctx.accounts.passenger.subscription_start;
+    let subscription_end = ctx.accounts.passenger.subscription_end;
+
+    // Reset subscription fields so the pass can't be expired twice
+    ctx.accounts.passenger.clear_subscription();
+
+    // Decrement active subscriptions counter
+    ctx.accounts.fare_config.total_active_subscriptions = ctx.accounts.fare_config.total_active_subscriptions.saturating_sub(1);
+
+    // Pay the caller from the fare config's spare lamports, never dipping below rent exemption
+    let mut reward = ctx.accounts.fare_config.expiry_crank_reward;
+    if reward > 0 {
+        let fare_config_info = ctx.accounts.fare_config.to_account_info();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(fare_config_info.data_len());
+
+        if fare_config_info.lamports() >= rent_exempt_minimum.saturating_add(reward) {
+            **fare_config_info.try_borrow_mut_lamports()? -= reward;
+            **ctx.accounts.fee_payer.to_account_info().try_borrow_mut_lamports()? += reward;
+        } else {
+            reward = 0;
+        }
+    }
+
+    emit!(SubscriptionExpired {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.passenger.user,
+        subscription_type,
+        subscription_start,
+        subscription_end,
+        expired_by: ctx.accounts.fee_payer.key(),
+        reward,
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
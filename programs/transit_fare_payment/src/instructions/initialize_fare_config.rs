@@ -1,5 +1,4 @@
 use crate::*;
-use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct InitializeFareConfig<'info> {
@@ -8,7 +7,7 @@ pub struct InitializeFareConfig<'info> {
 
     #[account(
         init,
-        space = 145,
+        space = 8 + FareConfig::INIT_SPACE,
         payer = fee_payer,
         seeds = [
             b"fare_config",
@@ -17,6 +16,17 @@ pub struct InitializeFareConfig<'info> {
     )]
     pub fare_config: Account<'info, FareConfig>,
 
+    #[account(
+        init,
+        space = 8 + Roles::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"roles",
+        ],
+        bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
     pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
@@ -27,8 +37,9 @@ pub struct InitializeFareConfig<'info> {
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
 /// 1. `[writable]` fare_config: [FareConfig]
-/// 2. `[signer]` admin: [AccountInfo] Administrator account
-/// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 2. `[writable]` roles: [Roles] Role holders, all initially the admin
+/// 3. `[signer]` admin: [AccountInfo] Administrator account
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
 ///
 /// Data:
 /// - mode_0_fare: [u64] Default bus fare amount (transport mode 0 = bus)
@@ -36,6 +47,8 @@ pub struct InitializeFareConfig<'info> {
 /// - currency_mint: [Pubkey] Currency mint address
 /// - monthly_pass_price: [u64] Price for 30-day subscription
 /// - yearly_pass_price: [u64] Price for 365-day subscription
+/// - cancellation_fee: [u64] Flat fee withheld from subscription refunds
+/// - non_refundable_period: [i64] Seconds of a subscription that are never refunded
 pub fn handler(
     ctx: Context<InitializeFareConfig>,
     mode_0_fare: u64,
@@ -43,9 +56,15 @@ pub fn handler(
     currency_mint: Pubkey,
     monthly_pass_price: u64,
     yearly_pass_price: u64,
+    cancellation_fee: u64,
+    non_refundable_period: i64,
 ) -> Result<()> {
+    require!(non_refundable_period >= 0, FarePaymentError::InvalidNonRefundablePeriod);
+
     ctx.accounts.fare_config.set_inner(FareConfig {
+        version: FareConfig::VERSION,
         admin: ctx.accounts.admin.key(),
+        treasury: ctx.accounts.admin.key(),
         bus_fare: mode_0_fare,      // Transport mode 0: bus
         train_fare: mode_1_fare,    // Transport mode 1: train
         currency_mint,
@@ -53,8 +72,40 @@ pub fn handler(
         monthly_pass_price,
         yearly_pass_price,
         total_active_subscriptions: 0,
+        expiry_crank_reward: 0,
+        cancellation_fee,
+        non_refundable_period,
+        subscription_allowed_modes: crate::ALL_TRANSPORT_MODES,
+        subscription_max_rides_per_day: 0,
+        subscription_max_rides_per_period: 0,
+        loyalty_rates: [0; crate::TRANSPORT_MODE_COUNT],
+        loyalty_subscription_rate: 0,
+        loyalty_bonus_multiplier: crate::BASIS_POINTS as u16,
+        loyalty_bonus_start: 0,
+        loyalty_bonus_end: 0,
+        loyalty_free_ticket_cost: 0,
+        price_feed: Pubkey::default(),
+        max_price_age: 0,
+        max_confidence_bps: 0,
+        pending_price_feed: PriceFeedSettings::default(),
+        ticket_validity_period: 0,
+        payment_retention_period: 0,
+        total_receipts: 0,
+        pending_admin: Pubkey::default(),
+        guardian: Pubkey::default(),
+        paused: 0,
+        min_fare_notice: crate::MIN_FARE_NOTICE_FLOOR,
+        pending_min_fare_notice: 0,
+        min_fare_notice_effective_at: 0,
+        pending_fares: FareSchedule::default(),
+        config_version: 0,
         bump: ctx.bumps.fare_config,
     });
 
+    // The admin holds every role until it delegates them
+    ctx.accounts.roles.set_inner(Roles::held_by(ctx.accounts.admin.key(), ctx.bumps.roles));
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+
     Ok(())
 }
\ No newline at end of file
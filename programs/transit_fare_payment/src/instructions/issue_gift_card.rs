@@ -0,0 +1,143 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(
+    claim_key: Pubkey,
+)]
+pub struct IssueGiftCard<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        init,
+        space = 8 + GiftCard::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"gift_card",
+            claim_key.as_ref(),
+        ],
+        bump,
+    )]
+    pub gift_card: Account<'info, GiftCard>,
+
+    #[account(
+        init,
+        payer = fee_payer,
+        seeds = [
+            b"gift_card_escrow",
+            gift_card.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = fare_config,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    pub issuer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"retail_partner",
+            issuer.key().as_ref(),
+        ],
+        bump = retail_partner.bump,
+    )]
+    pub retail_partner: Option<Account<'info, RetailPartner>>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: debited by transfer_checked, which checks its mint and that authority can spend from it
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        address = fare_config.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Issue a prepaid gift card claimable with a secret code
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[writable]` gift_card: [GiftCard]
+/// 4. `[writable]` escrow: [TokenAccount] Token account holding the card value
+/// 5. `[signer]` issuer: [AccountInfo] Eligibility issuer or retail partner issuing the card
+/// 6. `[optional]` retail_partner: [RetailPartner] Required when the issuer is not the eligibility issuer
+/// 7. `[writable]` source: [AccountInfo] The source account.
+/// 8. `[]` mint: [Mint] The token mint.
+/// 9. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 10. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 11. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data:
+/// - claim_key: [Pubkey] Public key of the keypair derived from the secret claim code
+/// - value: [u64] Value loaded onto the card
+/// - expires_at: [i64] Timestamp after which the card can no longer be redeemed
+pub fn handler(
+    ctx: Context<IssueGiftCard>,
+    claim_key: Pubkey,
+    value: u64,
+    expires_at: i64,
+) -> Result<()> {
+    // Only the eligibility issuer and registered retail partners may issue cards
+    require!(
+        ctx.accounts.issuer.key() == ctx.accounts.roles.eligibility_issuer || ctx.accounts.retail_partner.is_some(),
+        FarePaymentError::Unauthorized
+    );
+
+    require!(value > 0, FarePaymentError::InvalidAmount);
+    require!(expires_at > Clock::get()?.unix_timestamp, FarePaymentError::GiftCardExpired);
+
+    // Fund the card escrow
+    anchor_spl::token::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info()
+            }
+        ),
+        value,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.gift_card.set_inner(GiftCard {
+        version: GiftCard::VERSION,
+        issuer: ctx.accounts.issuer.key(),
+        claim_key,
+        value,
+        currency_mint: ctx.accounts.mint.key(),
+        expires_at,
+        bump: ctx.bumps.gift_card,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateFareConfig<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    /// CHECK: Legacy layout, decoded and validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"fare_config",
+        ],
+        bump,
+    )]
+    pub fare_config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        space = 8 + Roles::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"roles",
+        ],
+        bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade an unversioned fare config account to the current layout in place
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+/// 1. `[writable]` fare_config: [FareConfig] Fare config in the unversioned layout
+/// 2. `[writable]` roles: [Roles] Role holders, all initially the admin
+/// 3. `[signer]` admin: [AccountInfo] Administrator recorded in the old account
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up and roles
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<MigrateFareConfig>,
+) -> Result<()> {
+    let info = ctx.accounts.fare_config.to_account_info();
+    let legacy: FareConfigV0 = read_legacy(
+        &info,
+        FareConfig::DISCRIMINATOR,
+        FareConfig::VERSION,
+        FareConfigV0::LEN,
+        FareConfigV0::MAX_LEN,
+    )?;
+    require_keys_eq!(legacy.admin, ctx.accounts.admin.key(), FarePaymentError::Unauthorized);
+
+    let mut fare_config = legacy.upgrade(ctx.bumps.fare_config);
+    fare_config.record_update(ctx.accounts.admin.key())?;
+
+    grow_account(
+        &info,
+        8 + FareConfig::INIT_SPACE,
+        &ctx.accounts.fee_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    fare_config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    // The unversioned program had no roles, so the admin holds every one
+    ctx.accounts.roles.set_inner(Roles::held_by(ctx.accounts.admin.key(), ctx.bumps.roles));
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigratePassenger<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    /// CHECK: Legacy layout, decoded and validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub passenger: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the passenger account, only used for PDA derivation
+    pub user: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade an unversioned passenger account to the current layout in place.
+/// Permissionless, the fare config must be migrated first.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+/// 1. `[]` fare_config: [FareConfig] Prices an active pass for later refunds
+/// 2. `[writable]` passenger: [Passenger] Passenger in the unversioned layout
+/// 3. `[]` user: [AccountInfo] Owner of the passenger account
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<MigratePassenger>,
+) -> Result<()> {
+    let info = ctx.accounts.passenger.to_account_info();
+    let legacy: PassengerV0 = read_legacy(
+        &info,
+        Passenger::DISCRIMINATOR,
+        Passenger::VERSION,
+        PassengerV0::LEN,
+        PassengerV0::MAX_LEN,
+    )?;
+
+    require_keys_eq!(legacy.user, ctx.accounts.user.key(), FarePaymentError::InvalidAccountData);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let passenger = legacy.upgrade(&ctx.accounts.fare_config, current_time, ctx.bumps.passenger)?;
+
+    grow_account(
+        &info,
+        8 + Passenger::INIT_SPACE,
+        &ctx.accounts.fee_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    passenger.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
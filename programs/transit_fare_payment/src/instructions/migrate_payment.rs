@@ -0,0 +1,86 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+#[instruction(
+    payment_id: u64,
+)]
+pub struct MigratePayment<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    /// CHECK: Legacy layout, decoded and validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"payment",
+            user.key().as_ref(),
+            payment_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the receipt, only used for PDA derivation
+    pub user: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade a receipt written by the unversioned record_payment to the
+/// current layout in place. Permissionless, the passenger must be
+/// migrated first.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+/// 1. `[writable]` passenger: [Passenger] Moves new receipt ids past the migrated one
+/// 2. `[writable]` payment: [Payment] Receipt in the unversioned layout
+/// 3. `[]` user: [AccountInfo] Owner of the receipt
+/// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+///
+/// Data:
+/// - payment_id: [u64] Id the receipt was recorded with
+pub fn handler(
+    ctx: Context<MigratePayment>,
+    payment_id: u64,
+) -> Result<()> {
+    let info = ctx.accounts.payment.to_account_info();
+    let legacy: PaymentV0 = read_legacy(
+        &info,
+        Payment::DISCRIMINATOR,
+        Payment::VERSION,
+        PaymentV0::LEN,
+        PaymentV0::MAX_LEN,
+    )?;
+
+    require_keys_eq!(legacy.user, ctx.accounts.user.key(), FarePaymentError::InvalidAccountData);
+    require!(legacy.payment_id == payment_id, FarePaymentError::InvalidAccountData);
+
+    // Riders picked their own ids; new receipts start after the highest one
+    // so they never land on a migrated receipt's address
+    let passenger = &mut ctx.accounts.passenger;
+    passenger.payment_count = passenger.payment_count.max(payment_id.saturating_add(1));
+
+    let payment = legacy.upgrade(ctx.bumps.payment);
+
+    grow_account(
+        &info,
+        8 + Payment::INIT_SPACE,
+        &ctx.accounts.fee_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    payment.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+#[instruction(
+    ticket_id: u64,
+)]
+pub struct MigrateTicket<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    /// CHECK: Legacy layout, decoded and validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"ticket",
+            user.key().as_ref(),
+            ticket_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub ticket: UncheckedAccount<'info>,
+
+    /// CHECK: Owner of the ticket, only used for PDA derivation
+    pub user: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Upgrade an unversioned ticket to the current layout in place.
+/// Permissionless, the passenger must be migrated first.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+/// 1. `[]` fare_config: [FareConfig] Supplies the mint the old program took payment in
+/// 2. `[writable]` passenger: [Passenger] Counts the ticket if it is unused
+/// 3. `[writable]` ticket: [Ticket] Ticket in the unversioned layout
+/// 4. `[]` user: [AccountInfo] Owner of the ticket
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+///
+/// Data:
+/// - ticket_id: [u64] Id the ticket was bought with
+pub fn handler(
+    ctx: Context<MigrateTicket>,
+    ticket_id: u64,
+) -> Result<()> {
+    let info = ctx.accounts.ticket.to_account_info();
+    let legacy: TicketV0 = read_legacy(
+        &info,
+        Ticket::DISCRIMINATOR,
+        Ticket::VERSION,
+        TicketV0::LEN,
+        TicketV0::MAX_LEN,
+    )?;
+
+    require_keys_eq!(legacy.user, ctx.accounts.user.key(), FarePaymentError::InvalidAccountData);
+    require!(legacy.ticket_id == ticket_id, FarePaymentError::InvalidAccountData);
+
+    let ticket = legacy.upgrade(ctx.accounts.fare_config.currency_mint, ctx.bumps.ticket);
+
+    // An unused ticket keeps the passenger account open until it is used,
+    // refunded or closed, like one bought after versioning
+    if ticket.status == 0 {
+        ctx.accounts.passenger.outstanding_tickets += 1;
+    }
+
+    grow_account(
+        &info,
+        8 + Ticket::INIT_SPACE,
+        &ctx.accounts.fee_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    ticket.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
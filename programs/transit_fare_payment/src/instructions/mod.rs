@@ -1,19 +1,114 @@
+// Every module exports its own `handler`, always called by its qualified path
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize_fare_config;
 pub mod update_fare_config;
 pub mod purchase_ticket;
 pub mod use_ticket;
 pub mod refund_ticket;
-pub mod record_payment;
 pub mod purchase_subscription;
 pub mod use_subscription_ride;
 pub mod cancel_subscription;
+pub mod expire_subscription;
+pub mod create_household;
+pub mod add_household_member;
+pub mod remove_household_member;
+pub mod create_employer;
+pub mod enroll_employee;
+pub mod unenroll_employee;
+pub mod top_up_employer;
+pub mod withdraw_employer;
+pub mod top_up_stored_value;
+pub mod withdraw_stored_value;
+pub mod pay_fare_from_balance;
+pub mod set_low_balance_threshold;
+pub mod update_loyalty_config;
+pub mod redeem_points;
+pub mod create_promotion;
+pub mod add_retail_partner;
+pub mod remove_retail_partner;
+pub mod issue_gift_card;
+pub mod redeem_gift_card;
+pub mod reclaim_gift_card;
+pub mod add_accepted_currency;
+pub mod update_accepted_currency;
+pub mod set_price_feed;
+pub mod create_ride_history;
+pub mod close_ticket;
+pub mod close_payment;
+pub mod set_retention_policy;
+pub mod close_passenger;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_role;
+pub mod set_guardian;
+pub mod pause;
+pub mod unpause;
+pub mod schedule_fare_change;
+pub mod cancel_fare_change;
+pub mod apply_fare_change;
+pub mod set_fare_notice_period;
+pub mod create_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod migrate_fare_config;
+pub mod migrate_passenger;
+pub mod migrate_ticket;
+pub mod migrate_payment;
 
 pub use initialize_fare_config::*;
 pub use update_fare_config::*;
 pub use purchase_ticket::*;
 pub use use_ticket::*;
 pub use refund_ticket::*;
-pub use record_payment::*;
 pub use purchase_subscription::*;
 pub use use_subscription_ride::*;
 pub use cancel_subscription::*;
+pub use expire_subscription::*;
+pub use create_household::*;
+pub use add_household_member::*;
+pub use remove_household_member::*;
+pub use create_employer::*;
+pub use enroll_employee::*;
+pub use unenroll_employee::*;
+pub use top_up_employer::*;
+pub use withdraw_employer::*;
+pub use top_up_stored_value::*;
+pub use withdraw_stored_value::*;
+pub use pay_fare_from_balance::*;
+pub use set_low_balance_threshold::*;
+pub use update_loyalty_config::*;
+pub use redeem_points::*;
+pub use create_promotion::*;
+pub use add_retail_partner::*;
+pub use remove_retail_partner::*;
+pub use issue_gift_card::*;
+pub use redeem_gift_card::*;
+pub use reclaim_gift_card::*;
+pub use add_accepted_currency::*;
+pub use update_accepted_currency::*;
+pub use set_price_feed::*;
+pub use create_ride_history::*;
+pub use close_ticket::*;
+pub use close_payment::*;
+pub use set_retention_policy::*;
+pub use close_passenger::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use set_role::*;
+pub use set_guardian::*;
+pub use pause::*;
+pub use unpause::*;
+pub use schedule_fare_change::*;
+pub use cancel_fare_change::*;
+pub use apply_fare_change::*;
+pub use set_fare_notice_period::*;
+pub use create_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use migrate_fare_config::*;
+pub use migrate_passenger::*;
+pub use migrate_ticket::*;
+pub use migrate_payment::*;
@@ -0,0 +1,46 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Pause one or more instruction families
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[signer]` authority: [AccountInfo] Administrator or guardian
+///
+/// Data:
+/// - flags: [u8] Bitmask of families to pause (1 = ticket sales, 2 = ticket use, 4 = refunds, 8 = subscription purchase, 16 = subscription cancel)
+pub fn handler(
+    ctx: Context<Pause>,
+    flags: u8,
+) -> Result<()> {
+    // The guardian may pause, only the admin may undo it
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == ctx.accounts.fare_config.admin
+            || (ctx.accounts.fare_config.guardian != Pubkey::default() && authority == ctx.accounts.fare_config.guardian),
+        FarePaymentError::Unauthorized
+    );
+
+    ctx.accounts.fare_config.pause(flags)?;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.authority.key())?;
+
+    Ok(())
+}
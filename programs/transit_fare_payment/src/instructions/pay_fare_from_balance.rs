@@ -0,0 +1,146 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct PayFareFromBalance<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        init,
+        space = 8 + Payment::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"payment",
+            user.key().as_ref(),
+            passenger.payment_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stored_value",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stored_value: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = destination.owner == fare_config.treasury @ FarePaymentError::InvalidTreasury,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    #[account(
+        address = fare_config.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: compared with fare_config.price_feed and parsed as a PriceFeed
+    pub price_feed: Option<UncheckedAccount<'info>>,
+}
+
+/// Pay a fare at boarding from the passenger's stored-value balance
+///
+/// No ticket account is created and nothing is transferred out of the
+/// rider's wallet; the fare moves from the stored-value escrow to the
+/// treasury.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger]
+/// 3. `[writable]` payment: [Payment] Receipt for this fare
+/// 4. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+/// 5. `[signer]` user: [AccountInfo] User's wallet address
+/// 6. `[writable]` destination: [TokenAccount] System treasury token account
+/// 7. `[]` mint: [Mint] The token mint.
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 10. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
+///
+/// Data:
+/// - transport_mode: [u8] Transport mode (0 = bus, 1 = train)
+/// - max_price: [u64] Most the rider agrees to pay for the fare
+pub fn handler(
+    ctx: Context<PayFareFromBalance>,
+    transport_mode: u8,
+    max_price: u64,
+) -> Result<()> {
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_TICKET_SALES)?;
+
+    // Validate transport mode (0 = bus, 1 = train)
+    transit_core::validate_transport_mode(transport_mode).map_err(FarePaymentError::from)?;
+
+    // Get the appropriate fare based on transport mode
+    let current_time = Clock::get()?.unix_timestamp;
+    let fare = ctx.accounts.fare_config.current_fares(current_time).fare(transport_mode)?;
+
+    // Fiat fares are converted at the oracle price
+    let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+    let fare = ctx.accounts.fare_config.price_in_tokens(fare, price_feed.as_ref(), ctx.accounts.mint.decimals, current_time)?;
+    require!(fare <= max_price, FarePaymentError::PriceAboveMaximum);
+
+    require!(ctx.accounts.passenger.stored_value_balance >= fare, FarePaymentError::InsufficientFunds);
+
+    // Transfer tokens from the stored-value escrow to system
+    let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[ctx.accounts.fare_config.bump]]];
+    anchor_spl::token::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.stored_value.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.fare_config.to_account_info()
+            },
+            signer_seeds,
+        ),
+        fare,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Update passenger stats
+    ctx.accounts.passenger.stored_value_balance -= fare;
+    ctx.accounts.passenger.total_spent += fare;
+    ctx.accounts.passenger.last_ticket_timestamp = current_time;
+
+    // Write the payment receipt
+    let passenger_key = ctx.accounts.passenger.key();
+    let mint_key = ctx.accounts.mint.key();
+    ctx.accounts.passenger.record_payment(&mut ctx.accounts.fare_config, &mut ctx.accounts.payment, crate::PAYMENT_PRODUCT_STORED_VALUE_FARE, passenger_key, fare, mint_key, ctx.bumps.payment)?;
+    ctx.accounts.payment.rent_payer = ctx.accounts.fee_payer.key();
+
+    emit!(PaymentRecorded::new(ctx.accounts.payment.key(), &ctx.accounts.payment, ctx.accounts.fare_config.config_version));
+
+    Ok(())
+}
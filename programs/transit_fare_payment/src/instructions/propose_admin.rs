@@ -0,0 +1,43 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// Propose a new admin, who takes over once they accept
+///
+/// Proposing the default pubkey cancels a pending transfer.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[signer]` admin: [AccountInfo] Current administrator
+///
+/// Data:
+/// - new_admin: [Pubkey] Account that must call accept_admin to become admin
+pub fn handler(
+    ctx: Context<ProposeAdmin>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    ctx.accounts.fare_config.pending_admin = new_admin;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+
+    Ok(())
+}
@@ -1,20 +1,18 @@
 use crate::*;
-use anchor_lang::prelude::*;
 
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
-};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
 #[instruction(
     subscription_type: u8,
+    seat_count: u8,
 )]
 pub struct PurchaseSubscription<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             b"fare_config",
         ],
@@ -24,7 +22,7 @@ pub struct PurchaseSubscription<'info> {
 
     #[account(
         init_if_needed,
-        space = 109,
+        space = 8 + Passenger::INIT_SPACE,
         payer = fee_payer,
         seeds = [
             b"passenger",
@@ -34,6 +32,19 @@ pub struct PurchaseSubscription<'info> {
     )]
     pub passenger: Account<'info, Passenger>,
 
+    #[account(
+        init,
+        space = 8 + Payment::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"payment",
+            user.key().as_ref(),
+            passenger.next_payment_id(&fare_config).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+
     pub user: Signer<'info>,
 
     #[account(
@@ -69,6 +80,48 @@ pub struct PurchaseSubscription<'info> {
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+
+    pub employer: Option<Account<'info, Employer>>,
+
+    #[account(
+        mut,
+    )]
+    pub employee_benefit: Option<Account<'info, EmployeeBenefit>>,
+
+    #[account(
+        mut,
+    )]
+    pub employer_escrow: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+    )]
+    pub promotion: Option<Account<'info, Promotion>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + PromotionRedemption::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"promotion_redemption",
+            promotion.as_ref().map(|p| p.key()).unwrap_or_default().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub promotion_redemption: Option<Account<'info, PromotionRedemption>>,
+
+    #[account(
+        seeds = [
+            b"accepted_currency",
+            mint.key().as_ref(),
+        ],
+        bump = accepted_currency.bump,
+    )]
+    pub accepted_currency: Option<Account<'info, AcceptedCurrency>>,
+
+    /// CHECK: compared with fare_config.price_feed and parsed as a PriceFeed
+    pub price_feed: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> PurchaseSubscription<'info> {
@@ -86,6 +139,83 @@ impl<'info> PurchaseSubscription<'info> {
             decimals,
         )
     }
+
+    pub fn cpi_sponsored_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+        let employer = self.employer.as_ref().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+        let escrow = self.employer_escrow.as_ref().ok_or(FarePaymentError::InvalidEscrow)?;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"employer", employer.admin.as_ref(), &[employer.bump]]];
+
+        anchor_spl::token::transfer_checked(
+            CpiContext::new_with_signer(self.token_program.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: escrow.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: employer.to_account_info()
+                },
+                signer_seeds,
+            ),
+            amount,
+            decimals,
+        )
+    }
+
+    /// Check that the employee may draw `amount` from the employer escrow and record it
+    pub fn charge_employer(&mut self, amount: u64, current_time: i64) -> Result<Pubkey> {
+        let employer = self.employer.as_ref().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+        let escrow = self.employer_escrow.as_ref().ok_or(FarePaymentError::InvalidEscrow)?;
+        let benefit = self.employee_benefit.as_mut().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+
+        employer.verify_benefit(employer.key(), benefit, escrow.key(), self.user.key())?;
+
+        benefit.charge(amount, current_time)?;
+
+        Ok(escrow.key())
+    }
+
+    /// Redeem the promo code against the promotion, returning the discounted price
+    pub fn apply_promotion(&mut self, code: Option<String>, product: u8, modes: u8, price: u64, current_time: i64, redemption_bump: u8) -> Result<u64> {
+        let code = code.ok_or(FarePaymentError::InvalidPromoCode)?;
+        let promotion = self.promotion.as_mut().ok_or(FarePaymentError::InvalidPromoCode)?;
+        let redemption = self.promotion_redemption.as_mut().ok_or(FarePaymentError::InvalidPromoCode)?;
+
+        // First redemption by this rider
+        if redemption.count == 0 {
+            redemption.version = PromotionRedemption::VERSION;
+            redemption.promotion = promotion.key();
+            redemption.user = self.user.key();
+            redemption.bump = redemption_bump;
+        }
+
+        promotion.redeem(redemption, code.as_bytes(), product, modes, price, current_time)
+    }
+
+    /// Convert a price in fare units into the mint the rider pays with
+    pub fn price_in_payment_mint(&self, price: u64, current_time: i64) -> Result<u64> {
+        // Fiat fares are first converted into the base currency at the oracle price
+        if self.fare_config.current_price_feed(current_time).price_feed != Pubkey::default() {
+            require_keys_eq!(self.currency_mint.key(), self.fare_config.currency_mint, FarePaymentError::CurrencyMismatch);
+        }
+        let price_feed = self.price_feed.as_ref().map(|feed| feed.to_account_info());
+        let price = self.fare_config.price_in_tokens(price, price_feed.as_ref(), self.currency_mint.decimals, current_time)?;
+
+        // Every payment, sponsored or not, goes to a treasury-owned account in the paid mint
+        let destination = TokenAccount::try_deserialize(&mut &self.destination.try_borrow_data()?[..])?;
+        require_keys_eq!(destination.owner, self.fare_config.treasury, FarePaymentError::InvalidTreasury);
+        require_keys_eq!(destination.mint, self.mint.key(), FarePaymentError::CurrencyMismatch);
+
+        match &self.accepted_currency {
+            Some(currency) => {
+                require!(currency.enabled, FarePaymentError::CurrencyDisabled);
+                require_keys_eq!(self.destination.key(), currency.treasury, FarePaymentError::InvalidTreasury);
+                currency.convert(price, current_time)
+            }
+            None => {
+                require_keys_eq!(self.mint.key(), self.fare_config.currency_mint, FarePaymentError::CurrencyMismatch);
+                Ok(price)
+            }
+        }
+    }
 }
 
 /// Purchase a subscription pass for transit travel
@@ -94,25 +224,42 @@ impl<'info> PurchaseSubscription<'info> {
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
 /// 1. `[]` fare_config: [FareConfig] Fare configuration account
 /// 2. `[writable]` passenger: [Passenger]
-/// 3. `[signer]` user: [AccountInfo] User's wallet address
-/// 4. `[writable]` user_token_account: [AccountInfo] User's token account for payment
-/// 5. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
-/// 6. `[]` currency_mint: [Mint] Currency mint address
-/// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-/// 8. `[writable]` source: [AccountInfo] The source account.
-/// 9. `[]` mint: [Mint] The token mint.
-/// 10. `[writable]` destination: [AccountInfo] The destination account.
-/// 11. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 3. `[writable]` payment: [Payment] Receipt for this purchase
+/// 4. `[signer]` user: [AccountInfo] User's wallet address
+/// 5. `[writable]` user_token_account: [AccountInfo] User's token account for payment
+/// 6. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
+/// 7. `[]` currency_mint: [Mint] Currency mint address
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[writable]` source: [AccountInfo] The source account.
+/// 10. `[]` mint: [Mint] The token mint.
+/// 11. `[writable]` destination: [AccountInfo] The destination account.
+/// 12. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 13. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 14. `[optional]` employer: [Employer] Employer sponsoring the purchase
+/// 15. `[writable, optional]` employee_benefit: [EmployeeBenefit] User's enrollment with the employer
+/// 16. `[writable, optional]` employer_escrow: [TokenAccount] Employer escrow paying the pass
+/// 17. `[writable, optional]` promotion: [Promotion] Promotion to redeem
+/// 18. `[writable, optional]` promotion_redemption: [PromotionRedemption] User's redemptions of the promotion
+/// 19. `[optional]` accepted_currency: [AcceptedCurrency] Registry entry when paying in another mint
+/// 20. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
 ///
 /// Data:
 /// - subscription_type: [u8] Subscription type (1=monthly, 2=yearly)
+/// - seat_count: [u8] Riders covered by the pass (1 = individual, more = household)
+/// - max_price: [u64] Most the rider agrees to pay for the pass, in the payment mint
+/// - promo_code: [Option<String>] Promo code for the promotion (optional)
 pub fn handler(
     ctx: Context<PurchaseSubscription>,
     subscription_type: u8,
+    seat_count: u8,
+    max_price: u64,
+    promo_code: Option<String>,
 ) -> Result<()> {
-    // Validate subscription type
-    require!(subscription_type == 1 || subscription_type == 2, FarePaymentError::InvalidSubscriptionType);
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_SUBSCRIPTION_PURCHASE)?;
+
+    // Validate subscription type and seat count
+    transit_core::validate_subscription_type(subscription_type).map_err(FarePaymentError::from)?;
+    transit_core::validate_seat_count(seat_count).map_err(FarePaymentError::from)?;
 
     // Check if user already has active subscription
     let current_time = Clock::get()?.unix_timestamp;
@@ -120,39 +267,94 @@ pub fn handler(
         return Err(FarePaymentError::SubscriptionAlreadyActive.into());
     }
 
-    // Determine price and duration
-    let (price, duration_seconds) = if subscription_type == 1 {
-        // Monthly: 30 days
-        (ctx.accounts.fare_config.monthly_pass_price, 30 * 24 * 60 * 60)
+    // A lapsed pass that was never expired is still counted as active
+    let replaces_lapsed_subscription = ctx.accounts.passenger.subscription_type > 0;
+
+    // Determine price and duration, every seat on a household pass costs one pass
+    let (price, duration_seconds) = ctx.accounts.fare_config.current_fares(current_time).pass(subscription_type, seat_count)?;
+
+    // Apply the promotion, if any
+    let price = if ctx.accounts.promotion.is_some() {
+        let product = if subscription_type == crate::SUBSCRIPTION_MONTHLY {
+            crate::PROMO_PRODUCT_MONTHLY_PASS
+        } else {
+            crate::PROMO_PRODUCT_YEARLY_PASS
+        };
+        let modes = ctx.accounts.fare_config.subscription_allowed_modes;
+        ctx.accounts.apply_promotion(promo_code, product, modes, price, current_time, ctx.bumps.promotion_redemption)?
     } else {
-        // Yearly: 365 days
-        (ctx.accounts.fare_config.yearly_pass_price, 365 * 24 * 60 * 60)
+        price
     };
 
-    // Transfer tokens from user to system
-    ctx.accounts.cpi_token_transfer_checked(price, 9)?;
+    // Apply any discount redeemed with loyalty points, in fare units before conversion
+    let base_price = price.saturating_sub(ctx.accounts.passenger.pending_pass_discount);
+
+    // Convert the price into the mint the rider pays with
+    let price = ctx.accounts.price_in_payment_mint(base_price, current_time)?;
+    require!(price <= max_price, FarePaymentError::PriceAboveMaximum);
+
+    // Transfer tokens to system, from the employer escrow for sponsored employees
+    let sponsor_escrow = if ctx.accounts.employee_benefit.is_some() {
+        let escrow = ctx.accounts.charge_employer(price, current_time)?;
+        ctx.accounts.cpi_sponsored_transfer_checked(price, ctx.accounts.mint.decimals)?;
+        escrow
+    } else {
+        ctx.accounts.cpi_token_transfer_checked(price, ctx.accounts.mint.decimals)?;
+        Pubkey::default()
+    };
 
     // Initialize passenger if needed
-    if ctx.accounts.passenger.ticket_count == 0 && ctx.accounts.passenger.subscription_type == 0 {
+    if ctx.accounts.passenger.version == 0 {
+        ctx.accounts.passenger.version = Passenger::VERSION;
         ctx.accounts.passenger.user = ctx.accounts.user.key();
-        ctx.accounts.passenger.total_spent = 0;
-        ctx.accounts.passenger.ticket_count = 0;
-        ctx.accounts.passenger.last_ticket_timestamp = 0;
-        ctx.accounts.passenger.subscription_type = 0;
-        ctx.accounts.passenger.subscription_start = 0;
-        ctx.accounts.passenger.subscription_end = 0;
-        ctx.accounts.passenger.subscription_rides_used = 0;
+        ctx.accounts.passenger.bump = ctx.bumps.passenger;
+        ctx.accounts.passenger.payment_count = ctx.accounts.fare_config.total_receipts;
     }
 
     // Update passenger subscription data
-    ctx.accounts.passenger.subscription_type = subscription_type;
-    ctx.accounts.passenger.subscription_start = current_time;
-    ctx.accounts.passenger.subscription_end = current_time + duration_seconds;
-    ctx.accounts.passenger.subscription_rides_used = 0;
+    ctx.accounts.passenger.start_subscription(&ctx.accounts.fare_config, subscription_type, duration_seconds, seat_count, current_time);
+    ctx.accounts.passenger.subscription_price_paid = price;
+    ctx.accounts.passenger.subscription_currency_mint = ctx.accounts.mint.key();
+    ctx.accounts.passenger.subscription_sponsor_escrow = sponsor_escrow;
     ctx.accounts.passenger.total_spent += price;
+    ctx.accounts.passenger.pending_pass_discount = 0;
+
+    // Accrue loyalty points at the subscription rate
+    let points = ctx.accounts.fare_config.loyalty_points(
+        base_price,
+        ctx.accounts.fare_config.loyalty_subscription_rate,
+        current_time,
+    );
+    ctx.accounts.passenger.loyalty_points = ctx.accounts.passenger.loyalty_points.saturating_add(points);
+    ctx.accounts.passenger.subscription_points_awarded = points;
+
+    // Write the payment receipt
+    let passenger_key = ctx.accounts.passenger.key();
+    let mint_key = ctx.accounts.mint.key();
+    ctx.accounts.passenger.record_payment(&mut ctx.accounts.fare_config, &mut ctx.accounts.payment, crate::PAYMENT_PRODUCT_SUBSCRIPTION, passenger_key, price, mint_key, ctx.bumps.payment)?;
+    ctx.accounts.payment.rent_payer = ctx.accounts.fee_payer.key();
+
+    let fare_config_version = ctx.accounts.fare_config.config_version;
+    emit!(PaymentRecorded::new(ctx.accounts.payment.key(), &ctx.accounts.payment, fare_config_version));
+    emit!(SubscriptionPurchased {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.passenger.user,
+        subscription_type,
+        seat_count,
+        price,
+        currency_mint: mint_key,
+        payment_id: ctx.accounts.payment.payment_id,
+        sponsor_escrow,
+        subscription_start: ctx.accounts.passenger.subscription_start,
+        subscription_end: ctx.accounts.passenger.subscription_end,
+        fare_config_version,
+        timestamp: current_time,
+    });
 
     // Update fare config
-    ctx.accounts.fare_config.total_active_subscriptions += 1;
+    if !replaces_lapsed_subscription {
+        ctx.accounts.fare_config.total_active_subscriptions += 1;
+    }
 
     Ok(())
 }
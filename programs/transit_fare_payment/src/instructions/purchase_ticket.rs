@@ -1,23 +1,19 @@
 use crate::*;
-use anchor_lang::prelude::*;
-use std::str::FromStr;
 
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
-};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
 #[instruction(
     transport_mode: u8,
     ticket_id: u64,
-    amount: u64,
+    max_price: u64,
 )]
 pub struct PurchaseTicket<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             b"fare_config",
         ],
@@ -27,7 +23,7 @@ pub struct PurchaseTicket<'info> {
 
     #[account(
         init_if_needed,
-        space=61,
+        space = 8 + Passenger::INIT_SPACE,
         payer=fee_payer,
         seeds = [
             b"passenger",
@@ -39,7 +35,7 @@ pub struct PurchaseTicket<'info> {
 
     #[account(
         init,
-        space=67,
+        space = 8 + Ticket::INIT_SPACE,
         payer=fee_payer,
         seeds = [
             b"ticket",
@@ -50,6 +46,19 @@ pub struct PurchaseTicket<'info> {
     )]
     pub ticket: Account<'info, Ticket>,
 
+    #[account(
+        init,
+        space = 8 + Payment::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"payment",
+            user.key().as_ref(),
+            passenger.next_payment_id(&fare_config).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Account<'info, Payment>,
+
     pub user: Signer<'info>,
 
     #[account(
@@ -85,6 +94,58 @@ pub struct PurchaseTicket<'info> {
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+
+    pub employer: Option<Account<'info, Employer>>,
+
+    #[account(
+        mut,
+    )]
+    pub employee_benefit: Option<Account<'info, EmployeeBenefit>>,
+
+    #[account(
+        mut,
+    )]
+    pub employer_escrow: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+    )]
+    pub promotion: Option<Account<'info, Promotion>>,
+
+    #[account(
+        init_if_needed,
+        space = 8 + PromotionRedemption::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"promotion_redemption",
+            promotion.as_ref().map(|p| p.key()).unwrap_or_default().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub promotion_redemption: Option<Account<'info, PromotionRedemption>>,
+
+    #[account(
+        seeds = [
+            b"accepted_currency",
+            mint.key().as_ref(),
+        ],
+        bump = accepted_currency.bump,
+    )]
+    pub accepted_currency: Option<Account<'info, AcceptedCurrency>>,
+
+    /// CHECK: compared with fare_config.price_feed and parsed as a PriceFeed
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ride_history",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ride_history: Option<AccountLoader<'info, RideHistory>>,
 }
 
 impl<'info> PurchaseTicket<'info> {
@@ -102,75 +163,227 @@ impl<'info> PurchaseTicket<'info> {
             decimals, 
         )
     }
+
+    pub fn cpi_sponsored_transfer_checked(&self, amount: u64, decimals: u8) -> Result<()> {
+        let employer = self.employer.as_ref().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+        let escrow = self.employer_escrow.as_ref().ok_or(FarePaymentError::InvalidEscrow)?;
+        let signer_seeds: &[&[&[u8]]] = &[&[b"employer", employer.admin.as_ref(), &[employer.bump]]];
+
+        anchor_spl::token::transfer_checked(
+            CpiContext::new_with_signer(self.token_program.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: escrow.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to: self.destination.to_account_info(),
+                    authority: employer.to_account_info()
+                },
+                signer_seeds,
+            ),
+            amount,
+            decimals,
+        )
+    }
+
+    /// Check that the employee may draw `amount` from the employer escrow and record it
+    pub fn charge_employer(&mut self, amount: u64, current_time: i64) -> Result<Pubkey> {
+        let employer = self.employer.as_ref().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+        let escrow = self.employer_escrow.as_ref().ok_or(FarePaymentError::InvalidEscrow)?;
+        let benefit = self.employee_benefit.as_mut().ok_or(FarePaymentError::EmployeeNotEnrolled)?;
+
+        employer.verify_benefit(employer.key(), benefit, escrow.key(), self.user.key())?;
+
+        benefit.charge(amount, current_time)?;
+
+        Ok(escrow.key())
+    }
+
+    /// Redeem the promo code against the promotion, returning the discounted price
+    pub fn apply_promotion(&mut self, code: Option<String>, product: u8, modes: u8, price: u64, current_time: i64, redemption_bump: u8) -> Result<u64> {
+        let code = code.ok_or(FarePaymentError::InvalidPromoCode)?;
+        let promotion = self.promotion.as_mut().ok_or(FarePaymentError::InvalidPromoCode)?;
+        let redemption = self.promotion_redemption.as_mut().ok_or(FarePaymentError::InvalidPromoCode)?;
+
+        // First redemption by this rider
+        if redemption.count == 0 {
+            redemption.version = PromotionRedemption::VERSION;
+            redemption.promotion = promotion.key();
+            redemption.user = self.user.key();
+            redemption.bump = redemption_bump;
+        }
+
+        promotion.redeem(redemption, code.as_bytes(), product, modes, price, current_time)
+    }
+
+    /// Convert a price in fare units into the mint the rider pays with
+    pub fn price_in_payment_mint(&self, price: u64, current_time: i64) -> Result<u64> {
+        // Fiat fares are first converted into the base currency at the oracle price
+        if self.fare_config.current_price_feed(current_time).price_feed != Pubkey::default() {
+            require_keys_eq!(self.currency_mint.key(), self.fare_config.currency_mint, FarePaymentError::CurrencyMismatch);
+        }
+        let price_feed = self.price_feed.as_ref().map(|feed| feed.to_account_info());
+        let price = self.fare_config.price_in_tokens(price, price_feed.as_ref(), self.currency_mint.decimals, current_time)?;
+
+        // Every payment, sponsored or not, goes to a treasury-owned account in the paid mint
+        let destination = TokenAccount::try_deserialize(&mut &self.destination.try_borrow_data()?[..])?;
+        require_keys_eq!(destination.owner, self.fare_config.treasury, FarePaymentError::InvalidTreasury);
+        require_keys_eq!(destination.mint, self.mint.key(), FarePaymentError::CurrencyMismatch);
+
+        match &self.accepted_currency {
+            Some(currency) => {
+                require!(currency.enabled, FarePaymentError::CurrencyDisabled);
+                require_keys_eq!(self.destination.key(), currency.treasury, FarePaymentError::InvalidTreasury);
+                currency.convert(price, current_time)
+            }
+            None => {
+                require_keys_eq!(self.mint.key(), self.fare_config.currency_mint, FarePaymentError::CurrencyMismatch);
+                Ok(price)
+            }
+        }
+    }
 }
 
 /// Purchase a transit ticket for any transport mode
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
-/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
 /// 2. `[writable]` passenger: [Passenger]
 /// 3. `[writable]` ticket: [Ticket]
-/// 4. `[signer]` user: [AccountInfo] User's wallet address
-/// 5. `[writable]` user_token_account: [AccountInfo] User's token account for payment
-/// 6. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
-/// 7. `[]` currency_mint: [Mint] Currency mint address
-/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-/// 9. `[writable]` source: [AccountInfo] The source account.
-/// 10. `[]` mint: [Mint] The token mint.
-/// 11. `[writable]` destination: [AccountInfo] The destination account.
-/// 12. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-/// 13. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 4. `[writable]` payment: [Payment] Receipt for this purchase
+/// 5. `[signer]` user: [AccountInfo] User's wallet address
+/// 6. `[writable]` user_token_account: [AccountInfo] User's token account for payment
+/// 7. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
+/// 8. `[]` currency_mint: [Mint] Currency mint address
+/// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 10. `[writable]` source: [AccountInfo] The source account.
+/// 11. `[]` mint: [Mint] The token mint.
+/// 12. `[writable]` destination: [AccountInfo] The destination account.
+/// 13. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 14. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 15. `[optional]` employer: [Employer] Employer sponsoring the purchase
+/// 16. `[writable, optional]` employee_benefit: [EmployeeBenefit] User's enrollment with the employer
+/// 17. `[writable, optional]` employer_escrow: [TokenAccount] Employer escrow paying the fare
+/// 18. `[writable, optional]` promotion: [Promotion] Promotion to redeem
+/// 19. `[writable, optional]` promotion_redemption: [PromotionRedemption] User's redemptions of the promotion
+/// 20. `[optional]` accepted_currency: [AcceptedCurrency] Registry entry when paying in another mint
+/// 21. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
+/// 22. `[writable, optional]` ride_history: [RideHistory] User's ride history to append to
 ///
 /// Data:
 /// - transport_mode: [u8] Transport mode (0 = bus, 1 = train)
 /// - ticket_id: [u64] Unique ticket identifier
-/// - amount: [u64] Amount to pay for the ticket
+/// - max_price: [u64] Most the rider agrees to pay for the ticket, in the payment mint
+/// - promo_code: [Option<String>] Promo code for the promotion (optional)
 pub fn handler(
     ctx: Context<PurchaseTicket>,
     transport_mode: u8,
     ticket_id: u64,
-    amount: u64,
+    max_price: u64,
+    promo_code: Option<String>,
 ) -> Result<()> {
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_TICKET_SALES)?;
+
     // Validate transport mode (0 = bus, 1 = train)
-    require!(transport_mode == crate::TRANSPORT_MODE_BUS || transport_mode == crate::TRANSPORT_MODE_TRAIN, FarePaymentError::InvalidTransportMode);
+    transit_core::validate_transport_mode(transport_mode).map_err(FarePaymentError::from)?;
 
     // Get the appropriate fare based on transport mode
-    let fare = if transport_mode == crate::TRANSPORT_MODE_BUS {
-        ctx.accounts.fare_config.bus_fare
+    let current_time = Clock::get()?.unix_timestamp;
+    let fare = ctx.accounts.fare_config.current_fares(current_time).fare(transport_mode)?;
+
+    // Apply the promotion, if any
+    let fare = if ctx.accounts.promotion.is_some() {
+        ctx.accounts.apply_promotion(promo_code, crate::PROMO_PRODUCT_TICKET, 1 << transport_mode, fare, current_time, ctx.bumps.promotion_redemption)?
     } else {
-        ctx.accounts.fare_config.train_fare
+        fare
     };
 
-    require!(amount == fare, FarePaymentError::InvalidAmount);
+    // Convert the fare into the mint the rider pays with
+    let amount = ctx.accounts.price_in_payment_mint(fare, current_time)?;
+    require!(amount <= max_price, FarePaymentError::PriceAboveMaximum);
     
-    // Transfer tokens from user to system
-    ctx.accounts.cpi_token_transfer_checked(amount, 9)?;
+    // Transfer tokens to system, from the employer escrow for sponsored employees
+    let sponsor_escrow = if ctx.accounts.employee_benefit.is_some() {
+        let escrow = ctx.accounts.charge_employer(amount, current_time)?;
+        ctx.accounts.cpi_sponsored_transfer_checked(amount, ctx.accounts.mint.decimals)?;
+        escrow
+    } else {
+        ctx.accounts.cpi_token_transfer_checked(amount, ctx.accounts.mint.decimals)?;
+        Pubkey::default()
+    };
     
     // Update fare config
     ctx.accounts.fare_config.total_tickets_sold += 1;
     
     // Initialize passenger if needed
-    if ctx.accounts.passenger.ticket_count == 0 {
+    if ctx.accounts.passenger.version == 0 {
+        ctx.accounts.passenger.version = Passenger::VERSION;
         ctx.accounts.passenger.user = ctx.accounts.user.key();
-        ctx.accounts.passenger.total_spent = 0;
-        ctx.accounts.passenger.ticket_count = 0;
-        ctx.accounts.passenger.last_ticket_timestamp = 0;
+        ctx.accounts.passenger.bump = ctx.bumps.passenger;
+        ctx.accounts.passenger.payment_count = ctx.accounts.fare_config.total_receipts;
     }
     
     // Update passenger stats
     ctx.accounts.passenger.total_spent += amount;
     ctx.accounts.passenger.ticket_count += 1;
+    ctx.accounts.passenger.outstanding_tickets += 1;
     ctx.accounts.passenger.last_ticket_timestamp = Clock::get()?.unix_timestamp;
+
+    // Accrue loyalty points at the mode's rate
+    let points = ctx.accounts.fare_config.loyalty_points(
+        fare,
+        ctx.accounts.fare_config.loyalty_rates[transport_mode as usize],
+        current_time,
+    );
+    ctx.accounts.passenger.loyalty_points = ctx.accounts.passenger.loyalty_points.saturating_add(points);
     
     // Initialize ticket
+    ctx.accounts.ticket.version = Ticket::VERSION;
     ctx.accounts.ticket.user = ctx.accounts.user.key();
     ctx.accounts.ticket.ticket_id = ticket_id;
     ctx.accounts.ticket.transport_mode = transport_mode;
     ctx.accounts.ticket.fare_amount = amount;
+    ctx.accounts.ticket.currency_mint = ctx.accounts.mint.key();
     ctx.accounts.ticket.purchase_timestamp = Clock::get()?.unix_timestamp;
     ctx.accounts.ticket.status = 0; // Unused
+    ctx.accounts.ticket.sponsor_escrow = sponsor_escrow;
+    ctx.accounts.ticket.loyalty_points_awarded = points;
+    ctx.accounts.ticket.expires_at = ctx.accounts.fare_config.ticket_expiry(current_time);
+    ctx.accounts.ticket.rent_payer = ctx.accounts.fee_payer.key();
     ctx.accounts.ticket.bump = ctx.bumps.ticket;
+
+    // Append to the ride history
+    if let Some(ride_history) = &ctx.accounts.ride_history {
+        ride_history.load_mut()?.push(HistoryEntry {
+            timestamp: current_time,
+            amount,
+            transport_mode,
+            product: crate::HISTORY_TICKET_PURCHASE,
+            ..Default::default()
+        });
+    }
+
+    // Write the payment receipt
+    let ticket_key = ctx.accounts.ticket.key();
+    let mint_key = ctx.accounts.mint.key();
+    ctx.accounts.passenger.record_payment(&mut ctx.accounts.fare_config, &mut ctx.accounts.payment, crate::PAYMENT_PRODUCT_TICKET, ticket_key, amount, mint_key, ctx.bumps.payment)?;
+    ctx.accounts.payment.rent_payer = ctx.accounts.fee_payer.key();
+
+    let fare_config_version = ctx.accounts.fare_config.config_version;
+    emit!(PaymentRecorded::new(ctx.accounts.payment.key(), &ctx.accounts.payment, fare_config_version));
+    emit!(TicketPurchased {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.ticket.user,
+        ticket: ticket_key,
+        ticket_id,
+        transport_mode,
+        amount,
+        currency_mint: mint_key,
+        payment_id: ctx.accounts.payment.payment_id,
+        sponsor_escrow,
+        expires_at: ctx.accounts.ticket.expires_at,
+        fare_config_version,
+        timestamp: current_time,
+    });
     
     Ok(())
 }
\ No newline at end of file
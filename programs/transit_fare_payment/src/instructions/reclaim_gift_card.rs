@@ -0,0 +1,110 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ReclaimGiftCard<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        close = issuer,
+        seeds = [
+            b"gift_card",
+            gift_card.claim_key.as_ref(),
+        ],
+        bump = gift_card.bump,
+    )]
+    pub gift_card: Account<'info, GiftCard>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gift_card_escrow",
+            gift_card.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = gift_card.issuer @ FarePaymentError::Unauthorized,
+    )]
+    pub issuer: Signer<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: credited by transfer_checked, which checks that it holds mint
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        address = gift_card.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Return the value of an expired, unredeemed gift card to its issuer
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` gift_card: [GiftCard]
+/// 3. `[writable]` escrow: [TokenAccount] Token account holding the card value
+/// 4. `[writable, signer]` issuer: [AccountInfo] Issuer of the card
+/// 5. `[writable]` destination: [AccountInfo] The destination account.
+/// 6. `[]` mint: [Mint] The token mint.
+/// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<ReclaimGiftCard>,
+) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.gift_card.expires_at,
+        FarePaymentError::GiftCardNotExpired
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[ctx.accounts.fare_config.bump]]];
+
+    // Transfer the unredeemed value back to the issuer
+    anchor_spl::token::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.fare_config.to_account_info()
+            },
+            signer_seeds,
+        ),
+        ctx.accounts.escrow.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    // Close the empty escrow, returning its rent to the issuer
+    anchor_spl::token::close_account(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.issuer.to_account_info(),
+                authority: ctx.accounts.fare_config.to_account_info()
+            },
+            signer_seeds,
+        ),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,263 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct RedeemGiftCard<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        close = issuer,
+        seeds = [
+            b"gift_card",
+            gift_card.claim_key.as_ref(),
+        ],
+        bump = gift_card.bump,
+    )]
+    pub gift_card: Account<'info, GiftCard>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"gift_card_escrow",
+            gift_card.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = gift_card.issuer,
+    )]
+    /// CHECK: receives the rent of the closed gift card accounts
+    pub issuer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        seeds = [
+            b"stored_value",
+            user.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = fare_config,
+    )]
+    pub stored_value: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        address = gift_card.claim_key @ FarePaymentError::InvalidGiftCardCode,
+    )]
+    pub claim: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = destination.owner == fare_config.treasury @ FarePaymentError::InvalidTreasury,
+    )]
+    pub destination: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        address = gift_card.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: compared with fare_config.price_feed and parsed as a PriceFeed
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        space = 8 + Payment::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"payment",
+            user.key().as_ref(),
+            passenger.payment_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub payment: Option<Account<'info, Payment>>,
+}
+
+impl<'info> RedeemGiftCard<'info> {
+    pub fn cpi_escrow_transfer_checked(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[self.fare_config.bump]]];
+
+        anchor_spl::token::transfer_checked(
+            CpiContext::new_with_signer(self.token_program.to_account_info(),
+                anchor_spl::token::TransferChecked {
+                    from: self.escrow.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                    to,
+                    authority: self.fare_config.to_account_info()
+                },
+                signer_seeds,
+            ),
+            amount,
+            self.mint.decimals,
+        )
+    }
+}
+
+/// Redeem a gift card for stored value or a pass
+///
+/// When a subscription type is given the card buys that pass and any
+/// leftover value is credited to the stored-value balance. The claim key
+/// signs the same transaction as the rider, so a claim seen in flight
+/// can't be replayed for another wallet.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` gift_card: [GiftCard]
+/// 3. `[writable]` escrow: [TokenAccount] Token account holding the card value
+/// 4. `[writable]` issuer: [AccountInfo] Issuer of the card, receives the closed accounts' rent
+/// 5. `[writable]` passenger: [Passenger]
+/// 6. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+/// 7. `[signer]` user: [AccountInfo] User's wallet address
+/// 8. `[signer]` claim: [AccountInfo] Keypair derived from the secret claim code
+/// 9. `[writable, optional]` destination: [TokenAccount] System treasury, required to buy a pass
+/// 10. `[]` mint: [Mint] The token mint.
+/// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 13. `[optional]` price_feed: [AccountInfo] Oracle price account, required to buy a pass while fares are in fiat
+/// 14. `[writable, optional]` payment: [Payment] Receipt for the pass, required to buy a pass
+///
+/// Data:
+/// - subscription_type: [u8] Pass to buy (0 = credit the balance only, 1 = monthly, 2 = yearly)
+/// - max_price: [u64] Most the rider agrees to pay for the pass, ignored without a pass
+pub fn handler(
+    ctx: Context<RedeemGiftCard>,
+    subscription_type: u8,
+    max_price: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time < ctx.accounts.gift_card.expires_at, FarePaymentError::GiftCardExpired);
+
+    let value = ctx.accounts.escrow.amount;
+
+    // Buy the pass first, if one was requested
+    let price = if subscription_type != 0 {
+        ctx.accounts.fare_config.require_not_paused(crate::PAUSE_SUBSCRIPTION_PURCHASE)?;
+        transit_core::validate_subscription_type(subscription_type).map_err(FarePaymentError::from)?;
+
+        if ctx.accounts.passenger.subscription_type > 0 && ctx.accounts.passenger.subscription_end > current_time {
+            return Err(FarePaymentError::SubscriptionAlreadyActive.into());
+        }
+
+        // A lapsed pass that was never expired is still counted as active
+        let replaces_lapsed_subscription = ctx.accounts.passenger.subscription_type > 0;
+
+        let (price, duration_seconds) = ctx.accounts.fare_config.current_fares(current_time).pass(subscription_type, 1)?;
+
+        // Fiat pass prices are converted at the oracle price
+        let price_feed = ctx.accounts.price_feed.as_ref().map(|feed| feed.to_account_info());
+        let price = ctx.accounts.fare_config.price_in_tokens(price, price_feed.as_ref(), ctx.accounts.mint.decimals, current_time)?;
+        require!(price <= max_price, FarePaymentError::PriceAboveMaximum);
+        require!(value >= price, FarePaymentError::InsufficientFunds);
+
+        let destination = ctx.accounts.destination.as_ref().ok_or(FarePaymentError::InvalidTreasury)?;
+        ctx.accounts.cpi_escrow_transfer_checked(destination.to_account_info(), price)?;
+
+        ctx.accounts.passenger.start_subscription(&ctx.accounts.fare_config, subscription_type, duration_seconds, 1, current_time);
+        ctx.accounts.passenger.subscription_price_paid = price;
+        ctx.accounts.passenger.subscription_currency_mint = ctx.accounts.mint.key();
+        ctx.accounts.passenger.total_spent += price;
+
+        if !replaces_lapsed_subscription {
+            ctx.accounts.fare_config.total_active_subscriptions += 1;
+        }
+
+        // Write the payment receipt
+        let passenger_key = ctx.accounts.passenger.key();
+        let mint_key = ctx.accounts.mint.key();
+        let payment = ctx.accounts.payment.as_mut().ok_or(FarePaymentError::MissingPaymentReceipt)?;
+        ctx.accounts.passenger.record_payment(&mut ctx.accounts.fare_config, payment, crate::PAYMENT_PRODUCT_SUBSCRIPTION, passenger_key, price, mint_key, ctx.bumps.payment)?;
+        payment.rent_payer = ctx.accounts.fee_payer.key();
+        emit!(PaymentRecorded::new(payment.key(), payment, ctx.accounts.fare_config.config_version));
+
+        emit!(SubscriptionPurchased {
+            version: crate::EVENT_SCHEMA_VERSION,
+            user: ctx.accounts.passenger.user,
+            subscription_type,
+            seat_count: 1,
+            price,
+            currency_mint: mint_key,
+            payment_id: payment.payment_id,
+            sponsor_escrow: Pubkey::default(),
+            subscription_start: ctx.accounts.passenger.subscription_start,
+            subscription_end: ctx.accounts.passenger.subscription_end,
+            fare_config_version: ctx.accounts.fare_config.config_version,
+            timestamp: current_time,
+        });
+
+        price
+    } else {
+        0
+    };
+
+    // Credit whatever is left to the stored-value balance
+    let remaining = value - price;
+    if remaining > 0 {
+        ctx.accounts.cpi_escrow_transfer_checked(ctx.accounts.stored_value.to_account_info(), remaining)?;
+        ctx.accounts.passenger.stored_value_balance = ctx
+            .accounts
+            .passenger
+            .stored_value_balance
+            .checked_add(remaining)
+            .ok_or(FarePaymentError::InvalidAmount)?;
+
+        emit!(StoredValueCredited {
+            version: crate::EVENT_SCHEMA_VERSION,
+            user: ctx.accounts.passenger.user,
+            amount: remaining,
+            balance: ctx.accounts.passenger.stored_value_balance,
+            currency_mint: ctx.accounts.mint.key(),
+            gift_card: ctx.accounts.gift_card.key(),
+            fare_config_version: ctx.accounts.fare_config.config_version,
+            timestamp: current_time,
+        });
+    }
+
+    // Close the empty escrow, returning its rent to the issuer
+    let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[ctx.accounts.fare_config.bump]]];
+    anchor_spl::token::close_account(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::CloseAccount {
+                account: ctx.accounts.escrow.to_account_info(),
+                destination: ctx.accounts.issuer.to_account_info(),
+                authority: ctx.accounts.fare_config.to_account_info()
+            },
+            signer_seeds,
+        ),
+    )?;
+
+    Ok(())
+}
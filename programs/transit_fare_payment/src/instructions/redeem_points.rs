@@ -0,0 +1,137 @@
+use crate::*;
+
+#[derive(Accounts)]
+#[instruction(
+    reward: u8,
+    points: u64,
+    transport_mode: u8,
+    ticket_id: u64,
+)]
+pub struct RedeemPoints<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        init,
+        space = 8 + Ticket::INIT_SPACE,
+        payer=fee_payer,
+        seeds = [
+            b"ticket",
+            user.key().as_ref(),
+            ticket_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub ticket: Option<Account<'info, Ticket>>,
+
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Redeem loyalty points for a free ticket or a discount on the next pass
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger]
+/// 3. `[writable, optional]` ticket: [Ticket] Free ticket, required for the free ticket reward
+/// 4. `[signer]` user: [AccountInfo] User's wallet address
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - reward: [u8] Reward to redeem (0 = free ticket, 1 = pass discount)
+/// - points: [u64] Points to spend on a pass discount, one point per fare unit off the pass price
+/// - transport_mode: [u8] Transport mode of the free ticket (0 = bus, 1 = train)
+/// - ticket_id: [u64] Unique identifier of the free ticket
+pub fn handler(
+    ctx: Context<RedeemPoints>,
+    reward: u8,
+    points: u64,
+    transport_mode: u8,
+    ticket_id: u64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    match reward {
+        crate::LOYALTY_REWARD_FREE_TICKET => {
+            ctx.accounts.fare_config.require_not_paused(crate::PAUSE_TICKET_SALES)?;
+
+            // Validate transport mode (0 = bus, 1 = train)
+            transit_core::validate_transport_mode(transport_mode).map_err(FarePaymentError::from)?;
+
+            let cost = ctx.accounts.fare_config.loyalty_free_ticket_cost;
+            require!(cost > 0, FarePaymentError::InvalidLoyaltyReward);
+            require!(ctx.accounts.passenger.loyalty_points >= cost, FarePaymentError::InsufficientLoyaltyPoints);
+
+            let ticket = ctx.accounts.ticket.as_mut().ok_or(FarePaymentError::InvalidLoyaltyReward)?;
+            ticket.version = Ticket::VERSION;
+            ticket.user = ctx.accounts.user.key();
+            ticket.ticket_id = ticket_id;
+            ticket.transport_mode = transport_mode;
+            ticket.fare_amount = 0;
+            ticket.currency_mint = ctx.accounts.fare_config.currency_mint;
+            ticket.purchase_timestamp = current_time;
+            ticket.status = 0; // Unused
+            ticket.sponsor_escrow = Pubkey::default();
+            ticket.loyalty_points_awarded = 0;
+            ticket.expires_at = ctx.accounts.fare_config.ticket_expiry(current_time);
+            ticket.rent_payer = ctx.accounts.fee_payer.key();
+            ticket.bump = ctx.bumps.ticket;
+
+            ctx.accounts.passenger.loyalty_points -= cost;
+            ctx.accounts.passenger.ticket_count += 1;
+            ctx.accounts.passenger.outstanding_tickets += 1;
+            ctx.accounts.passenger.last_ticket_timestamp = current_time;
+            ctx.accounts.fare_config.total_tickets_sold += 1;
+
+            emit!(TicketPurchased {
+                version: crate::EVENT_SCHEMA_VERSION,
+                user: ticket.user,
+                ticket: ticket.key(),
+                ticket_id,
+                transport_mode,
+                amount: 0,
+                currency_mint: ticket.currency_mint,
+                payment_id: crate::NO_PAYMENT_RECEIPT,
+                sponsor_escrow: Pubkey::default(),
+                expires_at: ticket.expires_at,
+                fare_config_version: ctx.accounts.fare_config.config_version,
+                timestamp: current_time,
+            });
+        }
+        crate::LOYALTY_REWARD_PASS_DISCOUNT => {
+            require!(points > 0, FarePaymentError::InvalidAmount);
+            require!(ctx.accounts.passenger.loyalty_points >= points, FarePaymentError::InsufficientLoyaltyPoints);
+
+            ctx.accounts.passenger.loyalty_points -= points;
+            ctx.accounts.passenger.pending_pass_discount = ctx
+                .accounts
+                .passenger
+                .pending_pass_discount
+                .saturating_add(points);
+        }
+        _ => return Err(FarePaymentError::InvalidLoyaltyReward.into()),
+    }
+
+    Ok(())
+}
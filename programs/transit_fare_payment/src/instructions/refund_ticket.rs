@@ -1,12 +1,14 @@
 use crate::*;
-use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
 
 #[derive(Accounts)]
+#[instruction(ticket_id: u64)]
 pub struct RefundTicket<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [
             b"fare_config",
         ],
@@ -68,13 +70,20 @@ pub struct RefundTicket<'info> {
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        address = ticket.rent_payer,
+    )]
+    /// CHECK: receives the rent of the closed ticket
+    pub rent_payer: UncheckedAccount<'info>,
 }
 
 /// Refund a transit ticket and return funds to user
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
-/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
 /// 2. `[writable]` passenger: [Passenger]
 /// 3. `[writable]` ticket: [Ticket]
 /// 4. `[signer]` user: [AccountInfo] User's wallet address
@@ -86,6 +95,7 @@ pub struct RefundTicket<'info> {
 /// 10. `[writable]` destination: [AccountInfo] The destination account.
 /// 11. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
 /// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+/// 13. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the ticket
 ///
 /// Data:
 /// - ticket_id: [u64] Ticket identifier
@@ -93,11 +103,21 @@ pub fn handler(
     ctx: Context<RefundTicket>,
     ticket_id: u64,
 ) -> Result<()> {
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_REFUNDS)?;
+
     // Verify that the ticket belongs to the user
     require!(ctx.accounts.ticket.user == ctx.accounts.user.key(), FarePaymentError::Unauthorized);
 
     // Verify that the ticket hasn't been used already
     require!(ctx.accounts.ticket.status == 0, FarePaymentError::TicketAlreadyUsed);
+
+    // Refunds are paid in the currency the ticket was bought with
+    require_keys_eq!(ctx.accounts.mint.key(), ctx.accounts.ticket.currency_mint, FarePaymentError::CurrencyMismatch);
+
+    // Employer-paid tickets are refunded to the employer escrow
+    if ctx.accounts.ticket.sponsor_escrow != Pubkey::default() {
+        require_keys_eq!(ctx.accounts.destination.key(), ctx.accounts.ticket.sponsor_escrow, FarePaymentError::RefundDestinationMismatch);
+    }
     
     // Transfer tokens from system to user
     let amount = ctx.accounts.ticket.fare_amount;
@@ -111,7 +131,7 @@ pub fn handler(
             }
         ),
         amount, 
-        9, 
+        ctx.accounts.mint.decimals,
     )?;
     
     // Update fare config
@@ -120,9 +140,29 @@ pub fn handler(
     // Update passenger stats
     ctx.accounts.passenger.total_spent = ctx.accounts.passenger.total_spent.saturating_sub(amount);
     ctx.accounts.passenger.ticket_count = ctx.accounts.passenger.ticket_count.saturating_sub(1);
+    ctx.accounts.passenger.outstanding_tickets = ctx.accounts.passenger.outstanding_tickets.saturating_sub(1);
+
+    // Claw back the loyalty points the ticket earned; once they've been
+    // redeemed the reward can't be returned, so neither can the fare
+    ctx.accounts.passenger.loyalty_points = ctx.accounts.passenger.loyalty_points
+        .checked_sub(ctx.accounts.ticket.loyalty_points_awarded)
+        .ok_or(FarePaymentError::LoyaltyPointsSpent)?;
     
-    // Delete ticket account
-    ctx.accounts.ticket.close(ctx.accounts.fee_payer.to_account_info())?;
+    emit!(TicketRefunded {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.ticket.user,
+        ticket: ctx.accounts.ticket.key(),
+        ticket_id,
+        transport_mode: ctx.accounts.ticket.transport_mode,
+        amount,
+        currency_mint: ctx.accounts.ticket.currency_mint,
+        destination: ctx.accounts.destination.key(),
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // Delete ticket account, returning its rent to whoever paid it
+    ctx.accounts.ticket.close(ctx.accounts.rent_payer.to_account_info())?;
     
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,40 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveHouseholdMember<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"household",
+            user.key().as_ref(),
+        ],
+        bump = household.bump,
+    )]
+    pub household: Account<'info, Household>,
+
+    pub user: Signer<'info>,
+}
+
+/// Remove a member wallet from a household pass
+///
+/// Rides the member has already taken this period still count if the
+/// wallet is added back before the pass renews.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` household: [Household]
+/// 2. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+///
+/// Data:
+/// - member: [Pubkey] Wallet to remove from the household
+pub fn handler(
+    ctx: Context<RemoveHouseholdMember>,
+    member: Pubkey,
+) -> Result<()> {
+    ctx.accounts.household.remove_member(&member)?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct RemoveRetailPartner<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        close = fee_payer,
+        seeds = [
+            b"retail_partner",
+            retail_partner.partner.as_ref(),
+        ],
+        bump = retail_partner.bump,
+    )]
+    pub retail_partner: Account<'info, RetailPartner>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.eligibility_issuer @ FarePaymentError::Unauthorized,
+    )]
+    pub eligibility_issuer: Signer<'info>,
+}
+
+/// Revoke a retail partner's permission to issue gift cards
+///
+/// Gift cards the partner already issued stay redeemable.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` retail_partner: [RetailPartner]
+/// 3. `[]` roles: [Roles] Role holders
+/// 4. `[signer]` eligibility_issuer: [AccountInfo] Eligibility issuer role holder
+///
+/// Data: None
+pub fn handler(
+    _ctx: Context<RemoveRetailPartner>,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct ScheduleFareChange<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
+}
+
+/// Queue new fares and pass prices to take effect at a later time
+///
+/// Unset values keep the fares in force when the change is queued. A new
+/// change replaces any change that is still pending.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+///
+/// Data:
+/// - mode_0_fare: [Option<u64>] New bus fare amount (transport mode 0 = bus, optional)
+/// - mode_1_fare: [Option<u64>] New train fare amount (transport mode 1 = train, optional)
+/// - monthly_pass_price: [Option<u64>] New monthly subscription price (optional)
+/// - yearly_pass_price: [Option<u64>] New yearly subscription price (optional)
+/// - effective_at: [i64] Timestamp the new fares take effect, at least min_fare_notice from now
+pub fn handler(
+    ctx: Context<ScheduleFareChange>,
+    mode_0_fare: Option<u64>,
+    mode_1_fare: Option<u64>,
+    monthly_pass_price: Option<u64>,
+    yearly_pass_price: Option<u64>,
+    effective_at: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.fare_config.schedule_fare_change(
+        mode_0_fare,
+        mode_1_fare,
+        monthly_pass_price,
+        yearly_pass_price,
+        effective_at,
+        current_time,
+    )?;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fare_manager.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetFareNoticePeriod<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// Set the minimum notice required before a fare change takes effect
+///
+/// The notice can't go below MIN_FARE_NOTICE_FLOOR. A longer notice applies
+/// immediately; a shorter one takes effect once the current notice has passed.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[signer]` admin: [AccountInfo] Administrator account
+///
+/// Data:
+/// - min_fare_notice: [i64] Seconds between scheduling a fare change and its effective time
+pub fn handler(
+    ctx: Context<SetFareNoticePeriod>,
+    min_fare_notice: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.fare_config.set_fare_notice(min_fare_notice, current_time)?;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// Set the guardian key allowed to pause the program
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[signer]` admin: [AccountInfo] Administrator account
+///
+/// Data:
+/// - guardian: [Pubkey] New guardian, default pubkey to remove it
+pub fn handler(
+    ctx: Context<SetGuardian>,
+    guardian: Pubkey,
+) -> Result<()> {
+    ctx.accounts.fare_config.guardian = guardian;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+
+    Ok(())
+}
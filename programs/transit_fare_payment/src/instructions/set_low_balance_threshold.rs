@@ -0,0 +1,37 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetLowBalanceThreshold<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    pub user: Signer<'info>,
+}
+
+/// Set the stored-value balance under which clients prompt a top-up
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` passenger: [Passenger]
+/// 2. `[signer]` user: [AccountInfo] User's wallet address
+///
+/// Data:
+/// - threshold: [u64] New low-balance threshold
+pub fn handler(
+    ctx: Context<SetLowBalanceThreshold>,
+    threshold: u64,
+) -> Result<()> {
+    ctx.accounts.passenger.low_balance_threshold = threshold;
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetPriceFeed<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
+}
+
+/// Denominate fares in fiat units priced by an oracle, or switch back to token fares
+///
+/// While a price feed is set, every fare and pass price in the fare config is
+/// read as fiat units and converted into `currency_mint` at purchase time.
+/// The change is queued and takes effect once the minimum fare notice has
+/// passed, replacing any change already queued.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+///
+/// Data:
+/// - price_feed: [Pubkey] Price account for the currency mint, default pubkey for token fares
+/// - max_price_age: [i64] Seconds after which a price is rejected as stale
+/// - max_confidence_bps: [u16] Widest accepted confidence interval, in basis points of the price
+pub fn handler(
+    ctx: Context<SetPriceFeed>,
+    price_feed: Pubkey,
+    max_price_age: i64,
+    max_confidence_bps: u16,
+) -> Result<()> {
+    // A zero age or confidence bound would reject almost every price
+    if price_feed != Pubkey::default() {
+        require!(max_price_age > 0 && max_confidence_bps > 0, FarePaymentError::InvalidPriceFeed);
+    } else {
+        require!(max_price_age >= 0, FarePaymentError::InvalidPriceFeed);
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    ctx.accounts.fare_config.schedule_price_feed_change(price_feed, max_price_age, max_confidence_bps, current_time);
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fare_manager.key())?;
+
+    Ok(())
+}
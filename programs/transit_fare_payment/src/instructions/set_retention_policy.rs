@@ -0,0 +1,58 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetRetentionPolicy<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.operator @ FarePaymentError::Unauthorized,
+    )]
+    pub operator: Signer<'info>,
+}
+
+/// Set how long new tickets stay valid and how long receipts are kept
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` operator: [AccountInfo] Operator role holder
+///
+/// Data:
+/// - ticket_validity_period: [i64] Seconds a ticket stays usable after purchase, 0 = never expires
+/// - payment_retention_period: [i64] Seconds before a receipt may be closed, 0 = kept forever
+pub fn handler(
+    ctx: Context<SetRetentionPolicy>,
+    ticket_validity_period: i64,
+    payment_retention_period: i64,
+) -> Result<()> {
+    require!(
+        ticket_validity_period >= 0 && payment_retention_period >= 0,
+        FarePaymentError::InvalidRetentionPeriod
+    );
+
+    ctx.accounts.fare_config.ticket_validity_period = ticket_validity_period;
+    ctx.accounts.fare_config.payment_retention_period = payment_retention_period;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.operator.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        init,
+        space = 8 + RoleChange::INIT_SPACE,
+        payer = fee_payer,
+        seeds = [
+            b"role_change",
+            roles.change_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub role_change: Account<'info, RoleChange>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Assign a privileged role to a new holder
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` roles: [Roles]
+/// 3. `[writable]` role_change: [RoleChange] Record of the change
+/// 4. `[signer]` admin: [AccountInfo] Administrator account
+/// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+///
+/// Data:
+/// - role: [u8] Role to assign (1 = fare manager, 2 = treasurer, 3 = operator, 4 = eligibility issuer, 5 = treasury owner)
+/// - holder: [Pubkey] New holder of the role
+pub fn handler(
+    ctx: Context<SetRole>,
+    role: u8,
+    holder: Pubkey,
+) -> Result<()> {
+    let previous = ctx.accounts.fare_config.assign_role(&mut ctx.accounts.roles, role, holder)?;
+
+    ctx.accounts.roles.record_change(
+        &mut ctx.accounts.role_change,
+        role,
+        previous,
+        holder,
+        ctx.accounts.admin.key(),
+        ctx.bumps.role_change,
+    )?;
+
+    if role == crate::ROLE_TREASURY {
+        ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct TopUpEmployer<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"employer",
+            employer.admin.as_ref(),
+        ],
+        bump = employer.bump,
+    )]
+    pub employer: Account<'info, Employer>,
+
+    #[account(
+        mut,
+        address = employer.escrow @ FarePaymentError::InvalidEscrow,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: debited by transfer_checked, which checks its mint and that authority can spend from it
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        address = employer.currency_mint,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Fund an employer's benefits escrow
+///
+/// Anyone may top up an escrow; the funds can only leave through employee
+/// purchases or an admin withdrawal.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` employer: [Employer]
+/// 2. `[writable]` escrow: [TokenAccount] Employer escrow token account
+/// 3. `[writable]` source: [AccountInfo] The source account.
+/// 4. `[]` mint: [Mint] The token mint.
+/// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 6. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data:
+/// - amount: [u64] Amount to deposit
+pub fn handler(
+    ctx: Context<TopUpEmployer>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, FarePaymentError::InvalidAmount);
+
+    anchor_spl::token::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info()
+            }
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct TopUpStoredValue<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_payer,
+        seeds = [
+            b"stored_value",
+            user.key().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = fare_config,
+    )]
+    pub stored_value: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: debited by transfer_checked, which checks its mint and that authority can spend from it
+    pub source: UncheckedAccount<'info>,
+
+    #[account(
+        address = fare_config.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Add funds to a passenger's stored-value balance
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger]
+/// 3. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+/// 4. `[signer]` user: [AccountInfo] User's wallet address
+/// 5. `[writable]` source: [AccountInfo] The source account.
+/// 6. `[]` mint: [Mint] The token mint.
+/// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+/// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+/// 9. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data:
+/// - amount: [u64] Amount to add to the balance
+pub fn handler(
+    ctx: Context<TopUpStoredValue>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, FarePaymentError::InvalidAmount);
+
+    // Transfer tokens from user to the stored-value escrow
+    anchor_spl::token::transfer_checked(
+        CpiContext::new(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.stored_value.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info()
+            }
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.passenger.stored_value_balance = ctx
+        .accounts
+        .passenger
+        .stored_value_balance
+        .checked_add(amount)
+        .ok_or(FarePaymentError::InvalidAmount)?;
+
+    emit!(StoredValueCredited {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.passenger.user,
+        amount,
+        balance: ctx.accounts.passenger.stored_value_balance,
+        currency_mint: ctx.accounts.mint.key(),
+        gift_card: Pubkey::default(),
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
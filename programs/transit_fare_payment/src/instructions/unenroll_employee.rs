@@ -0,0 +1,48 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UnenrollEmployee<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"employer",
+            admin.key().as_ref(),
+        ],
+        bump = employer.bump,
+    )]
+    pub employer: Account<'info, Employer>,
+
+    #[account(
+        mut,
+        close = fee_payer,
+        seeds = [
+            b"employee_benefit",
+            employer.key().as_ref(),
+            employee_benefit.employee.as_ref(),
+        ],
+        bump = employee_benefit.bump,
+    )]
+    pub employee_benefit: Account<'info, EmployeeBenefit>,
+
+    pub admin: Signer<'info>,
+}
+
+/// Remove an employee from the employer's transit benefits
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo] Receives the benefit account's rent
+/// 1. `[writable]` employer: [Employer]
+/// 2. `[writable]` employee_benefit: [EmployeeBenefit]
+/// 3. `[signer]` admin: [AccountInfo] Employer administrator
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<UnenrollEmployee>,
+) -> Result<()> {
+    ctx.accounts.employer.enrolled_employees = ctx.accounts.employer.enrolled_employees.saturating_sub(1);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        address = fare_config.admin @ FarePaymentError::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+}
+
+/// Resume one or more paused instruction families
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[signer]` admin: [AccountInfo] Administrator account
+///
+/// Data:
+/// - flags: [u8] Bitmask of families to resume, same bits as pause
+pub fn handler(
+    ctx: Context<Unpause>,
+    flags: u8,
+) -> Result<()> {
+    ctx.accounts.fare_config.unpause(flags)?;
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.admin.key())?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+use anchor_spl::token::TokenAccount;
+
+#[derive(Accounts)]
+pub struct UpdateAcceptedCurrency<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"accepted_currency",
+            accepted_currency.mint.as_ref(),
+        ],
+        bump = accepted_currency.bump,
+    )]
+    pub accepted_currency: Account<'info, AcceptedCurrency>,
+
+    #[account(
+        token::mint = accepted_currency.mint,
+        constraint = treasury.owner == fare_config.treasury @ FarePaymentError::InvalidTreasury,
+    )]
+    pub treasury: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.treasurer @ FarePaymentError::Unauthorized,
+    )]
+    pub treasurer: Signer<'info>,
+}
+
+/// Update the conversion rate, treasury or enabled flag of an accepted currency
+///
+/// A new rate is queued and takes effect once the minimum fare notice has
+/// passed; the treasury and enabled flag change at once.
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` accepted_currency: [AcceptedCurrency]
+/// 3. `[optional]` treasury: [TokenAccount] New treasury for the currency
+/// 4. `[]` roles: [Roles] Role holders
+/// 5. `[signer]` treasurer: [AccountInfo] Treasurer role holder
+///
+/// Data:
+/// - rate_numerator: [Option<u64>] New rate numerator (optional)
+/// - rate_denominator: [Option<u64>] New rate denominator (optional)
+/// - enabled: [Option<bool>] Whether riders may pay with this currency (optional)
+pub fn handler(
+    ctx: Context<UpdateAcceptedCurrency>,
+    rate_numerator: Option<u64>,
+    rate_denominator: Option<u64>,
+    enabled: Option<bool>,
+) -> Result<()> {
+    // A new rate reprices every fare in this currency, so riders get the
+    // same notice as for a fare change
+    if rate_numerator.is_some() || rate_denominator.is_some() {
+        let current_time = Clock::get()?.unix_timestamp;
+        let (numerator, denominator) = ctx.accounts.accepted_currency.rate(current_time);
+        let numerator = rate_numerator.unwrap_or(numerator);
+        let denominator = rate_denominator.unwrap_or(denominator);
+        require!(numerator > 0 && denominator > 0, FarePaymentError::InvalidExchangeRate);
+
+        let effective_at = current_time + ctx.accounts.fare_config.fare_notice(current_time);
+        ctx.accounts.accepted_currency.schedule_rate(numerator, denominator, effective_at, current_time);
+    }
+
+    if let Some(enabled) = enabled {
+        ctx.accounts.accepted_currency.enabled = enabled;
+    }
+
+    if let Some(treasury) = &ctx.accounts.treasury {
+        ctx.accounts.accepted_currency.treasury = treasury.key();
+    }
+
+    Ok(())
+}
@@ -1,5 +1,4 @@
 use crate::*;
-use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdateFareConfig<'info> {
@@ -15,43 +14,76 @@ pub struct UpdateFareConfig<'info> {
     )]
     pub fare_config: Account<'info, FareConfig>,
 
-    pub admin: Signer<'info>,
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
 }
 
 /// Update transit fare configuration settings
 ///
+/// Fares and pass prices are changed with schedule_fare_change instead.
+///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
 /// 1. `[writable]` fare_config: [FareConfig]
-/// 2. `[signer]` admin: [AccountInfo] Administrator account
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
 ///
 /// Data:
-/// - mode_0_fare: [Option<u64>] New bus fare amount (transport mode 0 = bus, optional)
-/// - mode_1_fare: [Option<u64>] New train fare amount (transport mode 1 = train, optional)
-/// - monthly_pass_price: [Option<u64>] New monthly subscription price (optional)
-/// - yearly_pass_price: [Option<u64>] New yearly subscription price (optional)
+/// - cancellation_fee: [Option<u64>] New subscription cancellation fee (optional)
+/// - non_refundable_period: [Option<i64>] New non-refundable period in seconds (optional)
+/// - subscription_allowed_modes: [Option<u8>] Bitmask of transport modes new passes cover (optional)
+/// - subscription_max_rides_per_day: [Option<u32>] Daily ride cap for new passes, 0 = unlimited (optional)
+/// - subscription_max_rides_per_period: [Option<u32>] Ride cap per pass period for new passes, 0 = unlimited (optional)
+/// - expiry_crank_reward: [Option<u64>] New reward in lamports for expiring a lapsed subscription (optional)
 pub fn handler(
     ctx: Context<UpdateFareConfig>,
-    mode_0_fare: Option<u64>,
-    mode_1_fare: Option<u64>,
-    monthly_pass_price: Option<u64>,
-    yearly_pass_price: Option<u64>,
+    cancellation_fee: Option<u64>,
+    non_refundable_period: Option<i64>,
+    subscription_allowed_modes: Option<u8>,
+    subscription_max_rides_per_day: Option<u32>,
+    subscription_max_rides_per_period: Option<u32>,
+    expiry_crank_reward: Option<u64>,
 ) -> Result<()> {
-    if let Some(fare) = mode_0_fare {
-        ctx.accounts.fare_config.bus_fare = fare;
+    if let Some(fee) = cancellation_fee {
+        ctx.accounts.fare_config.cancellation_fee = fee;
+    }
+
+    if let Some(period) = non_refundable_period {
+        require!(period >= 0, FarePaymentError::InvalidNonRefundablePeriod);
+        ctx.accounts.fare_config.non_refundable_period = period;
     }
 
-    if let Some(fare) = mode_1_fare {
-        ctx.accounts.fare_config.train_fare = fare;
+    if let Some(modes) = subscription_allowed_modes {
+        require!(
+            modes != 0 && modes & !crate::ALL_TRANSPORT_MODES == 0,
+            FarePaymentError::InvalidTransportMode
+        );
+        ctx.accounts.fare_config.subscription_allowed_modes = modes;
     }
 
-    if let Some(price) = monthly_pass_price {
-        ctx.accounts.fare_config.monthly_pass_price = price;
+    if let Some(max_rides) = subscription_max_rides_per_day {
+        ctx.accounts.fare_config.subscription_max_rides_per_day = max_rides;
     }
 
-    if let Some(price) = yearly_pass_price {
-        ctx.accounts.fare_config.yearly_pass_price = price;
+    if let Some(max_rides) = subscription_max_rides_per_period {
+        ctx.accounts.fare_config.subscription_max_rides_per_period = max_rides;
     }
 
+    if let Some(reward) = expiry_crank_reward {
+        ctx.accounts.fare_config.expiry_crank_reward = reward;
+    }
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fare_manager.key())?;
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,93 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct UpdateLoyaltyConfig<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        seeds = [
+            b"roles",
+        ],
+        bump = roles.bump,
+    )]
+    pub roles: Account<'info, Roles>,
+
+    #[account(
+        address = roles.fare_manager @ FarePaymentError::Unauthorized,
+    )]
+    pub fare_manager: Signer<'info>,
+}
+
+/// Update loyalty point rates, promotion bonus and redemption costs
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[writable]` fare_config: [FareConfig]
+/// 2. `[]` roles: [Roles] Role holders
+/// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+///
+/// Data:
+/// - mode_0_rate: [Option<u16>] Points per 10_000 units paid for bus tickets (optional)
+/// - mode_1_rate: [Option<u16>] Points per 10_000 units paid for train tickets (optional)
+/// - subscription_rate: [Option<u16>] Points per 10_000 units paid for passes (optional)
+/// - bonus_multiplier: [Option<u16>] Promotion multiplier in basis points, 10_000 = 1x (optional)
+/// - bonus_start: [Option<i64>] Promotion window start timestamp (optional)
+/// - bonus_end: [Option<i64>] Promotion window end timestamp (optional)
+/// - free_ticket_cost: [Option<u64>] Points needed for a free ticket, 0 disables it (optional)
+pub fn handler(
+    ctx: Context<UpdateLoyaltyConfig>,
+    mode_0_rate: Option<u16>,
+    mode_1_rate: Option<u16>,
+    subscription_rate: Option<u16>,
+    bonus_multiplier: Option<u16>,
+    bonus_start: Option<i64>,
+    bonus_end: Option<i64>,
+    free_ticket_cost: Option<u64>,
+) -> Result<()> {
+    if let Some(rate) = mode_0_rate {
+        ctx.accounts.fare_config.loyalty_rates[crate::TRANSPORT_MODE_BUS as usize] = rate;
+    }
+
+    if let Some(rate) = mode_1_rate {
+        ctx.accounts.fare_config.loyalty_rates[crate::TRANSPORT_MODE_TRAIN as usize] = rate;
+    }
+
+    if let Some(rate) = subscription_rate {
+        ctx.accounts.fare_config.loyalty_subscription_rate = rate;
+    }
+
+    if let Some(multiplier) = bonus_multiplier {
+        ctx.accounts.fare_config.loyalty_bonus_multiplier = multiplier;
+    }
+
+    if let Some(start) = bonus_start {
+        ctx.accounts.fare_config.loyalty_bonus_start = start;
+    }
+
+    if let Some(end) = bonus_end {
+        ctx.accounts.fare_config.loyalty_bonus_end = end;
+    }
+
+    require!(
+        ctx.accounts.fare_config.loyalty_bonus_end >= ctx.accounts.fare_config.loyalty_bonus_start,
+        FarePaymentError::InvalidPromotionWindow
+    );
+
+    if let Some(cost) = free_ticket_cost {
+        ctx.accounts.fare_config.loyalty_free_ticket_cost = cost;
+    }
+
+    ctx.accounts.fare_config.record_update(ctx.accounts.fare_manager.key())?;
+
+    Ok(())
+}
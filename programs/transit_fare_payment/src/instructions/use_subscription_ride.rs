@@ -1,35 +1,76 @@
 use crate::*;
-use anchor_lang::prelude::*;
+use transit_core::RideCounter;
 
 #[derive(Accounts)]
 pub struct UseSubscriptionRide<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
     #[account(
         mut,
         seeds = [
             b"passenger",
-            user.key().as_ref(),
+            passenger.user.as_ref(),
         ],
         bump = passenger.bump,
     )]
     pub passenger: Account<'info, Passenger>,
 
     pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"household",
+            passenger.user.as_ref(),
+        ],
+        bump = household.bump,
+    )]
+    pub household: Option<Account<'info, Household>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ride_history",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ride_history: Option<AccountLoader<'info, RideHistory>>,
 }
 
 /// Use a subscription ride for transit travel
 ///
+/// The rider is either the subscription holder or a member of the holder's
+/// household. Ride caps apply to each rider separately.
+///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo]
-/// 1. `[writable]` passenger: [Passenger]
-/// 2. `[signer]` user: [AccountInfo] User's wallet address
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger] Passenger holding the subscription
+/// 3. `[signer]` user: [AccountInfo] Rider's wallet address (holder or household member)
+/// 4. `[writable, optional]` household: [Household] Required when the rider is a household member
+/// 5. `[writable, optional]` ride_history: [RideHistory] Rider's ride history to append to
 ///
-/// Data: None
+/// Data:
+/// - transport_mode: [u8] Transport mode being boarded (0 = bus, 1 = train)
+/// - station_id: [u32] Boarding station identifier
 pub fn handler(
     ctx: Context<UseSubscriptionRide>,
+    transport_mode: u8,
+    station_id: u32,
 ) -> Result<()> {
+    // Validate transport mode (0 = bus, 1 = train)
+    transit_core::validate_transport_mode(transport_mode).map_err(FarePaymentError::from)?;
+
     // Verify that the passenger has an active subscription
     let current_time = Clock::get()?.unix_timestamp;
     require!(
@@ -43,8 +84,80 @@ pub fn handler(
         FarePaymentError::SubscriptionExpired
     );
 
-    // Increment the rides used counter
-    ctx.accounts.passenger.subscription_rides_used += 1;
+    let passenger = &mut *ctx.accounts.passenger;
+
+    // Verify that the pass covers this transport mode
+    require!(
+        transit_core::mode_allowed(passenger.subscription_allowed_modes, transport_mode),
+        FarePaymentError::TransportModeNotAllowed
+    );
+
+    let max_rides_per_day = passenger.subscription_max_rides_per_day;
+    let max_rides_per_period = passenger.subscription_max_rides_per_period;
+
+    let (rides_today, rides_this_period) = if ctx.accounts.user.key() == passenger.user {
+        // The holder's own rides are tracked on the passenger account
+        RideCounter {
+            rides_used: &mut passenger.subscription_rides_used,
+            ride_day: &mut passenger.subscription_ride_day,
+            rides_today: &mut passenger.subscription_rides_today,
+        }
+        .record(current_time, max_rides_per_day, max_rides_per_period)
+        .map_err(FarePaymentError::from)?
+    } else {
+        // Anyone else must be a member of the holder's household
+        let household = ctx
+            .accounts
+            .household
+            .as_mut()
+            .ok_or(FarePaymentError::NotHouseholdMember)?;
+
+        require!(
+            household.active_members() < passenger.subscription_seats as usize,
+            FarePaymentError::HouseholdSeatsExceeded
+        );
+
+        household.start_period(passenger.subscription_start);
+        let index = household
+            .member_index(&ctx.accounts.user.key())
+            .ok_or(FarePaymentError::NotHouseholdMember)?;
+
+        let member = &mut household.members[index];
+        RideCounter {
+            rides_used: &mut member.rides_used,
+            ride_day: &mut member.ride_day,
+            rides_today: &mut member.rides_today,
+        }
+        .record(current_time, max_rides_per_day, max_rides_per_period)
+        .map_err(FarePaymentError::from)?
+    };
+
+    // Per-mode counters cover every rider on the pass
+    passenger.subscription_rides_by_mode[transport_mode as usize] += 1;
+
+    // Append to the rider's ride history
+    if let Some(ride_history) = &ctx.accounts.ride_history {
+        ride_history.load_mut()?.push(HistoryEntry {
+            timestamp: current_time,
+            station_id,
+            transport_mode,
+            product: crate::HISTORY_SUBSCRIPTION_RIDE,
+            ..Default::default()
+        });
+    }
+
+    emit!(SubscriptionRideUsed {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: passenger.user,
+        rider: ctx.accounts.user.key(),
+        subscription_type: passenger.subscription_type,
+        transport_mode,
+        station_id,
+        rides_today,
+        rides_this_period,
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: current_time,
+    });
 
     Ok(())
 }
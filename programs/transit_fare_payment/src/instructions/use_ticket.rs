@@ -1,11 +1,19 @@
 use crate::*;
-use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
+#[instruction(ticket_id: u64)]
 pub struct UseTicket<'info> {
     #[account(mut)]
     pub fee_payer: Signer<'info>,
 
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
     #[account(
         mut,
         seeds = [
@@ -17,27 +25,83 @@ pub struct UseTicket<'info> {
     )]
     pub ticket: Account<'info, Ticket>,
 
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
     pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"ride_history",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub ride_history: Option<AccountLoader<'info, RideHistory>>,
 }
 
 /// Mark a ticket as used for travel
 ///
 /// Accounts:
 /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-/// 1. `[writable]` ticket: [Ticket] 
-/// 2. `[signer]` user: [AccountInfo] User's wallet address
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` ticket: [Ticket] 
+/// 3. `[writable]` passenger: [Passenger]
+/// 4. `[signer]` user: [AccountInfo] User's wallet address
+/// 5. `[writable, optional]` ride_history: [RideHistory] User's ride history to append to
 ///
 /// Data:
 /// - ticket_id: [u64] Ticket identifier
-pub fn handler(ctx: Context<UseTicket>, ticket_id: u64) -> Result<()> {
+/// - station_id: [u32] Boarding station identifier
+pub fn handler(ctx: Context<UseTicket>, ticket_id: u64, station_id: u32) -> Result<()> {
+    ctx.accounts.fare_config.require_not_paused(crate::PAUSE_TICKET_USE)?;
+
     // Verify that the ticket belongs to the user
-    require!(ctx.accounts.ticket.user == ctx.accounts.user.key(), BusTrainError::Unauthorized);
+    require!(ctx.accounts.ticket.user == ctx.accounts.user.key(), FarePaymentError::Unauthorized);
     
     // Verify that the ticket hasn't been used already
-    require!(ctx.accounts.ticket.status == 0, BusTrainError::TicketAlreadyUsed);
+    require!(ctx.accounts.ticket.status == 0, FarePaymentError::TicketAlreadyUsed);
+
+    // Verify that the ticket hasn't expired
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.ticket.expires_at == 0 || current_time < ctx.accounts.ticket.expires_at,
+        FarePaymentError::TicketExpired
+    );
     
     // Mark ticket as used
     ctx.accounts.ticket.status = 1; // Used
+    ctx.accounts.passenger.outstanding_tickets = ctx.accounts.passenger.outstanding_tickets.saturating_sub(1);
+
+    // Append to the ride history
+    if let Some(ride_history) = &ctx.accounts.ride_history {
+        ride_history.load_mut()?.push(HistoryEntry {
+            timestamp: current_time,
+            station_id,
+            transport_mode: ctx.accounts.ticket.transport_mode,
+            product: crate::HISTORY_TICKET_RIDE,
+            ..Default::default()
+        });
+    }
+
+    emit!(TicketUsed {
+        version: crate::EVENT_SCHEMA_VERSION,
+        user: ctx.accounts.ticket.user,
+        ticket: ctx.accounts.ticket.key(),
+        ticket_id,
+        transport_mode: ctx.accounts.ticket.transport_mode,
+        station_id,
+        fare_config_version: ctx.accounts.fare_config.config_version,
+        timestamp: current_time,
+    });
     
     Ok(())
 }
\ No newline at end of file
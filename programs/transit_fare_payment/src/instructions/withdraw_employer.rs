@@ -0,0 +1,79 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawEmployer<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"employer",
+            admin.key().as_ref(),
+        ],
+        bump = employer.bump,
+    )]
+    pub employer: Account<'info, Employer>,
+
+    #[account(
+        mut,
+        address = employer.escrow @ FarePaymentError::InvalidEscrow,
+    )]
+    pub escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: credited by transfer_checked, which checks that it holds mint
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        address = employer.currency_mint,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub admin: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw unused funds from an employer's benefits escrow
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` employer: [Employer]
+/// 2. `[writable]` escrow: [TokenAccount] Employer escrow token account
+/// 3. `[writable]` destination: [AccountInfo] The destination account.
+/// 4. `[]` mint: [Mint] The token mint.
+/// 5. `[signer]` admin: [AccountInfo] Employer administrator
+/// 6. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data:
+/// - amount: [u64] Amount to withdraw
+pub fn handler(
+    ctx: Context<WithdrawEmployer>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, FarePaymentError::InvalidAmount);
+    require!(amount <= ctx.accounts.escrow.amount, FarePaymentError::InsufficientFunds);
+
+    let admin = ctx.accounts.employer.admin;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"employer", admin.as_ref(), &[ctx.accounts.employer.bump]]];
+
+    anchor_spl::token::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.escrow.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.employer.to_account_info()
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    Ok(())
+}
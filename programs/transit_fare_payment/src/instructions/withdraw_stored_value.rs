@@ -0,0 +1,92 @@
+use crate::*;
+
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct WithdrawStoredValue<'info> {
+    #[account(mut)]
+    pub fee_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            b"fare_config",
+        ],
+        bump = fare_config.bump,
+    )]
+    pub fare_config: Account<'info, FareConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"passenger",
+            user.key().as_ref(),
+        ],
+        bump = passenger.bump,
+    )]
+    pub passenger: Account<'info, Passenger>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"stored_value",
+            user.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stored_value: Account<'info, TokenAccount>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+    )]
+    /// CHECK: credited by transfer_checked, which checks that it holds mint
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        address = fare_config.currency_mint @ FarePaymentError::CurrencyMismatch,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw the whole remaining stored-value balance back to the rider
+///
+/// Accounts:
+/// 0. `[writable, signer]` fee_payer: [AccountInfo]
+/// 1. `[]` fare_config: [FareConfig] Fare configuration account
+/// 2. `[writable]` passenger: [Passenger]
+/// 3. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+/// 4. `[signer]` user: [AccountInfo] User's wallet address
+/// 5. `[writable]` destination: [AccountInfo] The destination account.
+/// 6. `[]` mint: [Mint] The token mint.
+/// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+///
+/// Data: None
+pub fn handler(
+    ctx: Context<WithdrawStoredValue>,
+) -> Result<()> {
+    let amount = ctx.accounts.stored_value.amount;
+    require!(amount > 0, FarePaymentError::InsufficientFunds);
+
+    // Transfer tokens from the stored-value escrow back to the user
+    let signer_seeds: &[&[&[u8]]] = &[&[b"fare_config", &[ctx.accounts.fare_config.bump]]];
+    anchor_spl::token::transfer_checked(
+        CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::TransferChecked {
+                from: ctx.accounts.stored_value.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.fare_config.to_account_info()
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    ctx.accounts.passenger.stored_value_balance = 0;
+
+    Ok(())
+}
@@ -1,18 +1,76 @@
+// Instruction handlers take one parameter per instruction argument
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
-use std::str::FromStr;
 
 declare_id!("FQB354YeYLHky7omGhQXQxQ2QBcuYLq2QXiF4gdVogJt");
 
-// Transport mode constants
-pub const TRANSPORT_MODE_BUS: u8 = 0;
-pub const TRANSPORT_MODE_TRAIN: u8 = 1;
+// Fare rules shared with off-chain clients
+pub use transit_core::{
+    ALL_TRANSPORT_MODES, BASIS_POINTS, MAX_HOUSEHOLD_SEATS, PROMO_DISCOUNT_FIXED, PROMO_DISCOUNT_FREE_RIDE,
+    PROMO_DISCOUNT_PERCENTAGE, PriceFeed, SECONDS_PER_DAY, SECONDS_PER_MONTH, SECONDS_PER_YEAR, SUBSCRIPTION_MONTHLY,
+    SUBSCRIPTION_YEARLY, TRANSPORT_MODE_BUS, TRANSPORT_MODE_COUNT, TRANSPORT_MODE_TRAIN,
+};
+
+// Household passes cover the holder plus up to MAX_HOUSEHOLD_SEATS - 1 members
+pub const MAX_HOUSEHOLD_MEMBERS: u8 = MAX_HOUSEHOLD_SEATS - 1;
+
+// Rewards that loyalty points can be redeemed for
+pub const LOYALTY_REWARD_FREE_TICKET: u8 = 0;
+pub const LOYALTY_REWARD_PASS_DISCOUNT: u8 = 1;
+
+// Products a promotion can apply to, combined as a bitmask
+pub const PROMO_PRODUCT_TICKET: u8 = 1 << 0;
+pub const PROMO_PRODUCT_MONTHLY_PASS: u8 = 1 << 1;
+pub const PROMO_PRODUCT_YEARLY_PASS: u8 = 1 << 2;
+pub const ALL_PROMO_PRODUCTS: u8 = PROMO_PRODUCT_TICKET | PROMO_PRODUCT_MONTHLY_PASS | PROMO_PRODUCT_YEARLY_PASS;
+
+// What a payment receipt paid for
+pub const PAYMENT_PRODUCT_TICKET: u8 = 0;
+pub const PAYMENT_PRODUCT_SUBSCRIPTION: u8 = 1;
+pub const PAYMENT_PRODUCT_STORED_VALUE_FARE: u8 = 2;
+pub const PAYMENT_PRODUCT_RECORDED: u8 = 3; // Self-reported through the unversioned record_payment
+
+// Payment id carried by purchase events that wrote no receipt, such as free tickets
+pub const NO_PAYMENT_RECEIPT: u64 = u64::MAX;
+
+// Entries kept in each passenger's ride history ring buffer
+pub const RIDE_HISTORY_LEN: usize = 32;
+// Kinds of ride history entries
+pub const HISTORY_TICKET_PURCHASE: u8 = 0;
+pub const HISTORY_TICKET_RIDE: u8 = 1;
+pub const HISTORY_SUBSCRIPTION_RIDE: u8 = 2;
+
+// Privileged roles, ROLE_ADMIN and ROLE_TREASURY are held in FareConfig and the rest in Roles
+pub const ROLE_ADMIN: u8 = 0;
+pub const ROLE_FARE_MANAGER: u8 = 1;
+pub const ROLE_TREASURER: u8 = 2;
+pub const ROLE_OPERATOR: u8 = 3;
+pub const ROLE_ELIGIBILITY_ISSUER: u8 = 4;
+pub const ROLE_TREASURY: u8 = 5;
+
+// Instruction families that can be paused, combined as a bitmask
+pub const PAUSE_TICKET_SALES: u8 = 1 << 0;
+pub const PAUSE_TICKET_USE: u8 = 1 << 1;
+pub const PAUSE_REFUNDS: u8 = 1 << 2;
+pub const PAUSE_SUBSCRIPTION_PURCHASE: u8 = 1 << 3;
+pub const PAUSE_SUBSCRIPTION_CANCEL: u8 = 1 << 4;
+pub const ALL_PAUSE_FLAGS: u8 = PAUSE_TICKET_SALES | PAUSE_TICKET_USE | PAUSE_REFUNDS | PAUSE_SUBSCRIPTION_PURCHASE | PAUSE_SUBSCRIPTION_CANCEL;
+
+// Shortest notice the admin may require before a fare change, in seconds
+pub const MIN_FARE_NOTICE_FLOOR: i64 = 86_400;
+
+// Largest owner set a multisig can have, approvals are tracked in a u16
+pub const MAX_MULTISIG_OWNERS: usize = 10;
 
 // Module declarations
 mod error;
+mod events;
 mod instructions;
 mod state;
 
 pub use error::*;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -20,82 +78,108 @@ pub use state::*;
 pub mod transit_fare_payment {
     use super::*;
 
-    /// Initialize the fare configuration with default values
+    /// Initialize the transit fare configuration with default values
     ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
     /// 1. `[writable]` fare_config: [FareConfig]
-    /// 2. `[signer]` admin: [AccountInfo] Administrator account
-    /// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 2. `[writable]` roles: [Roles] Role holders, all initially the admin
+    /// 3. `[signer]` admin: [AccountInfo] Administrator account
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
     ///
     /// Data:
     /// - mode_0_fare: [u64] Default bus fare amount (transport mode 0 = bus)
     /// - mode_1_fare: [u64] Default train fare amount (transport mode 1 = train)
     /// - currency_mint: [Pubkey] Currency mint address
-    pub fn initialize_fare_config(ctx: Context<InitializeFareConfig>, mode_0_fare: u64, mode_1_fare: u64, currency_mint: Pubkey, monthly_pass_price: u64, yearly_pass_price: u64) -> Result<()> {
-        initialize_fare_config::handler(ctx, mode_0_fare, mode_1_fare, currency_mint, monthly_pass_price, yearly_pass_price)
+    /// - monthly_pass_price: [u64] Price for 30-day subscription
+    /// - yearly_pass_price: [u64] Price for 365-day subscription
+    /// - cancellation_fee: [u64] Flat fee withheld from subscription refunds
+    /// - non_refundable_period: [i64] Seconds of a subscription that are never refunded
+    pub fn initialize_fare_config(ctx: Context<InitializeFareConfig>, mode_0_fare: u64, mode_1_fare: u64, currency_mint: Pubkey, monthly_pass_price: u64, yearly_pass_price: u64, cancellation_fee: u64, non_refundable_period: i64) -> Result<()> {
+        initialize_fare_config::handler(ctx, mode_0_fare, mode_1_fare, currency_mint, monthly_pass_price, yearly_pass_price, cancellation_fee, non_refundable_period)
     }
 
     /// Update transit fare configuration settings
     ///
+    /// Fares and pass prices are changed with schedule_fare_change instead.
+    ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
     /// 1. `[writable]` fare_config: [FareConfig]
-    /// 2. `[signer]` admin: [AccountInfo] Administrator account
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
     ///
     /// Data:
-    /// - mode_0_fare: [Option<u64>] New bus fare amount (transport mode 0 = bus, optional)
-    /// - mode_1_fare: [Option<u64>] New train fare amount (transport mode 1 = train, optional)
-    pub fn update_fare_config(ctx: Context<UpdateFareConfig>, mode_0_fare: Option<u64>, mode_1_fare: Option<u64>, monthly_pass_price: Option<u64>, yearly_pass_price: Option<u64>) -> Result<()> {
-        update_fare_config::handler(ctx, mode_0_fare, mode_1_fare, monthly_pass_price, yearly_pass_price)
+    /// - cancellation_fee: [Option<u64>] New subscription cancellation fee (optional)
+    /// - non_refundable_period: [Option<i64>] New non-refundable period in seconds (optional)
+    /// - subscription_allowed_modes: [Option<u8>] Bitmask of transport modes new passes cover (optional)
+    /// - subscription_max_rides_per_day: [Option<u32>] Daily ride cap for new passes, 0 = unlimited (optional)
+    /// - subscription_max_rides_per_period: [Option<u32>] Ride cap per pass period for new passes, 0 = unlimited (optional)
+    /// - expiry_crank_reward: [Option<u64>] New reward in lamports for expiring a lapsed subscription (optional)
+    pub fn update_fare_config(ctx: Context<UpdateFareConfig>, cancellation_fee: Option<u64>, non_refundable_period: Option<i64>, subscription_allowed_modes: Option<u8>, subscription_max_rides_per_day: Option<u32>, subscription_max_rides_per_period: Option<u32>, expiry_crank_reward: Option<u64>) -> Result<()> {
+        update_fare_config::handler(ctx, cancellation_fee, non_refundable_period, subscription_allowed_modes, subscription_max_rides_per_day, subscription_max_rides_per_period, expiry_crank_reward)
     }
 
     /// Purchase a transit ticket for any transport mode
     ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
-    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
     /// 2. `[writable]` passenger: [Passenger]
     /// 3. `[writable]` ticket: [Ticket]
-    /// 4. `[signer]` user: [AccountInfo] User's wallet address
-    /// 5. `[writable]` user_token_account: [AccountInfo] User's token account for payment
-    /// 6. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
-    /// 7. `[]` currency_mint: [Mint] Currency mint address
-    /// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-    /// 9. `[writable]` source: [AccountInfo] The source account.
-    /// 10. `[]` mint: [Mint] The token mint.
-    /// 11. `[writable]` destination: [AccountInfo] The destination account.
-    /// 12. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-    /// 13. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 4. `[writable]` payment: [Payment] Receipt for this purchase
+    /// 5. `[signer]` user: [AccountInfo] User's wallet address
+    /// 6. `[writable]` user_token_account: [AccountInfo] User's token account for payment
+    /// 7. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
+    /// 8. `[]` currency_mint: [Mint] Currency mint address
+    /// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 10. `[writable]` source: [AccountInfo] The source account.
+    /// 11. `[]` mint: [Mint] The token mint.
+    /// 12. `[writable]` destination: [AccountInfo] The destination account.
+    /// 13. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+    /// 14. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 15. `[optional]` employer: [Employer] Employer sponsoring the purchase
+    /// 16. `[writable, optional]` employee_benefit: [EmployeeBenefit] User's enrollment with the employer
+    /// 17. `[writable, optional]` employer_escrow: [TokenAccount] Employer escrow paying the fare
+    /// 18. `[writable, optional]` promotion: [Promotion] Promotion to redeem
+    /// 19. `[writable, optional]` promotion_redemption: [PromotionRedemption] User's redemptions of the promotion
+    /// 20. `[optional]` accepted_currency: [AcceptedCurrency] Registry entry when paying in another mint
+    /// 21. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
+    /// 22. `[writable, optional]` ride_history: [RideHistory] User's ride history to append to
     ///
     /// Data:
     /// - transport_mode: [u8] Transport mode (0 = bus, 1 = train)
     /// - ticket_id: [u64] Unique ticket identifier
-    /// - amount: [u64] Amount to pay for the ticket
-    pub fn purchase_ticket(ctx: Context<PurchaseTicket>, transport_mode: u8, ticket_id: u64, amount: u64) -> Result<()> {
-        purchase_ticket::handler(ctx, transport_mode, ticket_id, amount)
+    /// - max_price: [u64] Most the rider agrees to pay for the ticket, in the payment mint
+    /// - promo_code: [Option<String>] Promo code for the promotion (optional)
+    pub fn purchase_ticket(ctx: Context<PurchaseTicket>, transport_mode: u8, ticket_id: u64, max_price: u64, promo_code: Option<String>) -> Result<()> {
+        purchase_ticket::handler(ctx, transport_mode, ticket_id, max_price, promo_code)
     }
 
     /// Mark a ticket as used for travel
     ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-    /// 1. `[writable]` ticket: [Ticket] 
-    /// 2. `[signer]` user: [AccountInfo] User's wallet address
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` ticket: [Ticket] 
+    /// 3. `[writable]` passenger: [Passenger]
+    /// 4. `[signer]` user: [AccountInfo] User's wallet address
+    /// 5. `[writable, optional]` ride_history: [RideHistory] User's ride history to append to
     ///
     /// Data:
     /// - ticket_id: [u64] Ticket identifier
-    pub fn use_ticket(ctx: Context<UseTicket>, ticket_id: u64) -> Result<()> {
-        use_ticket::handler(ctx, ticket_id)
+    /// - station_id: [u32] Boarding station identifier
+    pub fn use_ticket(ctx: Context<UseTicket>, ticket_id: u64, station_id: u32) -> Result<()> {
+        use_ticket::handler(ctx, ticket_id, station_id)
     }
 
-    /// Refund a ticket and return funds to user
+    /// Refund a transit ticket and return funds to user
     ///
     /// Accounts:
-    /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
-    /// 2. `[writable]` passenger: [Passenger] 
-    /// 3. `[writable]` ticket: [Ticket] 
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable]` ticket: [Ticket]
     /// 4. `[signer]` user: [AccountInfo] User's wallet address
     /// 5. `[writable]` user_token_account: [AccountInfo] User's token account for refund
     /// 6. `[writable]` system_token_account: [AccountInfo] System's token account for refund
@@ -105,6 +189,7 @@ pub mod transit_fare_payment {
     /// 10. `[writable]` destination: [AccountInfo] The destination account.
     /// 11. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
     /// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 13. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the ticket
     ///
     /// Data:
     /// - ticket_id: [u64] Ticket identifier
@@ -112,60 +197,66 @@ pub mod transit_fare_payment {
         refund_ticket::handler(ctx, ticket_id)
     }
 
-    /// Record a payment transaction
-    ///
-    /// Accounts:
-    /// 0. `[writable, signer]` fee_payer: [AccountInfo] 
-    /// 1. `[writable]` payment: [Payment] 
-    /// 2. `[signer]` user: [AccountInfo] User's wallet address
-    /// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-    ///
-    /// Data:
-    /// - payment_id: [u64] Unique payment identifier
-    /// - amount: [u64] Payment amount
-    /// - currency_mint: [Pubkey] Currency mint address
-    /// - transaction_hash: [String] Transaction hash for reference
-    pub fn record_payment(ctx: Context<RecordPayment>, payment_id: u64, amount: u64, currency_mint: Pubkey, transaction_hash: String) -> Result<()> {
-        record_payment::handler(ctx, payment_id, amount, currency_mint, transaction_hash)
-    }
-
     /// Purchase a subscription pass for transit travel
     ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
     /// 1. `[]` fare_config: [FareConfig] Fare configuration account
     /// 2. `[writable]` passenger: [Passenger]
-    /// 3. `[signer]` user: [AccountInfo] User's wallet address
-    /// 4. `[writable]` user_token_account: [AccountInfo] User's token account for payment
-    /// 5. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
-    /// 6. `[]` currency_mint: [Mint] Currency mint address
-    /// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
-    /// 8. `[writable]` source: [AccountInfo] The source account.
-    /// 9. `[]` mint: [Mint] The token mint.
-    /// 10. `[writable]` destination: [AccountInfo] The destination account.
-    /// 11. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
-    /// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 3. `[writable]` payment: [Payment] Receipt for this purchase
+    /// 4. `[signer]` user: [AccountInfo] User's wallet address
+    /// 5. `[writable]` user_token_account: [AccountInfo] User's token account for payment
+    /// 6. `[writable]` system_token_account: [AccountInfo] System's token account for receiving payment
+    /// 7. `[]` currency_mint: [Mint] Currency mint address
+    /// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 9. `[writable]` source: [AccountInfo] The source account.
+    /// 10. `[]` mint: [Mint] The token mint.
+    /// 11. `[writable]` destination: [AccountInfo] The destination account.
+    /// 12. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+    /// 13. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 14. `[optional]` employer: [Employer] Employer sponsoring the purchase
+    /// 15. `[writable, optional]` employee_benefit: [EmployeeBenefit] User's enrollment with the employer
+    /// 16. `[writable, optional]` employer_escrow: [TokenAccount] Employer escrow paying the pass
+    /// 17. `[writable, optional]` promotion: [Promotion] Promotion to redeem
+    /// 18. `[writable, optional]` promotion_redemption: [PromotionRedemption] User's redemptions of the promotion
+    /// 19. `[optional]` accepted_currency: [AcceptedCurrency] Registry entry when paying in another mint
+    /// 20. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
     ///
     /// Data:
     /// - subscription_type: [u8] Subscription type (1=monthly, 2=yearly)
-    pub fn purchase_subscription(ctx: Context<PurchaseSubscription>, subscription_type: u8) -> Result<()> {
-        purchase_subscription::handler(ctx, subscription_type)
+    /// - seat_count: [u8] Riders covered by the pass (1 = individual, more = household)
+    /// - max_price: [u64] Most the rider agrees to pay for the pass, in the payment mint
+    /// - promo_code: [Option<String>] Promo code for the promotion (optional)
+    pub fn purchase_subscription(ctx: Context<PurchaseSubscription>, subscription_type: u8, seat_count: u8, max_price: u64, promo_code: Option<String>) -> Result<()> {
+        purchase_subscription::handler(ctx, subscription_type, seat_count, max_price, promo_code)
     }
 
     /// Use a subscription ride for transit travel
     ///
+    /// The rider is either the subscription holder or a member of the holder's
+    /// household. Ride caps apply to each rider separately.
+    ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
-    /// 1. `[writable]` passenger: [Passenger]
-    /// 2. `[signer]` user: [AccountInfo] User's wallet address
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger] Passenger holding the subscription
+    /// 3. `[signer]` user: [AccountInfo] Rider's wallet address (holder or household member)
+    /// 4. `[writable, optional]` household: [Household] Required when the rider is a household member
+    /// 5. `[writable, optional]` ride_history: [RideHistory] Rider's ride history to append to
     ///
-    /// Data: None
-    pub fn use_subscription_ride(ctx: Context<UseSubscriptionRide>) -> Result<()> {
-        use_subscription_ride::handler(ctx)
+    /// Data:
+    /// - transport_mode: [u8] Transport mode being boarded (0 = bus, 1 = train)
+    /// - station_id: [u32] Boarding station identifier
+    pub fn use_subscription_ride(ctx: Context<UseSubscriptionRide>, transport_mode: u8, station_id: u32) -> Result<()> {
+        use_subscription_ride::handler(ctx, transport_mode, station_id)
     }
 
     /// Cancel an active subscription and process pro-rated refund
     ///
+    /// The refund is based on the price recorded at purchase time, always
+    /// charges at least the non-refundable period and withholds the
+    /// configured cancellation fee.
+    ///
     /// Accounts:
     /// 0. `[writable, signer]` fee_payer: [AccountInfo]
     /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
@@ -179,9 +270,825 @@ pub mod transit_fare_payment {
     /// 9. `[writable]` destination: [AccountInfo] The destination account.
     /// 10. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
     /// 11. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 12. `[optional]` accepted_currency: [AcceptedCurrency] Required when the pass was paid in another accepted currency
     ///
     /// Data: None
     pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
         cancel_subscription::handler(ctx)
     }
+
+    /// Expire a lapsed subscription and keep the active-subscription count accurate
+    ///
+    /// Permissionless: anyone can call it once the subscription end has passed.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Caller, receives the crank reward
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger] Passenger whose subscription has lapsed
+    ///
+    /// Data: None
+    pub fn expire_subscription(ctx: Context<ExpireSubscription>) -> Result<()> {
+        expire_subscription::handler(ctx)
+    }
+
+    /// Create the household account that lists the members sharing a pass
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` passenger: [Passenger] Primary passenger holding the subscription
+    /// 2. `[writable]` household: [Household]
+    /// 3. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data: None
+    pub fn create_household(ctx: Context<CreateHousehold>) -> Result<()> {
+        create_household::handler(ctx)
+    }
+
+    /// Add a member wallet to a household pass
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` passenger: [Passenger] Primary passenger holding the subscription
+    /// 2. `[writable]` household: [Household]
+    /// 3. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+    ///
+    /// Data:
+    /// - member: [Pubkey] Wallet to add to the household
+    pub fn add_household_member(ctx: Context<AddHouseholdMember>, member: Pubkey) -> Result<()> {
+        add_household_member::handler(ctx, member)
+    }
+
+    /// Remove a member wallet from a household pass
+    ///
+    /// Rides the member has already taken this period still count if the
+    /// wallet is added back before the pass renews.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` household: [Household]
+    /// 2. `[signer]` user: [AccountInfo] Primary passenger's wallet address
+    ///
+    /// Data:
+    /// - member: [Pubkey] Wallet to remove from the household
+    pub fn remove_household_member(ctx: Context<RemoveHouseholdMember>, member: Pubkey) -> Result<()> {
+        remove_household_member::handler(ctx, member)
+    }
+
+    /// Create an employer benefits account and its token escrow
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` employer: [Employer]
+    /// 3. `[writable]` escrow: [TokenAccount] Escrow token account owned by the employer PDA
+    /// 4. `[signer]` admin: [AccountInfo] Employer administrator
+    /// 5. `[]` currency_mint: [Mint] Fare currency mint
+    /// 6. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data: None
+    pub fn create_employer(ctx: Context<CreateEmployer>) -> Result<()> {
+        create_employer::handler(ctx)
+    }
+
+    /// Enroll an employee wallet in the employer's transit benefits
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` employer: [Employer]
+    /// 2. `[writable]` employee_benefit: [EmployeeBenefit]
+    /// 3. `[signer]` admin: [AccountInfo] Employer administrator
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - employee: [Pubkey] Employee wallet to enroll
+    /// - monthly_allowance: [u64] Maximum the employee may draw from the escrow per month
+    pub fn enroll_employee(ctx: Context<EnrollEmployee>, employee: Pubkey, monthly_allowance: u64) -> Result<()> {
+        enroll_employee::handler(ctx, employee, monthly_allowance)
+    }
+
+    /// Remove an employee from the employer's transit benefits
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Receives the benefit account's rent
+    /// 1. `[writable]` employer: [Employer]
+    /// 2. `[writable]` employee_benefit: [EmployeeBenefit]
+    /// 3. `[signer]` admin: [AccountInfo] Employer administrator
+    ///
+    /// Data: None
+    pub fn unenroll_employee(ctx: Context<UnenrollEmployee>) -> Result<()> {
+        unenroll_employee::handler(ctx)
+    }
+
+    /// Fund an employer's benefits escrow
+    ///
+    /// Anyone may top up an escrow; the funds can only leave through employee
+    /// purchases or an admin withdrawal.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` employer: [Employer]
+    /// 2. `[writable]` escrow: [TokenAccount] Employer escrow token account
+    /// 3. `[writable]` source: [AccountInfo] The source account.
+    /// 4. `[]` mint: [Mint] The token mint.
+    /// 5. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+    /// 6. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data:
+    /// - amount: [u64] Amount to deposit
+    pub fn top_up_employer(ctx: Context<TopUpEmployer>, amount: u64) -> Result<()> {
+        top_up_employer::handler(ctx, amount)
+    }
+
+    /// Withdraw unused funds from an employer's benefits escrow
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` employer: [Employer]
+    /// 2. `[writable]` escrow: [TokenAccount] Employer escrow token account
+    /// 3. `[writable]` destination: [AccountInfo] The destination account.
+    /// 4. `[]` mint: [Mint] The token mint.
+    /// 5. `[signer]` admin: [AccountInfo] Employer administrator
+    /// 6. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data:
+    /// - amount: [u64] Amount to withdraw
+    pub fn withdraw_employer(ctx: Context<WithdrawEmployer>, amount: u64) -> Result<()> {
+        withdraw_employer::handler(ctx, amount)
+    }
+
+    /// Add funds to a passenger's stored-value balance
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+    /// 4. `[signer]` user: [AccountInfo] User's wallet address
+    /// 5. `[writable]` source: [AccountInfo] The source account.
+    /// 6. `[]` mint: [Mint] The token mint.
+    /// 7. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+    /// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 9. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data:
+    /// - amount: [u64] Amount to add to the balance
+    pub fn top_up_stored_value(ctx: Context<TopUpStoredValue>, amount: u64) -> Result<()> {
+        top_up_stored_value::handler(ctx, amount)
+    }
+
+    /// Withdraw the whole remaining stored-value balance back to the rider
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+    /// 4. `[signer]` user: [AccountInfo] User's wallet address
+    /// 5. `[writable]` destination: [AccountInfo] The destination account.
+    /// 6. `[]` mint: [Mint] The token mint.
+    /// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data: None
+    pub fn withdraw_stored_value(ctx: Context<WithdrawStoredValue>) -> Result<()> {
+        withdraw_stored_value::handler(ctx)
+    }
+
+    /// Pay a fare at boarding from the passenger's stored-value balance
+    ///
+    /// No ticket account is created and nothing is transferred out of the
+    /// rider's wallet; the fare moves from the stored-value escrow to the
+    /// treasury.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable]` payment: [Payment] Receipt for this fare
+    /// 4. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+    /// 5. `[signer]` user: [AccountInfo] User's wallet address
+    /// 6. `[writable]` destination: [TokenAccount] System treasury token account
+    /// 7. `[]` mint: [Mint] The token mint.
+    /// 8. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 9. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 10. `[optional]` price_feed: [AccountInfo] Oracle price account, required while fares are in fiat
+    ///
+    /// Data:
+    /// - transport_mode: [u8] Transport mode (0 = bus, 1 = train)
+    /// - max_price: [u64] Most the rider agrees to pay for the fare
+    pub fn pay_fare_from_balance(ctx: Context<PayFareFromBalance>, transport_mode: u8, max_price: u64) -> Result<()> {
+        pay_fare_from_balance::handler(ctx, transport_mode, max_price)
+    }
+
+    /// Set the stored-value balance under which clients prompt a top-up
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` passenger: [Passenger]
+    /// 2. `[signer]` user: [AccountInfo] User's wallet address
+    ///
+    /// Data:
+    /// - threshold: [u64] New low-balance threshold
+    pub fn set_low_balance_threshold(ctx: Context<SetLowBalanceThreshold>, threshold: u64) -> Result<()> {
+        set_low_balance_threshold::handler(ctx, threshold)
+    }
+
+    /// Update loyalty point rates, promotion bonus and redemption costs
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+    ///
+    /// Data:
+    /// - mode_0_rate: [Option<u16>] Points per 10_000 units paid for bus tickets (optional)
+    /// - mode_1_rate: [Option<u16>] Points per 10_000 units paid for train tickets (optional)
+    /// - subscription_rate: [Option<u16>] Points per 10_000 units paid for passes (optional)
+    /// - bonus_multiplier: [Option<u16>] Promotion multiplier in basis points, 10_000 = 1x (optional)
+    /// - bonus_start: [Option<i64>] Promotion window start timestamp (optional)
+    /// - bonus_end: [Option<i64>] Promotion window end timestamp (optional)
+    /// - free_ticket_cost: [Option<u64>] Points needed for a free ticket, 0 disables it (optional)
+    pub fn update_loyalty_config(ctx: Context<UpdateLoyaltyConfig>, mode_0_rate: Option<u16>, mode_1_rate: Option<u16>, subscription_rate: Option<u16>, bonus_multiplier: Option<u16>, bonus_start: Option<i64>, bonus_end: Option<i64>, free_ticket_cost: Option<u64>) -> Result<()> {
+        update_loyalty_config::handler(ctx, mode_0_rate, mode_1_rate, subscription_rate, bonus_multiplier, bonus_start, bonus_end, free_ticket_cost)
+    }
+
+    /// Redeem loyalty points for a free ticket or a discount on the next pass
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable, optional]` ticket: [Ticket] Free ticket, required for the free ticket reward
+    /// 4. `[signer]` user: [AccountInfo] User's wallet address
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - reward: [u8] Reward to redeem (0 = free ticket, 1 = pass discount)
+    /// - points: [u64] Points to spend on a pass discount, one point per fare unit off the pass price
+    /// - transport_mode: [u8] Transport mode of the free ticket (0 = bus, 1 = train)
+    /// - ticket_id: [u64] Unique identifier of the free ticket
+    pub fn redeem_points(ctx: Context<RedeemPoints>, reward: u8, points: u64, transport_mode: u8, ticket_id: u64) -> Result<()> {
+        redeem_points::handler(ctx, reward, points, transport_mode, ticket_id)
+    }
+
+    /// Create a promo code campaign
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` promotion: [Promotion]
+    /// 3. `[]` roles: [Roles] Role holders
+    /// 4. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - code_hash: [[u8; 32]] sha256 of the promo code
+    /// - discount_type: [u8] Discount type (0 = percentage, 1 = fixed amount, 2 = free ride)
+    /// - discount_value: [u64] Basis points for percentage, fare units off the price before currency conversion for fixed, unused for free ride
+    /// - applicable_modes: [u8] Bitmask of transport modes the promotion covers
+    /// - applicable_products: [u8] Bitmask of products (1 = ticket, 2 = monthly pass, 4 = yearly pass)
+    /// - valid_from: [i64] Start of the validity window
+    /// - valid_until: [i64] End of the validity window
+    /// - max_redemptions: [u32] Global redemption limit, 0 = unlimited
+    /// - max_per_user: [u32] Redemptions allowed per rider, 0 = unlimited
+    pub fn create_promotion(ctx: Context<CreatePromotion>, code_hash: [u8; 32], discount_type: u8, discount_value: u64, applicable_modes: u8, applicable_products: u8, valid_from: i64, valid_until: i64, max_redemptions: u32, max_per_user: u32) -> Result<()> {
+        create_promotion::handler(ctx, code_hash, discount_type, discount_value, applicable_modes, applicable_products, valid_from, valid_until, max_redemptions, max_per_user)
+    }
+
+    /// Allow a retail partner to issue gift cards
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` retail_partner: [RetailPartner]
+    /// 3. `[]` roles: [Roles] Role holders
+    /// 4. `[signer]` eligibility_issuer: [AccountInfo] Eligibility issuer role holder
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - partner: [Pubkey] Retail partner wallet
+    pub fn add_retail_partner(ctx: Context<AddRetailPartner>, partner: Pubkey) -> Result<()> {
+        add_retail_partner::handler(ctx, partner)
+    }
+
+    /// Revoke a retail partner's permission to issue gift cards
+    ///
+    /// Gift cards the partner already issued stay redeemable.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` retail_partner: [RetailPartner]
+    /// 3. `[]` roles: [Roles] Role holders
+    /// 4. `[signer]` eligibility_issuer: [AccountInfo] Eligibility issuer role holder
+    ///
+    /// Data: None
+    pub fn remove_retail_partner(ctx: Context<RemoveRetailPartner>) -> Result<()> {
+        remove_retail_partner::handler(ctx)
+    }
+
+    /// Issue a prepaid gift card claimable with a secret code
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[writable]` gift_card: [GiftCard]
+    /// 4. `[writable]` escrow: [TokenAccount] Token account holding the card value
+    /// 5. `[signer]` issuer: [AccountInfo] Eligibility issuer or retail partner issuing the card
+    /// 6. `[optional]` retail_partner: [RetailPartner] Required when the issuer is not the eligibility issuer
+    /// 7. `[writable]` source: [AccountInfo] The source account.
+    /// 8. `[]` mint: [Mint] The token mint.
+    /// 9. `[signer]` authority: [AccountInfo] The source account's owner/delegate.
+    /// 10. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 11. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data:
+    /// - claim_key: [Pubkey] Public key of the keypair derived from the secret claim code
+    /// - value: [u64] Value loaded onto the card
+    /// - expires_at: [i64] Timestamp after which the card can no longer be redeemed
+    pub fn issue_gift_card(ctx: Context<IssueGiftCard>, claim_key: Pubkey, value: u64, expires_at: i64) -> Result<()> {
+        issue_gift_card::handler(ctx, claim_key, value, expires_at)
+    }
+
+    /// Redeem a gift card for stored value or a pass
+    ///
+    /// When a subscription type is given the card buys that pass and any
+    /// leftover value is credited to the stored-value balance. The claim key
+    /// signs the same transaction as the rider, so a claim seen in flight
+    /// can't be replayed for another wallet.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` gift_card: [GiftCard]
+    /// 3. `[writable]` escrow: [TokenAccount] Token account holding the card value
+    /// 4. `[writable]` issuer: [AccountInfo] Issuer of the card, receives the closed accounts' rent
+    /// 5. `[writable]` passenger: [Passenger]
+    /// 6. `[writable]` stored_value: [TokenAccount] Passenger's stored-value escrow
+    /// 7. `[signer]` user: [AccountInfo] User's wallet address
+    /// 8. `[signer]` claim: [AccountInfo] Keypair derived from the secret claim code
+    /// 9. `[writable, optional]` destination: [TokenAccount] System treasury, required to buy a pass
+    /// 10. `[]` mint: [Mint] The token mint.
+    /// 11. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 12. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    /// 13. `[optional]` price_feed: [AccountInfo] Oracle price account, required to buy a pass while fares are in fiat
+    /// 14. `[writable, optional]` payment: [Payment] Receipt for the pass, required to buy a pass
+    ///
+    /// Data:
+    /// - subscription_type: [u8] Pass to buy (0 = credit the balance only, 1 = monthly, 2 = yearly)
+    /// - max_price: [u64] Most the rider agrees to pay for the pass, ignored without a pass
+    pub fn redeem_gift_card(ctx: Context<RedeemGiftCard>, subscription_type: u8, max_price: u64) -> Result<()> {
+        redeem_gift_card::handler(ctx, subscription_type, max_price)
+    }
+
+    /// Return the value of an expired, unredeemed gift card to its issuer
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` gift_card: [GiftCard]
+    /// 3. `[writable]` escrow: [TokenAccount] Token account holding the card value
+    /// 4. `[writable, signer]` issuer: [AccountInfo] Issuer of the card
+    /// 5. `[writable]` destination: [AccountInfo] The destination account.
+    /// 6. `[]` mint: [Mint] The token mint.
+    /// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data: None
+    pub fn reclaim_gift_card(ctx: Context<ReclaimGiftCard>) -> Result<()> {
+        reclaim_gift_card::handler(ctx)
+    }
+
+    /// Accept an additional payment currency at a fixed conversion rate
+    ///
+    /// Prices are converted as `base_amount * rate_numerator / rate_denominator`,
+    /// rounded up.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` accepted_currency: [AcceptedCurrency]
+    /// 3. `[]` mint: [Mint] Mint to accept
+    /// 4. `[]` treasury: [TokenAccount] Token account receiving payments in this mint
+    /// 5. `[]` roles: [Roles] Role holders
+    /// 6. `[signer]` treasurer: [AccountInfo] Treasurer role holder
+    /// 7. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - rate_numerator: [u64] Units of the mint per rate_denominator base fare units
+    /// - rate_denominator: [u64] Base fare units the numerator is quoted against
+    pub fn add_accepted_currency(ctx: Context<AddAcceptedCurrency>, rate_numerator: u64, rate_denominator: u64) -> Result<()> {
+        add_accepted_currency::handler(ctx, rate_numerator, rate_denominator)
+    }
+
+    /// Update the conversion rate, treasury or enabled flag of an accepted currency
+    ///
+    /// A new rate is queued and takes effect once the minimum fare notice has
+    /// passed; the treasury and enabled flag change at once.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` accepted_currency: [AcceptedCurrency]
+    /// 3. `[optional]` treasury: [TokenAccount] New treasury for the currency
+    /// 4. `[]` roles: [Roles] Role holders
+    /// 5. `[signer]` treasurer: [AccountInfo] Treasurer role holder
+    ///
+    /// Data:
+    /// - rate_numerator: [Option<u64>] New rate numerator (optional)
+    /// - rate_denominator: [Option<u64>] New rate denominator (optional)
+    /// - enabled: [Option<bool>] Whether riders may pay with this currency (optional)
+    pub fn update_accepted_currency(ctx: Context<UpdateAcceptedCurrency>, rate_numerator: Option<u64>, rate_denominator: Option<u64>, enabled: Option<bool>) -> Result<()> {
+        update_accepted_currency::handler(ctx, rate_numerator, rate_denominator, enabled)
+    }
+
+    /// Denominate fares in fiat units priced by an oracle, or switch back to token fares
+    ///
+    /// While a price feed is set, every fare and pass price in the fare config is
+    /// read as fiat units and converted into `currency_mint` at purchase time.
+    /// The change is queued and takes effect once the minimum fare notice has
+    /// passed, replacing any change already queued.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+    ///
+    /// Data:
+    /// - price_feed: [Pubkey] Price account for the currency mint, default pubkey for token fares
+    /// - max_price_age: [i64] Seconds after which a price is rejected as stale
+    /// - max_confidence_bps: [u16] Widest accepted confidence interval, in basis points of the price
+    pub fn set_price_feed(ctx: Context<SetPriceFeed>, price_feed: Pubkey, max_price_age: i64, max_confidence_bps: u16) -> Result<()> {
+        set_price_feed::handler(ctx, price_feed, max_price_age, max_confidence_bps)
+    }
+
+    /// Create the rider's ride and payment history ring buffer
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` ride_history: [RideHistory]
+    /// 2. `[signer]` user: [AccountInfo] User's wallet address
+    /// 3. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data: None
+    pub fn create_ride_history(ctx: Context<CreateRideHistory>) -> Result<()> {
+        create_ride_history::handler(ctx)
+    }
+
+    /// Close a used or expired ticket and return its rent to the original fee payer
+    ///
+    /// Anyone may call this; the rent always goes to the account that paid for
+    /// the ticket.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` ticket: [Ticket]
+    /// 2. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the ticket
+    /// 3. `[writable, optional]` passenger: [Passenger] Ticket owner, required for unused tickets
+    ///
+    /// Data: None
+    pub fn close_ticket(ctx: Context<CloseTicket>) -> Result<()> {
+        close_ticket::handler(ctx)
+    }
+
+    /// Close a payment receipt older than the retention period
+    ///
+    /// Anyone may call this; the rent always goes to the account that paid for
+    /// the receipt.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` payment: [Payment]
+    /// 3. `[writable]` rent_payer: [AccountInfo] Fee payer recorded on the receipt
+    ///
+    /// Data: None
+    pub fn close_payment(ctx: Context<ClosePayment>) -> Result<()> {
+        close_payment::handler(ctx)
+    }
+
+    /// Set how long new tickets stay valid and how long receipts are kept
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` operator: [AccountInfo] Operator role holder
+    ///
+    /// Data:
+    /// - ticket_validity_period: [i64] Seconds a ticket stays usable after purchase, 0 = never expires
+    /// - payment_retention_period: [i64] Seconds before a receipt may be closed, 0 = kept forever
+    pub fn set_retention_policy(ctx: Context<SetRetentionPolicy>, ticket_validity_period: i64, payment_retention_period: i64) -> Result<()> {
+        set_retention_policy::handler(ctx, ticket_validity_period, payment_retention_period)
+    }
+
+    /// Delete a passenger account and return its rent to the rider
+    ///
+    /// The rider's ride history, empty stored-value escrow and household are
+    /// deleted along with it when passed in, their rent going to the rider too.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` passenger: [Passenger]
+    /// 3. `[writable, optional]` ride_history: [RideHistory] Rider's ride history to delete
+    /// 4. `[writable, optional]` stored_value: [TokenAccount] Rider's stored-value escrow to close
+    /// 5. `[writable, optional]` household: [Household] Rider's household to delete
+    /// 6. `[writable, signer]` user: [AccountInfo] User's wallet address
+    /// 7. `[]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data: None
+    pub fn close_passenger(ctx: Context<ClosePassenger>) -> Result<()> {
+        close_passenger::handler(ctx)
+    }
+
+    /// Propose a new admin, who takes over once they accept
+    ///
+    /// Proposing the default pubkey cancels a pending transfer.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[signer]` admin: [AccountInfo] Current administrator
+    ///
+    /// Data:
+    /// - new_admin: [Pubkey] Account that must call accept_admin to become admin
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        propose_admin::handler(ctx, new_admin)
+    }
+
+    /// Complete an admin transfer started with propose_admin
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[writable]` roles: [Roles]
+    /// 3. `[writable]` role_change: [RoleChange] Record of the transfer
+    /// 4. `[signer]` new_admin: [AccountInfo] The proposed admin
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data: None
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    /// Assign a privileged role to a new holder
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` roles: [Roles]
+    /// 3. `[writable]` role_change: [RoleChange] Record of the change
+    /// 4. `[signer]` admin: [AccountInfo] Administrator account
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - role: [u8] Role to assign (1 = fare manager, 2 = treasurer, 3 = operator, 4 = eligibility issuer, 5 = treasury owner)
+    /// - holder: [Pubkey] New holder of the role
+    pub fn set_role(ctx: Context<SetRole>, role: u8, holder: Pubkey) -> Result<()> {
+        set_role::handler(ctx, role, holder)
+    }
+
+    /// Set the guardian key allowed to pause the program
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[signer]` admin: [AccountInfo] Administrator account
+    ///
+    /// Data:
+    /// - guardian: [Pubkey] New guardian, default pubkey to remove it
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        set_guardian::handler(ctx, guardian)
+    }
+
+    /// Pause one or more instruction families
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[signer]` authority: [AccountInfo] Administrator or guardian
+    ///
+    /// Data:
+    /// - flags: [u8] Bitmask of families to pause (1 = ticket sales, 2 = ticket use, 4 = refunds, 8 = subscription purchase, 16 = subscription cancel)
+    pub fn pause(ctx: Context<Pause>, flags: u8) -> Result<()> {
+        pause::handler(ctx, flags)
+    }
+
+    /// Resume one or more paused instruction families
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[signer]` admin: [AccountInfo] Administrator account
+    ///
+    /// Data:
+    /// - flags: [u8] Bitmask of families to resume, same bits as pause
+    pub fn unpause(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+        unpause::handler(ctx, flags)
+    }
+
+    /// Queue new fares and pass prices to take effect at a later time
+    ///
+    /// Unset values keep the fares in force when the change is queued. A new
+    /// change replaces any change that is still pending.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+    ///
+    /// Data:
+    /// - mode_0_fare: [Option<u64>] New bus fare amount (transport mode 0 = bus, optional)
+    /// - mode_1_fare: [Option<u64>] New train fare amount (transport mode 1 = train, optional)
+    /// - monthly_pass_price: [Option<u64>] New monthly subscription price (optional)
+    /// - yearly_pass_price: [Option<u64>] New yearly subscription price (optional)
+    /// - effective_at: [i64] Timestamp the new fares take effect, at least min_fare_notice from now
+    pub fn schedule_fare_change(ctx: Context<ScheduleFareChange>, mode_0_fare: Option<u64>, mode_1_fare: Option<u64>, monthly_pass_price: Option<u64>, yearly_pass_price: Option<u64>, effective_at: i64) -> Result<()> {
+        schedule_fare_change::handler(ctx, mode_0_fare, mode_1_fare, monthly_pass_price, yearly_pass_price, effective_at)
+    }
+
+    /// Cancel a queued fare change before it takes effect
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` roles: [Roles] Role holders
+    /// 3. `[signer]` fare_manager: [AccountInfo] Fare manager role holder
+    ///
+    /// Data: None
+    pub fn cancel_fare_change(ctx: Context<CancelFareChange>) -> Result<()> {
+        cancel_fare_change::handler(ctx)
+    }
+
+    /// Write a due fare or price feed change into the live settings
+    ///
+    /// Anyone may call this. Fare instructions already charge a due change
+    /// before it is applied, so this only tidies up the stored settings.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    ///
+    /// Data: None
+    pub fn apply_fare_change(ctx: Context<ApplyFareChange>) -> Result<()> {
+        apply_fare_change::handler(ctx)
+    }
+
+    /// Set the minimum notice required before a fare change takes effect
+    ///
+    /// The notice can't go below MIN_FARE_NOTICE_FLOOR. A longer notice applies
+    /// immediately; a shorter one takes effect once the current notice has passed.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[signer]` admin: [AccountInfo] Administrator account
+    ///
+    /// Data:
+    /// - min_fare_notice: [i64] Seconds between scheduling a fare change and its effective time
+    pub fn set_fare_notice_period(ctx: Context<SetFareNoticePeriod>, min_fare_notice: i64) -> Result<()> {
+        set_fare_notice_period::handler(ctx, min_fare_notice)
+    }
+
+    /// Create the M-of-N multisig that can take over administration
+    ///
+    /// The multisig only gains authority once the admin proposes it with
+    /// propose_admin and a multisig proposal accepts the role.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` fare_config: [FareConfig] Fare configuration account
+    /// 2. `[writable]` multisig: [Multisig]
+    /// 3. `[signer]` admin: [AccountInfo] Administrator account
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - owners: [Vec<Pubkey>] Owners allowed to propose and approve, at most 10
+    /// - threshold: [u8] Approvals needed to execute a proposal
+    pub fn create_multisig(ctx: Context<CreateMultisig>, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        create_multisig::handler(ctx, owners, threshold)
+    }
+
+    /// Propose a privileged action for the multisig owners to approve
+    ///
+    /// The proposer's approval is recorded with the proposal.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` multisig: [Multisig]
+    /// 2. `[writable]` proposal: [Proposal]
+    /// 3. `[signer]` proposer: [AccountInfo] Multisig owner making the proposal
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    ///
+    /// Data:
+    /// - action: [MultisigAction] Action to carry out once approved
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: MultisigAction) -> Result<()> {
+        create_proposal::handler(ctx, action)
+    }
+
+    /// Approve a pending multisig proposal
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[]` multisig: [Multisig]
+    /// 2. `[writable]` proposal: [Proposal]
+    /// 3. `[signer]` owner: [AccountInfo] Multisig owner approving the proposal
+    ///
+    /// Data: None
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        approve_proposal::handler(ctx)
+    }
+
+    /// Carry out a multisig proposal that reached its approval threshold
+    ///
+    /// Anyone may call this. Every action except AcceptAdmin and Withdraw
+    /// requires the multisig to be the admin; Withdraw requires the multisig to
+    /// own the treasury token account. Role and admin changes need role_change;
+    /// withdrawals need treasury, destination, mint and token_program.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo]
+    /// 1. `[writable]` fare_config: [FareConfig]
+    /// 2. `[]` multisig: [Multisig]
+    /// 3. `[writable]` proposal: [Proposal]
+    /// 4. `[writable]` roles: [Roles]
+    /// 5. `[writable, optional]` role_change: [RoleChange] Record of an admin or role change
+    /// 6. `[writable, optional]` treasury: [TokenAccount] Multisig-owned token account to withdraw from
+    /// 7. `[writable, optional]` destination: [TokenAccount] Token account receiving a withdrawal
+    /// 8. `[optional]` mint: [Mint] Mint of the treasury
+    /// 9. `[]` system_program: [AccountInfo] Auto-generated, for account initialization
+    /// 10. `[optional]` token_program: [AccountInfo] Auto-generated, TokenProgram
+    ///
+    /// Data: None
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        execute_proposal::handler(ctx)
+    }
+
+    /// Upgrade an unversioned fare config account to the current layout in place
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+    /// 1. `[writable]` fare_config: [FareConfig] Fare config in the unversioned layout
+    /// 2. `[writable]` roles: [Roles] Role holders, all initially the admin
+    /// 3. `[signer]` admin: [AccountInfo] Administrator recorded in the old account
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up and roles
+    ///
+    /// Data: None
+    pub fn migrate_fare_config(ctx: Context<MigrateFareConfig>) -> Result<()> {
+        migrate_fare_config::handler(ctx)
+    }
+
+    /// Upgrade an unversioned passenger account to the current layout in place.
+    /// Permissionless, the fare config must be migrated first.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+    /// 1. `[]` fare_config: [FareConfig] Prices an active pass for later refunds
+    /// 2. `[writable]` passenger: [Passenger] Passenger in the unversioned layout
+    /// 3. `[]` user: [AccountInfo] Owner of the passenger account
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+    ///
+    /// Data: None
+    pub fn migrate_passenger(ctx: Context<MigratePassenger>) -> Result<()> {
+        migrate_passenger::handler(ctx)
+    }
+
+    /// Upgrade an unversioned ticket to the current layout in place.
+    /// Permissionless, the passenger must be migrated first.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+    /// 1. `[]` fare_config: [FareConfig] Supplies the mint the old program took payment in
+    /// 2. `[writable]` passenger: [Passenger] Counts the ticket if it is unused
+    /// 3. `[writable]` ticket: [Ticket] Ticket in the unversioned layout
+    /// 4. `[]` user: [AccountInfo] Owner of the ticket
+    /// 5. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+    ///
+    /// Data:
+    /// - ticket_id: [u64] Id the ticket was bought with
+    pub fn migrate_ticket(ctx: Context<MigrateTicket>, ticket_id: u64) -> Result<()> {
+        migrate_ticket::handler(ctx, ticket_id)
+    }
+
+    /// Upgrade a receipt written by the unversioned record_payment to the
+    /// current layout in place. Permissionless, the passenger must be
+    /// migrated first.
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` fee_payer: [AccountInfo] Pays the extra rent for the larger account
+    /// 1. `[writable]` passenger: [Passenger] Moves new receipt ids past the migrated one
+    /// 2. `[writable]` payment: [Payment] Receipt in the unversioned layout
+    /// 3. `[]` user: [AccountInfo] Owner of the receipt
+    /// 4. `[]` system_program: [AccountInfo] Auto-generated, for the rent top-up
+    ///
+    /// Data:
+    /// - payment_id: [u64] Id the receipt was recorded with
+    pub fn migrate_payment(ctx: Context<MigratePayment>, payment_id: u64) -> Result<()> {
+        migrate_payment::handler(ctx, payment_id)
+    }
 }
\ No newline at end of file
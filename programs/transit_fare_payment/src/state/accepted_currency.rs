@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::FarePaymentError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct AcceptedCurrency {
+	pub version: u8,               // Account layout version, AcceptedCurrency::VERSION once initialized
+	pub mint: Pubkey,              // Mint riders may pay with
+	pub treasury: Pubkey,          // Token account receiving payments in this mint
+	pub rate_numerator: u64,       // Units of this mint per rate_denominator base units
+	pub rate_denominator: u64,
+	pub pending_rate_numerator: u64,   // Queued rate, applied at rate_effective_at
+	pub pending_rate_denominator: u64,
+	pub rate_effective_at: i64,        // Timestamp the queued rate takes effect, 0 = none queued
+	pub enabled: bool,
+	pub bump: u8,
+}
+
+impl AcceptedCurrency {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Rate numerator and denominator in force at `current_time`, including
+	/// a queued rate that has taken effect
+	pub fn rate(&self, current_time: i64) -> (u64, u64) {
+		if self.rate_effective_at != 0 && current_time >= self.rate_effective_at {
+			return (self.pending_rate_numerator, self.pending_rate_denominator);
+		}
+		(self.rate_numerator, self.rate_denominator)
+	}
+
+	/// Queue a new rate at `effective_at`, replacing any queued rate
+	pub fn schedule_rate(&mut self, rate_numerator: u64, rate_denominator: u64, effective_at: i64, current_time: i64) {
+		(self.rate_numerator, self.rate_denominator) = self.rate(current_time);
+		self.pending_rate_numerator = rate_numerator;
+		self.pending_rate_denominator = rate_denominator;
+		self.rate_effective_at = effective_at;
+	}
+
+	/// Convert a price in base fare units into this mint at the rate in force
+	/// at `current_time`, rounding up so the fare system is never underpaid
+	pub fn convert(&self, base_amount: u64, current_time: i64) -> Result<u64> {
+		let (rate_numerator, rate_denominator) = self.rate(current_time);
+		Ok(transit_core::convert_at_rate(base_amount, rate_numerator, rate_denominator).map_err(FarePaymentError::from)?)
+	}
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::FarePaymentError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Employer {
+	pub version: u8,               // Account layout version, Employer::VERSION once initialized
+	pub admin: Pubkey,             // Employer administrator wallet
+	pub currency_mint: Pubkey,     // Mint held in the escrow
+	pub escrow: Pubkey,            // Token account owned by this PDA that funds benefits
+	pub enrolled_employees: u32,   // Number of EmployeeBenefit accounts for this employer
+	pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EmployeeBenefit {
+	pub version: u8,               // Account layout version, EmployeeBenefit::VERSION once initialized
+	pub employer: Pubkey,
+	pub employee: Pubkey,          // Enrolled employee wallet
+	pub monthly_allowance: u64,    // Maximum drawn from the escrow per 30-day month
+	pub allowance_month: i64,      // Month index (unix_timestamp / 30 days) of spent_this_month
+	pub spent_this_month: u64,
+	pub bump: u8,
+}
+
+impl Employer {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Verify that `benefit` and `escrow` let `employee` spend from this employer
+	pub fn verify_benefit(&self, employer: Pubkey, benefit: &EmployeeBenefit, escrow: Pubkey, employee: Pubkey) -> Result<()> {
+		require_keys_eq!(benefit.employer, employer, FarePaymentError::EmployeeNotEnrolled);
+		require_keys_eq!(benefit.employee, employee, FarePaymentError::EmployeeNotEnrolled);
+		require_keys_eq!(escrow, self.escrow, FarePaymentError::InvalidEscrow);
+		Ok(())
+	}
+}
+
+impl EmployeeBenefit {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Count `amount` against this month's allowance, starting a new month if needed
+	pub fn charge(&mut self, amount: u64, current_time: i64) -> Result<()> {
+		let month = current_time / crate::SECONDS_PER_MONTH;
+		if self.allowance_month != month {
+			self.allowance_month = month;
+			self.spent_this_month = 0;
+		}
+
+		let spent = self
+			.spent_this_month
+			.checked_add(amount)
+			.ok_or(FarePaymentError::AllowanceExceeded)?;
+		require!(spent <= self.monthly_allowance, FarePaymentError::AllowanceExceeded);
+
+		self.spent_this_month = spent;
+		Ok(())
+	}
+}
@@ -1,8 +1,15 @@
 use anchor_lang::prelude::*;
 
+use transit_core::FareTable;
+
+use crate::{FareConfigUpdated, FarePaymentError, PriceFeed, Roles};
+
 #[account]
+#[derive(InitSpace)]
 pub struct FareConfig {
+	pub version: u8,               // Account layout version, FareConfig::VERSION once initialized
 	pub admin: Pubkey,
+	pub treasury: Pubkey,     // Owner of the token accounts fares and passes are paid into
 	pub bus_fare: u64,        // Transport mode 0: bus fare amount
 	pub train_fare: u64,      // Transport mode 1: train fare amount
 	pub currency_mint: Pubkey,
@@ -11,5 +18,510 @@ pub struct FareConfig {
 	pub monthly_pass_price: u64,       // Price for 30-day subscription
 	pub yearly_pass_price: u64,        // Price for 365-day subscription
 	pub total_active_subscriptions: u64, // Count of active subscriptions
+	pub expiry_crank_reward: u64,        // Lamports paid to whoever expires a lapsed subscription
+	// Subscription cancellation policy
+	pub cancellation_fee: u64,           // Flat fee withheld from every subscription refund
+	pub non_refundable_period: i64,      // Seconds of a subscription that are always charged
+	// Subscription ride rules, copied onto each pass at purchase time
+	pub subscription_allowed_modes: u8,         // Bitmask of transport modes (1 << mode)
+	pub subscription_max_rides_per_day: u32,    // 0 = unlimited
+	pub subscription_max_rides_per_period: u32, // 0 = unlimited
+	// Loyalty program, rates are points per 10_000 fare units paid
+	pub loyalty_rates: [u16; 2],         // Points rate per transport mode for tickets
+	pub loyalty_subscription_rate: u16,  // Points rate for subscription passes
+	pub loyalty_bonus_multiplier: u16,   // Promotion multiplier in basis points (10_000 = 1x)
+	pub loyalty_bonus_start: i64,        // Promotion window start, inclusive
+	pub loyalty_bonus_end: i64,          // Promotion window end, exclusive
+	pub loyalty_free_ticket_cost: u64,   // Points needed for a free ticket, 0 = not redeemable
+	// Oracle pricing, fares and pass prices are in fiat units while a feed is set
+	pub price_feed: Pubkey,              // Price account for currency_mint, default = fares in tokens
+	pub max_price_age: i64,              // Seconds after which a price is stale
+	pub max_confidence_bps: u16,         // Widest accepted confidence interval, in basis points of the price
+	pub pending_price_feed: PriceFeedSettings, // Queued price feed change, effective_at = 0 when none is pending
+	// Account retention
+	pub ticket_validity_period: i64,     // Seconds a ticket stays usable after purchase, 0 = never expires
+	pub payment_retention_period: i64,   // Seconds before a receipt may be closed, 0 = kept forever
+	pub total_receipts: u64,             // Receipts written for every rider, where a new rider's receipt ids start
+	// Two-step admin transfer
+	pub pending_admin: Pubkey,           // Proposed admin, default = no transfer in progress
+	// Emergency pause
+	pub guardian: Pubkey,                // May pause but not unpause, default = admin only
+	pub paused: u8,                      // Bitmask of PAUSE_* flags currently in effect
+	// Timelocked fare changes
+	pub min_fare_notice: i64,            // Minimum seconds between scheduling a fare change and its effective time
+	pub pending_min_fare_notice: i64,    // Queued shorter notice period
+	pub min_fare_notice_effective_at: i64, // Timestamp the shorter notice takes effect, 0 = none queued
+	pub pending_fares: FareSchedule,     // Queued fare change, effective_at = 0 when none is pending
+	pub config_version: u64,             // Incremented on every configuration change, carried by events
 	pub bump: u8,
-}
\ No newline at end of file
+}
+
+/// Fares and pass prices, either live or queued to take effect later
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct FareSchedule {
+	pub bus_fare: u64,
+	pub train_fare: u64,
+	pub monthly_pass_price: u64,
+	pub yearly_pass_price: u64,
+	pub effective_at: i64,      // Timestamp the schedule takes effect, 0 = not scheduled
+}
+
+/// Oracle settings, either live or queued to take effect later
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PriceFeedSettings {
+	pub price_feed: Pubkey,     // Default = fares in tokens
+	pub max_price_age: i64,
+	pub max_confidence_bps: u16,
+	pub effective_at: i64,      // Timestamp the settings take effect, 0 = not scheduled
+}
+
+impl FareSchedule {
+	/// The schedule's prices as a transit_core fare table
+	pub fn table(&self) -> FareTable {
+		FareTable {
+			bus_fare: self.bus_fare,
+			train_fare: self.train_fare,
+			monthly_pass_price: self.monthly_pass_price,
+			yearly_pass_price: self.yearly_pass_price,
+		}
+	}
+
+	/// Fare for `transport_mode`
+	pub fn fare(&self, transport_mode: u8) -> Result<u64> {
+		Ok(self.table().fare(transport_mode).map_err(FarePaymentError::from)?)
+	}
+
+	/// Price and duration of a pass of `subscription_type` covering `seat_count` riders
+	pub fn pass(&self, subscription_type: u8, seat_count: u8) -> Result<(u64, i64)> {
+		Ok(self.table().household_pass(subscription_type, seat_count).map_err(FarePaymentError::from)?)
+	}
+}
+
+impl FareConfig {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Bump the configuration version and emit FareConfigUpdated
+	pub fn record_update(&mut self, updated_by: Pubkey) -> Result<()> {
+		self.config_version += 1;
+		emit!(FareConfigUpdated {
+			version: crate::EVENT_SCHEMA_VERSION,
+			updated_by,
+			fare_config_version: self.config_version,
+			admin: self.admin,
+			bus_fare: self.bus_fare,
+			train_fare: self.train_fare,
+			monthly_pass_price: self.monthly_pass_price,
+			yearly_pass_price: self.yearly_pass_price,
+			pending_fares: self.pending_fares,
+			cancellation_fee: self.cancellation_fee,
+			non_refundable_period: self.non_refundable_period,
+			paused: self.paused,
+			timestamp: Clock::get()?.unix_timestamp,
+		});
+		Ok(())
+	}
+
+	/// Hand the admin role to the proposed admin, returning the previous admin
+	pub fn accept_admin(&mut self, new_admin: Pubkey) -> Result<Pubkey> {
+		require!(
+			new_admin != Pubkey::default() && new_admin == self.pending_admin,
+			FarePaymentError::NotPendingAdmin
+		);
+		self.pending_admin = Pubkey::default();
+		Ok(std::mem::replace(&mut self.admin, new_admin))
+	}
+
+	/// Assign `role` to `holder`, the treasury owner here and every other
+	/// role in `roles`, returning the previous holder
+	pub fn assign_role(&mut self, roles: &mut Roles, role: u8, holder: Pubkey) -> Result<Pubkey> {
+		if role == crate::ROLE_TREASURY {
+			require!(holder != Pubkey::default(), FarePaymentError::InvalidTreasury);
+			return Ok(std::mem::replace(&mut self.treasury, holder));
+		}
+		roles.assign(role, holder)
+	}
+
+	/// Whether a queued fare change has reached its effective time
+	pub fn fare_change_due(&self, current_time: i64) -> bool {
+		self.pending_fares.effective_at != 0 && current_time >= self.pending_fares.effective_at
+	}
+
+	/// Fares in force at `current_time`, including a due change that has not
+	/// been applied yet
+	pub fn current_fares(&self, current_time: i64) -> FareSchedule {
+		if self.fare_change_due(current_time) {
+			return self.pending_fares;
+		}
+		FareSchedule {
+			bus_fare: self.bus_fare,
+			train_fare: self.train_fare,
+			monthly_pass_price: self.monthly_pass_price,
+			yearly_pass_price: self.yearly_pass_price,
+			effective_at: 0,
+		}
+	}
+
+	/// Make a due fare change the live fares and clear the queue
+	pub fn apply_due_fare_change(&mut self, current_time: i64) -> bool {
+		if !self.fare_change_due(current_time) {
+			return false;
+		}
+		let fares = self.pending_fares;
+		self.bus_fare = fares.bus_fare;
+		self.train_fare = fares.train_fare;
+		self.monthly_pass_price = fares.monthly_pass_price;
+		self.yearly_pass_price = fares.yearly_pass_price;
+		self.pending_fares = FareSchedule::default();
+		true
+	}
+
+	/// Queue a fare change at `effective_at`, replacing any pending change.
+	/// Unset values keep the fares in force at `current_time`.
+	pub fn schedule_fare_change(&mut self, mode_0_fare: Option<u64>, mode_1_fare: Option<u64>, monthly_pass_price: Option<u64>, yearly_pass_price: Option<u64>, effective_at: i64, current_time: i64) -> Result<()> {
+		transit_core::validate_fare_notice(effective_at, current_time, self.fare_notice(current_time)).map_err(FarePaymentError::from)?;
+
+		// A change that is already due becomes the base for the new one
+		self.apply_due_fare_change(current_time);
+
+		let mut fares = self.current_fares(current_time);
+		fares.bus_fare = mode_0_fare.unwrap_or(fares.bus_fare);
+		fares.train_fare = mode_1_fare.unwrap_or(fares.train_fare);
+		fares.monthly_pass_price = monthly_pass_price.unwrap_or(fares.monthly_pass_price);
+		fares.yearly_pass_price = yearly_pass_price.unwrap_or(fares.yearly_pass_price);
+		fares.effective_at = effective_at;
+		self.pending_fares = fares;
+		Ok(())
+	}
+
+	/// Whether a queued price feed change has reached its effective time
+	pub fn price_feed_change_due(&self, current_time: i64) -> bool {
+		self.pending_price_feed.effective_at != 0 && current_time >= self.pending_price_feed.effective_at
+	}
+
+	/// Oracle settings in force at `current_time`, including a due change
+	/// that has not been applied yet
+	pub fn current_price_feed(&self, current_time: i64) -> PriceFeedSettings {
+		if self.price_feed_change_due(current_time) {
+			return self.pending_price_feed;
+		}
+		PriceFeedSettings {
+			price_feed: self.price_feed,
+			max_price_age: self.max_price_age,
+			max_confidence_bps: self.max_confidence_bps,
+			effective_at: 0,
+		}
+	}
+
+	/// Make a due price feed change the live settings and clear the queue
+	pub fn apply_due_price_feed_change(&mut self, current_time: i64) -> bool {
+		if !self.price_feed_change_due(current_time) {
+			return false;
+		}
+		let settings = self.pending_price_feed;
+		self.price_feed = settings.price_feed;
+		self.max_price_age = settings.max_price_age;
+		self.max_confidence_bps = settings.max_confidence_bps;
+		self.pending_price_feed = PriceFeedSettings::default();
+		true
+	}
+
+	/// Queue new oracle settings, replacing any pending change. Switching
+	/// feeds reprices every fare, so it gets the same notice as a fare change.
+	pub fn schedule_price_feed_change(&mut self, price_feed: Pubkey, max_price_age: i64, max_confidence_bps: u16, current_time: i64) {
+		self.apply_due_price_feed_change(current_time);
+		self.pending_price_feed = PriceFeedSettings {
+			price_feed,
+			max_price_age,
+			max_confidence_bps,
+			effective_at: current_time + self.fare_notice(current_time),
+		};
+	}
+
+	/// Minimum fare change notice in force at `current_time`, including a
+	/// queued shorter notice that has taken effect
+	pub fn fare_notice(&self, current_time: i64) -> i64 {
+		if self.min_fare_notice_effective_at != 0 && current_time >= self.min_fare_notice_effective_at {
+			return self.pending_min_fare_notice;
+		}
+		self.min_fare_notice
+	}
+
+	/// Change the minimum fare change notice. Longer notice applies at once;
+	/// shorter notice is queued until the current notice has passed, so no
+	/// fare change can be announced on less notice than riders were promised.
+	pub fn set_fare_notice(&mut self, min_fare_notice: i64, current_time: i64) -> Result<()> {
+		require!(min_fare_notice >= crate::MIN_FARE_NOTICE_FLOOR, FarePaymentError::InvalidFareNotice);
+
+		let current = self.fare_notice(current_time);
+		self.min_fare_notice = current;
+		if min_fare_notice >= current {
+			self.min_fare_notice = min_fare_notice;
+			self.pending_min_fare_notice = 0;
+			self.min_fare_notice_effective_at = 0;
+		} else {
+			self.pending_min_fare_notice = min_fare_notice;
+			self.min_fare_notice_effective_at = current_time + current;
+		}
+		Ok(())
+	}
+
+	/// Drop a pending fare change that has not taken effect
+	pub fn cancel_fare_change(&mut self, current_time: i64) -> Result<()> {
+		require!(self.pending_fares.effective_at != 0, FarePaymentError::NoPendingFareChange);
+		require!(!self.fare_change_due(current_time), FarePaymentError::FareChangeAlreadyEffective);
+		self.pending_fares = FareSchedule::default();
+		Ok(())
+	}
+
+	/// Pause the instruction families in `flags`
+	pub fn pause(&mut self, flags: u8) -> Result<()> {
+		require!(flags != 0 && flags & !crate::ALL_PAUSE_FLAGS == 0, FarePaymentError::InvalidPauseFlags);
+		self.paused |= flags;
+		Ok(())
+	}
+
+	/// Resume the instruction families in `flags`
+	pub fn unpause(&mut self, flags: u8) -> Result<()> {
+		require!(flags != 0 && flags & !crate::ALL_PAUSE_FLAGS == 0, FarePaymentError::InvalidPauseFlags);
+		self.paused &= !flags;
+		Ok(())
+	}
+
+	/// Fail with ProgramPaused if the instruction family `flag` is paused
+	pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+		require!(self.paused & flag == 0, FarePaymentError::ProgramPaused);
+		Ok(())
+	}
+
+	/// Expiry timestamp for a ticket bought at `current_time`
+	pub fn ticket_expiry(&self, current_time: i64) -> i64 {
+		transit_core::ticket_expiry(self.ticket_validity_period, current_time)
+	}
+
+	/// Points earned for paying `amount` at `rate`, including any running promotion bonus
+	pub fn loyalty_points(&self, amount: u64, rate: u16, current_time: i64) -> u64 {
+		transit_core::loyalty_points(
+			amount,
+			rate,
+			self.loyalty_bonus_multiplier,
+			self.loyalty_bonus_start,
+			self.loyalty_bonus_end,
+			current_time,
+		)
+	}
+
+	/// Convert a fare or pass price into base units of `currency_mint`,
+	/// reading the oracle price when fares are denominated in fiat
+	pub fn price_in_tokens(&self, price: u64, price_feed: Option<&AccountInfo>, decimals: u8, current_time: i64) -> Result<u64> {
+		let settings = self.current_price_feed(current_time);
+		if settings.price_feed == Pubkey::default() {
+			return Ok(price);
+		}
+
+		let price_feed = price_feed.ok_or(FarePaymentError::InvalidPriceFeed)?;
+		require_keys_eq!(price_feed.key(), settings.price_feed, FarePaymentError::InvalidPriceFeed);
+
+		let tokens = PriceFeed::unpack(&price_feed.try_borrow_data()?)
+			.and_then(|feed| feed.fiat_to_tokens(price, decimals, current_time, settings.max_price_age, settings.max_confidence_bps))
+			.map_err(FarePaymentError::from)?;
+		Ok(tokens)
+	}
+}
+/// FareConfig layout written before accounts carried a version byte
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FareConfigV0 {
+	pub admin: Pubkey,
+	pub bus_fare: u64,
+	pub train_fare: u64,
+	pub currency_mint: Pubkey,
+	pub total_tickets_sold: u64,
+	pub monthly_pass_price: u64,
+	pub yearly_pass_price: u64,
+	pub total_active_subscriptions: u64,
+	pub bump: u8,
+}
+
+impl FareConfigV0 {
+	/// Serialized size of the fields, without the discriminator
+	pub const LEN: usize = 32 + 8 + 8 + 32 + 8 + 8 + 8 + 8 + 1;
+	/// Largest fare config account the unversioned program allocated
+	pub const MAX_LEN: usize = 145;
+
+	/// Carry the old fields into the current layout, with every newer
+	/// setting at the value initialize_fare_config gives it
+	pub fn upgrade(self, bump: u8) -> FareConfig {
+		FareConfig {
+			version: FareConfig::VERSION,
+			admin: self.admin,
+			treasury: self.admin,
+			bus_fare: self.bus_fare,
+			train_fare: self.train_fare,
+			currency_mint: self.currency_mint,
+			total_tickets_sold: self.total_tickets_sold,
+			monthly_pass_price: self.monthly_pass_price,
+			yearly_pass_price: self.yearly_pass_price,
+			total_active_subscriptions: self.total_active_subscriptions,
+			expiry_crank_reward: 0,
+			cancellation_fee: 0,
+			non_refundable_period: 0,
+			subscription_allowed_modes: crate::ALL_TRANSPORT_MODES,
+			subscription_max_rides_per_day: 0,
+			subscription_max_rides_per_period: 0,
+			loyalty_rates: [0; crate::TRANSPORT_MODE_COUNT],
+			loyalty_subscription_rate: 0,
+			loyalty_bonus_multiplier: crate::BASIS_POINTS as u16,
+			loyalty_bonus_start: 0,
+			loyalty_bonus_end: 0,
+			loyalty_free_ticket_cost: 0,
+			price_feed: Pubkey::default(),
+			max_price_age: 0,
+			max_confidence_bps: 0,
+			pending_price_feed: PriceFeedSettings::default(),
+			ticket_validity_period: 0,
+			payment_retention_period: 0,
+			total_receipts: 0,
+			pending_admin: Pubkey::default(),
+			guardian: Pubkey::default(),
+			paused: 0,
+			min_fare_notice: crate::MIN_FARE_NOTICE_FLOOR,
+			pending_min_fare_notice: 0,
+			min_fare_notice_effective_at: 0,
+			pending_fares: FareSchedule::default(),
+			config_version: 0,
+			bump,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DAY: i64 = crate::MIN_FARE_NOTICE_FLOOR;
+	const NOW: i64 = 1_700_000_000;
+
+	fn config() -> FareConfig {
+		FareConfigV0 {
+			admin: Pubkey::new_unique(),
+			bus_fare: 250,
+			train_fare: 400,
+			currency_mint: Pubkey::new_unique(),
+			total_tickets_sold: 0,
+			monthly_pass_price: 9_000,
+			yearly_pass_price: 90_000,
+			total_active_subscriptions: 0,
+			bump: 255,
+		}
+		.upgrade(255)
+	}
+
+	#[test]
+	fn schedule_needs_the_notice_period() {
+		let mut config = config();
+		assert_eq!(
+			config.schedule_fare_change(Some(300), None, None, None, NOW + DAY - 1, NOW).unwrap_err(),
+			FarePaymentError::FareNoticeTooShort.into()
+		);
+		assert_eq!(
+			config.schedule_fare_change(Some(300), None, None, None, NOW, NOW).unwrap_err(),
+			FarePaymentError::FareNoticeTooShort.into()
+		);
+		config.schedule_fare_change(Some(300), None, None, None, NOW + DAY, NOW).unwrap();
+		assert_eq!(config.pending_fares.effective_at, NOW + DAY);
+	}
+
+	#[test]
+	fn scheduled_fares_apply_once_due() {
+		let mut config = config();
+		config.schedule_fare_change(Some(300), None, Some(10_000), None, NOW + DAY, NOW).unwrap();
+
+		// Unset values carry over the fares in force
+		assert_eq!(config.pending_fares.train_fare, 400);
+		assert_eq!(config.pending_fares.yearly_pass_price, 90_000);
+
+		assert_eq!(config.current_fares(NOW + DAY - 1).bus_fare, 250);
+		assert!(!config.apply_due_fare_change(NOW + DAY - 1));
+
+		// Charged as soon as it is due, before it is applied
+		assert_eq!(config.current_fares(NOW + DAY).bus_fare, 300);
+		assert!(config.apply_due_fare_change(NOW + DAY));
+		assert_eq!(config.bus_fare, 300);
+		assert_eq!(config.monthly_pass_price, 10_000);
+		assert_eq!(config.pending_fares.effective_at, 0);
+		assert!(!config.apply_due_fare_change(NOW + DAY));
+	}
+
+	#[test]
+	fn rescheduling_builds_on_a_due_change() {
+		let mut config = config();
+		config.schedule_fare_change(Some(300), None, None, None, NOW + DAY, NOW).unwrap();
+		config.schedule_fare_change(None, Some(500), None, None, NOW + 3 * DAY, NOW + 2 * DAY).unwrap();
+		assert_eq!(config.bus_fare, 300);
+		assert_eq!(config.pending_fares.bus_fare, 300);
+		assert_eq!(config.pending_fares.train_fare, 500);
+	}
+
+	#[test]
+	fn cancel_only_before_the_change_is_due() {
+		let mut config = config();
+		assert_eq!(config.cancel_fare_change(NOW).unwrap_err(), FarePaymentError::NoPendingFareChange.into());
+
+		config.schedule_fare_change(Some(300), None, None, None, NOW + DAY, NOW).unwrap();
+		assert_eq!(
+			config.cancel_fare_change(NOW + DAY).unwrap_err(),
+			FarePaymentError::FareChangeAlreadyEffective.into()
+		);
+		config.cancel_fare_change(NOW + DAY - 1).unwrap();
+		assert_eq!(config.pending_fares.effective_at, 0);
+		assert_eq!(config.current_fares(NOW + DAY).bus_fare, 250);
+	}
+
+	#[test]
+	fn notice_cannot_go_below_the_floor() {
+		let mut config = config();
+		assert_eq!(config.fare_notice(NOW), DAY);
+		assert_eq!(config.set_fare_notice(DAY - 1, NOW).unwrap_err(), FarePaymentError::InvalidFareNotice.into());
+		assert_eq!(config.set_fare_notice(0, NOW).unwrap_err(), FarePaymentError::InvalidFareNotice.into());
+	}
+
+	#[test]
+	fn longer_notice_applies_at_once_and_shorter_is_queued() {
+		let mut config = config();
+		config.set_fare_notice(7 * DAY, NOW).unwrap();
+		assert_eq!(config.fare_notice(NOW), 7 * DAY);
+
+		config.set_fare_notice(2 * DAY, NOW).unwrap();
+		assert_eq!(config.fare_notice(NOW + 7 * DAY - 1), 7 * DAY);
+		assert_eq!(config.fare_notice(NOW + 7 * DAY), 2 * DAY);
+		assert_eq!(
+			config.schedule_fare_change(Some(300), None, None, None, NOW + 2 * DAY, NOW).unwrap_err(),
+			FarePaymentError::FareNoticeTooShort.into()
+		);
+	}
+
+	#[test]
+	fn price_feed_change_waits_for_the_notice() {
+		let mut config = config();
+		let feed = Pubkey::new_unique();
+		config.schedule_price_feed_change(feed, 60, 100, NOW);
+		assert_eq!(config.current_price_feed(NOW + DAY - 1).price_feed, Pubkey::default());
+		assert_eq!(config.current_price_feed(NOW + DAY).price_feed, feed);
+		assert!(config.apply_due_price_feed_change(NOW + DAY));
+		assert_eq!(config.price_feed, feed);
+		assert_eq!(config.max_price_age, 60);
+		assert_eq!(config.pending_price_feed.effective_at, 0);
+	}
+
+	#[test]
+	fn pause_and_unpause_flags() {
+		let mut config = config();
+		config.pause(crate::PAUSE_TICKET_SALES | crate::PAUSE_REFUNDS).unwrap();
+		assert_eq!(config.require_not_paused(crate::PAUSE_REFUNDS).unwrap_err(), FarePaymentError::ProgramPaused.into());
+		config.unpause(crate::PAUSE_REFUNDS).unwrap();
+		assert!(config.require_not_paused(crate::PAUSE_REFUNDS).is_ok());
+		assert_eq!(config.paused, crate::PAUSE_TICKET_SALES);
+		assert_eq!(config.pause(0).unwrap_err(), FarePaymentError::InvalidPauseFlags.into());
+		assert_eq!(config.pause(1 << 7).unwrap_err(), FarePaymentError::InvalidPauseFlags.into());
+	}
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct GiftCard {
+	pub version: u8,               // Account layout version, GiftCard::VERSION once initialized
+	pub issuer: Pubkey,            // Admin or retail partner that funded the card
+	pub claim_key: Pubkey,         // Key derived from the secret claim code, signs the redemption
+	pub value: u64,                // Tokens held in the gift card escrow
+	pub currency_mint: Pubkey,
+	pub expires_at: i64,           // After this the issuer may reclaim the value
+	pub bump: u8,
+}
+
+impl GiftCard {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RetailPartner {
+	pub version: u8,               // Account layout version, RetailPartner::VERSION once initialized
+	pub partner: Pubkey,           // Wallet allowed to issue gift cards
+	pub bump: u8,
+}
+
+impl RetailPartner {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+
+use crate::{FarePaymentError, MAX_HOUSEHOLD_MEMBERS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct HouseholdMember {
+	pub wallet: Pubkey,
+	pub rides_used: u32,    // Rides used in the current subscription period
+	pub ride_day: i64,      // Day index (unix_timestamp / 86400) of rides_today
+	pub rides_today: u32,   // Rides used on ride_day
+	pub removed: bool,      // Taken off the pass, kept until the period rolls over so re-adding can't reset the counters
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Household {
+	pub version: u8,               // Account layout version, Household::VERSION once initialized
+	pub primary: Pubkey,              // Wallet of the passenger holding the subscription
+	pub subscription_start: i64,      // Start of the pass the member ride counts belong to
+	#[max_len(MAX_HOUSEHOLD_MEMBERS)]
+	pub members: Vec<HouseholdMember>, // Members besides the primary
+	pub bump: u8,
+}
+
+impl Household {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Position of `wallet` among the members currently on the pass
+	pub fn member_index(&self, wallet: &Pubkey) -> Option<usize> {
+		self.members.iter().position(|member| member.wallet == *wallet && !member.removed)
+	}
+
+	/// Members currently on the pass
+	pub fn active_members(&self) -> usize {
+		self.members.iter().filter(|member| !member.removed).count()
+	}
+
+	/// Put `wallet` on a pass with `seats` seats, one of which is the primary's
+	pub fn add_member(&mut self, wallet: Pubkey, seats: u8) -> Result<()> {
+		// The primary already holds a seat and can't be added twice
+		require!(
+			wallet != self.primary && self.member_index(&wallet).is_none(),
+			FarePaymentError::HouseholdMemberExists
+		);
+
+		// Members can only fill the seats left after the primary's own
+		require!(self.active_members() + 1 < seats as usize, FarePaymentError::HouseholdFull);
+
+		// A member removed earlier in the period comes back with the rides
+		// already used, so removing and re-adding can't reset the caps
+		if let Some(entry) = self.members.iter_mut().find(|entry| entry.wallet == wallet) {
+			entry.removed = false;
+		} else if self.members.len() < MAX_HOUSEHOLD_MEMBERS as usize {
+			self.members.push(HouseholdMember {
+				wallet,
+				..Default::default()
+			});
+		} else {
+			// Every slot has been used this period; hand a removed member's slot
+			// over with its ride counts, so swapping wallets can't reset them either
+			let entry = self
+				.members
+				.iter_mut()
+				.find(|entry| entry.removed)
+				.ok_or(FarePaymentError::HouseholdFull)?;
+			entry.wallet = wallet;
+			entry.removed = false;
+		}
+		Ok(())
+	}
+
+	/// Take `wallet` off the pass. Rides it already took this period stay
+	/// counted against the wallet.
+	pub fn remove_member(&mut self, wallet: &Pubkey) -> Result<()> {
+		let index = self.member_index(wallet).ok_or(FarePaymentError::NotHouseholdMember)?;
+		let entry = &mut self.members[index];
+		if entry.rides_used == 0 && entry.rides_today == 0 {
+			self.members.remove(index);
+		} else {
+			entry.removed = true;
+		}
+		Ok(())
+	}
+
+	/// Member ride counts belong to a single pass; start over and forget
+	/// removed members when a new one is bought
+	pub fn start_period(&mut self, subscription_start: i64) {
+		if self.subscription_start == subscription_start {
+			return;
+		}
+		self.subscription_start = subscription_start;
+		self.members.retain(|member| !member.removed);
+		for member in self.members.iter_mut() {
+			member.rides_used = 0;
+			member.ride_day = 0;
+			member.rides_today = 0;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn household() -> Household {
+		Household {
+			version: Household::VERSION,
+			primary: Pubkey::new_unique(),
+			subscription_start: 1_000,
+			members: Vec::new(),
+			bump: 255,
+		}
+	}
+
+	#[test]
+	fn primary_holds_a_seat() {
+		let mut household = household();
+		let primary = household.primary;
+		assert_eq!(household.add_member(primary, 3).unwrap_err(), FarePaymentError::HouseholdMemberExists.into());
+		assert_eq!(household.add_member(Pubkey::new_unique(), 1).unwrap_err(), FarePaymentError::HouseholdFull.into());
+
+		household.add_member(Pubkey::new_unique(), 2).unwrap();
+		assert_eq!(household.add_member(Pubkey::new_unique(), 2).unwrap_err(), FarePaymentError::HouseholdFull.into());
+		assert_eq!(household.active_members(), 1);
+	}
+
+	#[test]
+	fn member_cannot_be_added_twice() {
+		let mut household = household();
+		let member = Pubkey::new_unique();
+		household.add_member(member, 3).unwrap();
+		assert_eq!(household.add_member(member, 3).unwrap_err(), FarePaymentError::HouseholdMemberExists.into());
+	}
+
+	#[test]
+	fn removed_member_frees_the_seat_but_keeps_its_rides() {
+		let mut household = household();
+		let member = Pubkey::new_unique();
+		household.add_member(member, 2).unwrap();
+		household.members[0].rides_used = 4;
+
+		household.remove_member(&member).unwrap();
+		assert_eq!(household.active_members(), 0);
+		assert_eq!(household.member_index(&member), None);
+		assert_eq!(household.remove_member(&member).unwrap_err(), FarePaymentError::NotHouseholdMember.into());
+
+		household.add_member(member, 2).unwrap();
+		assert_eq!(household.members.len(), 1);
+		assert_eq!(household.members[0].rides_used, 4);
+	}
+
+	#[test]
+	fn member_without_rides_is_dropped() {
+		let mut household = household();
+		let member = Pubkey::new_unique();
+		household.add_member(member, 2).unwrap();
+		household.remove_member(&member).unwrap();
+		assert!(household.members.is_empty());
+	}
+
+	#[test]
+	fn full_list_reuses_a_removed_slot_with_its_rides() {
+		let mut household = household();
+		let seats = MAX_HOUSEHOLD_MEMBERS + 1;
+		let members: Vec<Pubkey> = (0..MAX_HOUSEHOLD_MEMBERS).map(|_| Pubkey::new_unique()).collect();
+		for member in &members {
+			household.add_member(*member, seats).unwrap();
+		}
+		household.members[0].rides_used = 7;
+		household.remove_member(&members[0]).unwrap();
+
+		let newcomer = Pubkey::new_unique();
+		household.add_member(newcomer, seats).unwrap();
+		assert_eq!(household.members.len(), MAX_HOUSEHOLD_MEMBERS as usize);
+		assert_eq!(household.members[0].wallet, newcomer);
+		assert_eq!(household.members[0].rides_used, 7);
+		assert_eq!(household.add_member(Pubkey::new_unique(), seats + 1).unwrap_err(), FarePaymentError::HouseholdFull.into());
+	}
+
+	#[test]
+	fn new_period_resets_counts_and_forgets_removed_members() {
+		let mut household = household();
+		let kept = Pubkey::new_unique();
+		let removed = Pubkey::new_unique();
+		household.add_member(kept, 3).unwrap();
+		household.add_member(removed, 3).unwrap();
+		household.members[0].rides_used = 2;
+		household.members[1].rides_today = 1;
+		household.remove_member(&removed).unwrap();
+
+		household.start_period(1_000);
+		assert_eq!(household.members.len(), 2);
+
+		household.start_period(2_000);
+		assert_eq!(household.members.len(), 1);
+		assert_eq!(household.members[0].wallet, kept);
+		assert_eq!(household.members[0].rides_used, 0);
+	}
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::FarePaymentError;
+
+/// Read an account written before layouts were versioned
+///
+/// Unversioned accounts are recognised by size: every versioned layout is
+/// larger than `legacy_max_len`, the most the old program ever allocated.
+/// Accounts that were allocated short are zero-filled up to `legacy_len`
+/// before decoding, matching what the old program would have read.
+pub fn read_legacy<T: AnchorDeserialize>(account: &AccountInfo, discriminator: [u8; 8], current_version: u8, legacy_len: usize, legacy_max_len: usize) -> Result<T> {
+	let data = account.try_borrow_data()?;
+	require!(data.len() >= 8 && data[..8] == discriminator, FarePaymentError::InvalidAccountData);
+
+	if data.len() > legacy_max_len {
+		// The version byte directly follows the discriminator
+		require!(data[8] != current_version, FarePaymentError::AccountAlreadyMigrated);
+		return err!(FarePaymentError::UnsupportedAccountVersion);
+	}
+
+	let mut padded = data[8..].to_vec();
+	padded.resize(legacy_len.max(padded.len()), 0);
+	Ok(T::deserialize(&mut &padded[..])?)
+}
+
+/// Grow `account` to `new_len` bytes, topping its rent up from `payer`
+pub fn grow_account<'info>(account: &AccountInfo<'info>, new_len: usize, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+	let shortfall = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+	if shortfall > 0 {
+		transfer(
+			CpiContext::new(
+				system_program.clone(),
+				Transfer {
+					from: payer.clone(),
+					to: account.clone(),
+				},
+			),
+			shortfall,
+		)?;
+	}
+	account.realloc(new_len, true)?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
+	struct Legacy {
+		owner: Pubkey,
+		count: u64,
+		bump: u8,
+	}
+
+	const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+	const LEN: usize = 32 + 8 + 1;
+	const MAX_LEN: usize = 8 + LEN + 8;
+
+	fn read(mut data: Vec<u8>) -> Result<Legacy> {
+		let key = Pubkey::new_unique();
+		let owner = crate::ID;
+		let mut lamports = 0;
+		let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+		read_legacy(&account, DISCRIMINATOR, 1, LEN, MAX_LEN)
+	}
+
+	fn account(legacy: &Legacy) -> Vec<u8> {
+		let mut data = DISCRIMINATOR.to_vec();
+		legacy.serialize(&mut data).unwrap();
+		data
+	}
+
+	#[test]
+	fn reads_the_legacy_layout() {
+		let legacy = Legacy { owner: Pubkey::new_unique(), count: 42, bump: 254 };
+		let mut data = account(&legacy);
+		assert_eq!(read(data.clone()).unwrap(), legacy);
+
+		// Slack the old program allocated past the fields is ignored
+		data.resize(MAX_LEN, 0);
+		assert_eq!(read(data).unwrap(), legacy);
+	}
+
+	#[test]
+	fn short_accounts_are_zero_filled() {
+		let legacy = Legacy { owner: Pubkey::new_unique(), count: 0, bump: 0 };
+		let mut data = account(&legacy);
+		data.truncate(8 + 32);
+		assert_eq!(read(data).unwrap(), legacy);
+	}
+
+	#[test]
+	fn rejects_other_accounts() {
+		let mut data = account(&Legacy { owner: Pubkey::new_unique(), count: 1, bump: 1 });
+		data[0] = 0;
+		assert_eq!(read(data).unwrap_err(), FarePaymentError::InvalidAccountData.into());
+		assert_eq!(read(vec![1, 2, 3]).unwrap_err(), FarePaymentError::InvalidAccountData.into());
+	}
+
+	#[test]
+	fn versioned_accounts_are_not_legacy() {
+		let mut data = DISCRIMINATOR.to_vec();
+		data.resize(MAX_LEN + 1, 0);
+		data[8] = 1;
+		assert_eq!(read(data.clone()).unwrap_err(), FarePaymentError::AccountAlreadyMigrated.into());
+		data[8] = 2;
+		assert_eq!(read(data).unwrap_err(), FarePaymentError::UnsupportedAccountVersion.into());
+	}
+}
@@ -2,8 +2,26 @@ pub mod fare_config;
 pub mod passenger;
 pub mod ticket;
 pub mod payment;
+pub mod household;
+pub mod employer;
+pub mod promotion;
+pub mod gift_card;
+pub mod accepted_currency;
+pub mod ride_history;
+pub mod roles;
+pub mod multisig;
+pub mod migration;
 
 pub use fare_config::*;
 pub use passenger::*;
 pub use ticket::*;
 pub use payment::*;
+pub use household::*;
+pub use employer::*;
+pub use promotion::*;
+pub use gift_card::*;
+pub use accepted_currency::*;
+pub use ride_history::*;
+pub use roles::*;
+pub use multisig::*;
+pub use migration::*;
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+
+use crate::{FarePaymentError, MAX_MULTISIG_OWNERS};
+
+/// M-of-N set of owners that approves privileged actions
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+	pub version: u8,               // Account layout version, Multisig::VERSION once initialized
+	#[max_len(MAX_MULTISIG_OWNERS)]
+	pub owners: Vec<Pubkey>,     // Fixed at creation
+	pub threshold: u8,           // Approvals needed to execute a proposal
+	pub proposal_count: u64,     // Proposals created so far, seeds the next proposal
+	pub bump: u8,
+}
+
+/// Privileged action awaiting multisig approval
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+	pub version: u8,               // Account layout version, Proposal::VERSION once initialized
+	pub proposal_id: u64,
+	pub proposer: Pubkey,
+	pub action: MultisigAction,
+	pub approvals: u16,          // Bit i set once owners[i] has approved
+	pub executed: bool,
+	pub created_at: i64,
+	pub bump: u8,
+}
+
+/// Actions a multisig proposal can carry out once approved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum MultisigAction {
+	/// Become admin after the current admin proposed the multisig with propose_admin
+	AcceptAdmin,
+	/// Start handing the admin role to another account
+	ProposeAdmin { new_admin: Pubkey },
+	/// Assign a privileged role, see set_role
+	SetRole { role: u8, holder: Pubkey },
+	/// Queue new fares, see schedule_fare_change
+	ScheduleFareChange {
+		mode_0_fare: Option<u64>,
+		mode_1_fare: Option<u64>,
+		monthly_pass_price: Option<u64>,
+		yearly_pass_price: Option<u64>,
+		effective_at: i64,
+	},
+	/// Cancel a queued fare change
+	CancelFareChange,
+	/// Move funds out of a treasury token account owned by the multisig
+	Withdraw { treasury: Pubkey, destination: Pubkey, amount: u64 },
+	/// Resume paused instruction families, see unpause
+	Unpause { flags: u8 },
+	/// Set the key allowed to pause the program, see set_guardian
+	SetGuardian { guardian: Pubkey },
+	/// Change the minimum fare change notice, see set_fare_notice_period
+	SetFareNoticePeriod { min_fare_notice: i64 },
+	/// Pause instruction families, see pause
+	Pause { flags: u8 },
+}
+
+impl MultisigAction {
+	/// Whether the multisig must be the admin to execute the action.
+	/// AcceptAdmin is how it becomes admin, and Withdraw only moves funds
+	/// out of token accounts the multisig itself owns.
+	pub fn requires_admin(&self) -> bool {
+		!matches!(self, MultisigAction::AcceptAdmin | MultisigAction::Withdraw { .. })
+	}
+}
+
+impl Multisig {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Check the owner list and threshold for a new multisig
+	pub fn validate(owners: &[Pubkey], threshold: u8) -> Result<()> {
+		require!(
+			!owners.is_empty() && owners.len() <= MAX_MULTISIG_OWNERS,
+			FarePaymentError::InvalidMultisig
+		);
+		require!(threshold >= 1 && threshold as usize <= owners.len(), FarePaymentError::InvalidMultisig);
+		for (i, owner) in owners.iter().enumerate() {
+			require!(!owners[..i].contains(owner), FarePaymentError::InvalidMultisig);
+		}
+		Ok(())
+	}
+
+	/// Position of `owner` in the owner list
+	pub fn owner_index(&self, owner: &Pubkey) -> Result<usize> {
+		self.owners
+			.iter()
+			.position(|o| o == owner)
+			.ok_or_else(|| FarePaymentError::NotMultisigOwner.into())
+	}
+}
+
+impl Proposal {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Record the approval of the owner at `index`
+	pub fn approve(&mut self, index: usize) -> Result<()> {
+		require!(!self.executed, FarePaymentError::ProposalAlreadyExecuted);
+		let bit = 1u16 << index;
+		require!(self.approvals & bit == 0, FarePaymentError::ProposalAlreadyApproved);
+		self.approvals |= bit;
+		Ok(())
+	}
+
+	/// Whether enough owners have approved to execute
+	pub fn is_approved(&self, threshold: u8) -> bool {
+		self.approvals.count_ones() >= threshold as u32
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn proposal() -> Proposal {
+		Proposal {
+			version: Proposal::VERSION,
+			proposal_id: 0,
+			proposer: Pubkey::new_unique(),
+			action: MultisigAction::CancelFareChange,
+			approvals: 0,
+			executed: false,
+			created_at: 0,
+			bump: 255,
+		}
+	}
+
+	#[test]
+	fn validate_owners_and_threshold() {
+		let owners = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+		assert!(Multisig::validate(&owners, 1).is_ok());
+		assert!(Multisig::validate(&owners, 3).is_ok());
+		assert_eq!(Multisig::validate(&owners, 0).unwrap_err(), FarePaymentError::InvalidMultisig.into());
+		assert_eq!(Multisig::validate(&owners, 4).unwrap_err(), FarePaymentError::InvalidMultisig.into());
+		assert_eq!(Multisig::validate(&[], 1).unwrap_err(), FarePaymentError::InvalidMultisig.into());
+		assert_eq!(
+			Multisig::validate(&[owners[0], owners[1], owners[0]], 2).unwrap_err(),
+			FarePaymentError::InvalidMultisig.into()
+		);
+		let too_many = [Pubkey::new_unique(); MAX_MULTISIG_OWNERS + 1];
+		assert_eq!(Multisig::validate(&too_many, 1).unwrap_err(), FarePaymentError::InvalidMultisig.into());
+	}
+
+	#[test]
+	fn owner_index_finds_owners_only() {
+		let owners = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+		let multisig = Multisig { version: Multisig::VERSION, owners: owners.clone(), threshold: 2, proposal_count: 0, bump: 255 };
+		assert_eq!(multisig.owner_index(&owners[1]).unwrap(), 1);
+		assert_eq!(multisig.owner_index(&Pubkey::new_unique()).unwrap_err(), FarePaymentError::NotMultisigOwner.into());
+	}
+
+	#[test]
+	fn approvals_reach_threshold() {
+		let mut proposal = proposal();
+		assert!(!proposal.is_approved(2));
+
+		proposal.approve(0).unwrap();
+		assert!(proposal.is_approved(1));
+		assert!(!proposal.is_approved(2));
+
+		proposal.approve(2).unwrap();
+		assert!(proposal.is_approved(2));
+		assert!(!proposal.is_approved(3));
+	}
+
+	#[test]
+	fn owner_cannot_approve_twice() {
+		let mut proposal = proposal();
+		proposal.approve(1).unwrap();
+		assert_eq!(proposal.approve(1).unwrap_err(), FarePaymentError::ProposalAlreadyApproved.into());
+		assert!(!proposal.is_approved(2));
+	}
+
+	#[test]
+	fn executed_proposal_takes_no_approvals() {
+		let mut proposal = proposal();
+		proposal.executed = true;
+		assert_eq!(proposal.approve(0).unwrap_err(), FarePaymentError::ProposalAlreadyExecuted.into());
+	}
+
+	#[test]
+	fn admin_required_except_accept_admin_and_withdraw() {
+		assert!(!MultisigAction::AcceptAdmin.requires_admin());
+		let withdraw = MultisigAction::Withdraw {
+			treasury: Pubkey::new_unique(),
+			destination: Pubkey::new_unique(),
+			amount: 1,
+		};
+		assert!(!withdraw.requires_admin());
+		assert!(MultisigAction::Pause { flags: 1 }.requires_admin());
+		assert!(MultisigAction::CancelFareChange.requires_admin());
+	}
+}
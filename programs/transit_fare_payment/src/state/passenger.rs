@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::{FareConfig, Payment};
+
 #[account]
+#[derive(Default, InitSpace)]
 pub struct Passenger {
+	pub version: u8,               // Account layout version, Passenger::VERSION once initialized
 	pub user: Pubkey,
 	pub total_spent: u64,
 	pub ticket_count: u32,
@@ -11,5 +15,143 @@ pub struct Passenger {
 	pub subscription_start: i64,        // Subscription start timestamp
 	pub subscription_end: i64,          // Subscription expiry timestamp
 	pub subscription_rides_used: u32,   // Rides used in current subscription period
+	pub subscription_price_paid: u64,   // Amount actually paid for the current subscription
+	pub subscription_currency_mint: Pubkey, // Mint the current subscription was paid in
+	pub subscription_allowed_modes: u8,         // Bitmask of transport modes the pass is valid on
+	pub subscription_max_rides_per_day: u32,    // Daily ride cap, 0 = unlimited
+	pub subscription_max_rides_per_period: u32, // Ride cap for the whole pass, 0 = unlimited
+	pub subscription_ride_day: i64,             // Day index (unix_timestamp / 86400) of subscription_rides_today
+	pub subscription_rides_today: u32,          // Rides used on subscription_ride_day
+	pub subscription_rides_by_mode: [u32; 2],   // Rides used per transport mode in current period
+	pub subscription_seats: u8,                 // Riders covered by the pass, including the holder
+	pub subscription_sponsor_escrow: Pubkey,    // Employer escrow that paid for the pass, default if self-paid
+	// Stored-value wallet
+	pub stored_value_balance: u64,      // Tokens held in the passenger's stored-value escrow
+	pub low_balance_threshold: u64,     // Balance under which clients should prompt a top-up
+	// Loyalty program
+	pub loyalty_points: u64,               // Unredeemed loyalty points
+	pub pending_pass_discount: u64,        // Redeemed discount off the next pass, in fare units like the pass price
+	pub subscription_points_awarded: u64,  // Points earned by the current pass, clawed back on cancel
+	pub payment_count: u64,                // Id of the next payment receipt, never reused for the wallet
+	pub outstanding_tickets: u32,          // Unused tickets that have not been refunded or expired
+	pub bump: u8,
+}
+
+impl Passenger {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Whether the stored-value balance has dropped below the rider's threshold
+	pub fn is_low_balance(&self) -> bool {
+		self.stored_value_balance < self.low_balance_threshold
+	}
+
+	/// Reset every subscription field back to "no subscription"
+	pub fn clear_subscription(&mut self) {
+		self.subscription_type = 0;
+		self.subscription_start = 0;
+		self.subscription_end = 0;
+		self.subscription_rides_used = 0;
+		self.subscription_price_paid = 0;
+		self.subscription_currency_mint = Pubkey::default();
+		self.subscription_allowed_modes = 0;
+		self.subscription_max_rides_per_day = 0;
+		self.subscription_max_rides_per_period = 0;
+		self.subscription_ride_day = 0;
+		self.subscription_rides_today = 0;
+		self.subscription_rides_by_mode = [0; crate::TRANSPORT_MODE_COUNT];
+		self.subscription_seats = 0;
+		self.subscription_sponsor_escrow = Pubkey::default();
+		self.subscription_points_awarded = 0;
+	}
+
+	/// Start a new pass, snapshotting the ride rules so later config changes don't affect it
+	pub fn start_subscription(&mut self, fare_config: &FareConfig, subscription_type: u8, duration_seconds: i64, seats: u8, current_time: i64) {
+		self.clear_subscription();
+		self.subscription_type = subscription_type;
+		self.subscription_start = current_time;
+		self.subscription_end = current_time + duration_seconds;
+		self.subscription_allowed_modes = fare_config.subscription_allowed_modes;
+		self.subscription_max_rides_per_day = fare_config.subscription_max_rides_per_day;
+		self.subscription_max_rides_per_period = fare_config.subscription_max_rides_per_period;
+		self.subscription_seats = seats;
+	}
+
+	/// Id the next receipt is seeded with. A new account continues after
+	/// every receipt written so far, so a rider who closed their account and
+	/// registered again can't collide with receipts that outlived it.
+	pub fn next_payment_id(&self, fare_config: &FareConfig) -> u64 {
+		if self.version == 0 {
+			fare_config.total_receipts
+		} else {
+			self.payment_count
+		}
+	}
+
+	/// Fill in the next payment receipt and advance the receipt counters
+	pub fn record_payment(&mut self, fare_config: &mut FareConfig, payment: &mut Payment, product: u8, reference: Pubkey, amount: u64, currency_mint: Pubkey, bump: u8) -> Result<()> {
+		let clock = Clock::get()?;
+		*payment = Payment {
+			version: Payment::VERSION,
+			user: self.user,
+			payment_id: self.payment_count,
+			product,
+			reference,
+			amount,
+			currency_mint,
+			slot: clock.slot,
+			payment_timestamp: clock.unix_timestamp,
+			rent_payer: Pubkey::default(),
+			bump,
+		};
+		self.payment_count += 1;
+		fare_config.total_receipts += 1;
+		Ok(())
+	}
+}
+/// Passenger layout written before accounts carried a version byte
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PassengerV0 {
+	pub user: Pubkey,
+	pub total_spent: u64,
+	pub ticket_count: u32,
+	pub last_ticket_timestamp: i64,
+	pub subscription_type: u8,
+	pub subscription_start: i64,
+	pub subscription_end: i64,
+	pub subscription_rides_used: u32,
 	pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl PassengerV0 {
+	/// Serialized size of the fields, without the discriminator
+	pub const LEN: usize = 32 + 8 + 4 + 8 + 1 + 8 + 8 + 4 + 1;
+	/// Largest passenger account the unversioned program allocated
+	pub const MAX_LEN: usize = 109;
+
+	/// Carry the old fields into the current layout. A pass bought before
+	/// ride rules existed stays valid on every mode for a single rider, and
+	/// refunds at the price the old program would have used.
+	pub fn upgrade(self, fare_config: &FareConfig, current_time: i64, bump: u8) -> Result<Passenger> {
+		let mut passenger = Passenger {
+			version: Passenger::VERSION,
+			user: self.user,
+			total_spent: self.total_spent,
+			ticket_count: self.ticket_count,
+			last_ticket_timestamp: self.last_ticket_timestamp,
+			subscription_type: self.subscription_type,
+			subscription_start: self.subscription_start,
+			subscription_end: self.subscription_end,
+			subscription_rides_used: self.subscription_rides_used,
+			bump,
+			..Default::default()
+		};
+		if self.subscription_type != 0 {
+			passenger.subscription_price_paid = fare_config.current_fares(current_time).pass(self.subscription_type, 1)?.0;
+			passenger.subscription_currency_mint = fare_config.currency_mint;
+			passenger.subscription_allowed_modes = crate::ALL_TRANSPORT_MODES;
+			passenger.subscription_seats = 1;
+		}
+		Ok(passenger)
+	}
+}
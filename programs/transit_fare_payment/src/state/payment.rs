@@ -1,7 +1,30 @@
 use anchor_lang::prelude::*;
 
+/// Receipt written by the fare instructions for every fare payment
 #[account]
+#[derive(InitSpace)]
 pub struct Payment {
+	pub version: u8,               // Account layout version, Payment::VERSION once initialized
+	pub user: Pubkey,
+	pub payment_id: u64,        // Index of the payment in the passenger's history
+	pub product: u8,            // PAYMENT_PRODUCT_* value
+	pub reference: Pubkey,      // Ticket for ticket purchases, passenger account otherwise
+	pub amount: u64,            // Amount actually transferred
+	pub currency_mint: Pubkey,  // Mint the amount was transferred in
+	pub slot: u64,
+	pub payment_timestamp: i64,
+	pub rent_payer: Pubkey,     // Fee payer that funded the account, refunded on close
+	pub bump: u8,
+}
+
+impl Payment {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
+
+/// Payment layout written by the unversioned program's record_payment
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PaymentV0 {
 	pub user: Pubkey,
 	pub payment_id: u64,
 	pub amount: u64,
@@ -9,4 +32,30 @@ pub struct Payment {
 	pub payment_timestamp: i64,
 	pub transaction_hash: String,
 	pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl PaymentV0 {
+	/// Serialized size of the fields with an empty transaction hash, without the discriminator
+	pub const LEN: usize = 32 + 8 + 8 + 32 + 8 + 4 + 1;
+	/// Largest payment account the unversioned program allocated
+	pub const MAX_LEN: usize = 100;
+
+	/// Carry the old fields into the current layout. The rider reported these
+	/// payments themselves, so they keep their own product and reference
+	/// nothing; the rent goes back to the rider on close.
+	pub fn upgrade(self, bump: u8) -> Payment {
+		Payment {
+			version: Payment::VERSION,
+			user: self.user,
+			payment_id: self.payment_id,
+			product: crate::PAYMENT_PRODUCT_RECORDED,
+			reference: Pubkey::default(),
+			amount: self.amount,
+			currency_mint: self.currency_mint,
+			slot: 0,
+			payment_timestamp: self.payment_timestamp,
+			rent_payer: self.user,
+			bump,
+		}
+	}
+}
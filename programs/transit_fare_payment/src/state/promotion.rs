@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+
+use crate::FarePaymentError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Promotion {
+	pub version: u8,               // Account layout version, Promotion::VERSION once initialized
+	pub code_hash: [u8; 32],       // sha256 of the promo code riders enter
+	pub discount_type: u8,         // 0 = percentage, 1 = fixed amount, 2 = free ride
+	pub discount_value: u64,       // Basis points for percentage, fare units for fixed
+	pub applicable_modes: u8,      // Bitmask of transport modes (1 << mode)
+	pub applicable_products: u8,   // Bitmask of PROMO_PRODUCT_* values
+	pub valid_from: i64,           // Start of the validity window, inclusive
+	pub valid_until: i64,          // End of the validity window, exclusive
+	pub max_redemptions: u32,      // Global redemption limit, 0 = unlimited
+	pub redemptions: u32,          // Redemptions so far
+	pub max_per_user: u32,         // Redemptions allowed per rider, 0 = unlimited
+	pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PromotionRedemption {
+	pub version: u8,               // Account layout version, PromotionRedemption::VERSION once initialized
+	pub promotion: Pubkey,
+	pub user: Pubkey,              // Rider who redeemed the promotion
+	pub count: u32,                // Times this rider has redeemed it
+	pub bump: u8,
+}
+
+impl PromotionRedemption {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
+
+impl Promotion {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Redeem the promotion for `product` covering `modes`, returning the discounted price
+	pub fn redeem(
+		&mut self,
+		redemption: &mut PromotionRedemption,
+		code: &[u8],
+		product: u8,
+		modes: u8,
+		price: u64,
+		current_time: i64,
+	) -> Result<u64> {
+		require!(
+			anchor_lang::solana_program::hash::hash(code).to_bytes() == self.code_hash,
+			FarePaymentError::InvalidPromoCode
+		);
+		require!(
+			current_time >= self.valid_from && current_time < self.valid_until,
+			FarePaymentError::PromotionNotActive
+		);
+		require!(
+			self.applicable_products & product != 0 && modes & !self.applicable_modes == 0,
+			FarePaymentError::PromotionNotApplicable
+		);
+		require!(
+			self.max_redemptions == 0 || self.redemptions < self.max_redemptions,
+			FarePaymentError::PromotionLimitReached
+		);
+		require!(
+			self.max_per_user == 0 || redemption.count < self.max_per_user,
+			FarePaymentError::PromotionLimitReached
+		);
+
+		let discounted = transit_core::promo_price(price, self.discount_type, self.discount_value);
+
+		self.redemptions += 1;
+		redemption.count += 1;
+
+		Ok(discounted)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const CODE: &[u8] = b"SPRING25";
+	const BUS: u8 = 1 << crate::TRANSPORT_MODE_BUS;
+	const TRAIN: u8 = 1 << crate::TRANSPORT_MODE_TRAIN;
+
+	fn promotion() -> Promotion {
+		Promotion {
+			version: Promotion::VERSION,
+			code_hash: anchor_lang::solana_program::hash::hash(CODE).to_bytes(),
+			discount_type: crate::PROMO_DISCOUNT_PERCENTAGE,
+			discount_value: 2_500,
+			applicable_modes: BUS,
+			applicable_products: crate::PROMO_PRODUCT_TICKET,
+			valid_from: 100,
+			valid_until: 200,
+			max_redemptions: 0,
+			redemptions: 0,
+			max_per_user: 0,
+			bump: 255,
+		}
+	}
+
+	fn redemption() -> PromotionRedemption {
+		PromotionRedemption {
+			version: PromotionRedemption::VERSION,
+			promotion: Pubkey::new_unique(),
+			user: Pubkey::new_unique(),
+			count: 0,
+			bump: 255,
+		}
+	}
+
+	#[test]
+	fn redeem_discounts_and_counts() {
+		let mut promotion = promotion();
+		let mut redemption = redemption();
+		let price = promotion.redeem(&mut redemption, CODE, crate::PROMO_PRODUCT_TICKET, BUS, 400, 150).unwrap();
+		assert_eq!(price, 300);
+		assert_eq!(promotion.redemptions, 1);
+		assert_eq!(redemption.count, 1);
+	}
+
+	#[test]
+	fn redeem_checks_code_window_and_scope() {
+		let mut promotion = promotion();
+		let mut redemption = redemption();
+		let ticket = crate::PROMO_PRODUCT_TICKET;
+		assert_eq!(
+			promotion.redeem(&mut redemption, b"WRONG", ticket, BUS, 400, 150).unwrap_err(),
+			FarePaymentError::InvalidPromoCode.into()
+		);
+		assert_eq!(
+			promotion.redeem(&mut redemption, CODE, ticket, BUS, 400, 99).unwrap_err(),
+			FarePaymentError::PromotionNotActive.into()
+		);
+		assert_eq!(
+			promotion.redeem(&mut redemption, CODE, ticket, BUS, 400, 200).unwrap_err(),
+			FarePaymentError::PromotionNotActive.into()
+		);
+		assert_eq!(
+			promotion.redeem(&mut redemption, CODE, ticket, TRAIN, 400, 150).unwrap_err(),
+			FarePaymentError::PromotionNotApplicable.into()
+		);
+		assert_eq!(
+			promotion.redeem(&mut redemption, CODE, crate::PROMO_PRODUCT_MONTHLY_PASS, BUS, 400, 150).unwrap_err(),
+			FarePaymentError::PromotionNotApplicable.into()
+		);
+		assert_eq!(promotion.redemptions, 0);
+		assert_eq!(redemption.count, 0);
+	}
+
+	#[test]
+	fn redeem_enforces_global_and_per_user_limits() {
+		let mut promotion = promotion();
+		promotion.max_redemptions = 2;
+		promotion.max_per_user = 1;
+		let ticket = crate::PROMO_PRODUCT_TICKET;
+
+		let mut first = redemption();
+		promotion.redeem(&mut first, CODE, ticket, BUS, 400, 150).unwrap();
+		assert_eq!(
+			promotion.redeem(&mut first, CODE, ticket, BUS, 400, 150).unwrap_err(),
+			FarePaymentError::PromotionLimitReached.into()
+		);
+
+		let mut second = redemption();
+		promotion.redeem(&mut second, CODE, ticket, BUS, 400, 150).unwrap();
+		let mut third = redemption();
+		assert_eq!(
+			promotion.redeem(&mut third, CODE, ticket, BUS, 400, 150).unwrap_err(),
+			FarePaymentError::PromotionLimitReached.into()
+		);
+		assert_eq!(promotion.redemptions, 2);
+	}
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[zero_copy]
+#[derive(Default)]
+pub struct HistoryEntry {
+	pub timestamp: i64,
+	pub amount: u64,               // Amount paid, 0 for rides on an already paid product
+	pub station_id: u32,           // Boarding station, 0 when not known
+	pub transport_mode: u8,        // 0 = bus, 1 = train
+	pub product: u8,               // HISTORY_* value
+	pub _padding: [u8; 2],
+}
+
+/// Fixed-size ring buffer of a rider's most recent purchases and rides
+#[account(zero_copy)]
+pub struct RideHistory {
+	pub user: Pubkey,
+	pub entries: [HistoryEntry; 32],  // RIDE_HISTORY_LEN entries, oldest overwritten first
+	pub head: u32,                    // Index the next entry is written to
+	pub count: u32,                   // Number of valid entries, at most RIDE_HISTORY_LEN
+	pub bump: u8,
+	pub version: u8,                  // Account layout version, RideHistory::VERSION once initialized
+	pub _padding: [u8; 6],
+}
+
+impl RideHistory {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Append an entry, overwriting the oldest one once the buffer is full
+	pub fn push(&mut self, entry: HistoryEntry) {
+		self.entries[self.head as usize] = entry;
+		self.head = (self.head + 1) % crate::RIDE_HISTORY_LEN as u32;
+		self.count = (self.count + 1).min(crate::RIDE_HISTORY_LEN as u32);
+	}
+
+	/// Entries from newest to oldest
+	pub fn iter_newest_first(&self) -> impl Iterator<Item = &HistoryEntry> {
+		let len = crate::RIDE_HISTORY_LEN;
+		let head = self.head as usize;
+		(1..=self.count as usize).map(move |i| &self.entries[(head + len - i) % len])
+	}
+}
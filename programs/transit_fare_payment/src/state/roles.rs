@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::FarePaymentError;
+
+/// Holders of the privileged roles below the admin
+#[account]
+#[derive(InitSpace)]
+pub struct Roles {
+	pub version: u8,               // Account layout version, Roles::VERSION once initialized
+	pub fare_manager: Pubkey,        // Sets fares, pass prices, loyalty rules, promotions and the price feed
+	pub treasurer: Pubkey,           // Manages accepted currencies and their treasuries
+	pub operator: Pubkey,            // Runs day-to-day operations such as retention policy
+	pub eligibility_issuer: Pubkey,  // Registers retail partners and issues gift cards
+	pub change_count: u64,           // Role changes recorded so far, seeds the next record
+	pub bump: u8,
+}
+
+/// On-chain record of a single admin transfer or role assignment
+#[account]
+#[derive(InitSpace)]
+pub struct RoleChange {
+	pub version: u8,               // Account layout version, RoleChange::VERSION once initialized
+	pub change_id: u64,         // Index of the change in the roles history
+	pub role: u8,               // ROLE_* value
+	pub previous: Pubkey,
+	pub new: Pubkey,
+	pub changed_by: Pubkey,     // Signer that made the change
+	pub slot: u64,
+	pub timestamp: i64,
+	pub bump: u8,
+}
+
+impl RoleChange {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
+
+impl Roles {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+
+	/// Roles with every role held by `admin`, before it delegates any
+	pub fn held_by(admin: Pubkey, bump: u8) -> Self {
+		Roles {
+			version: Roles::VERSION,
+			fare_manager: admin,
+			treasurer: admin,
+			operator: admin,
+			eligibility_issuer: admin,
+			change_count: 0,
+			bump,
+		}
+	}
+
+	/// Assign `role` to `holder`, returning the previous holder
+	pub fn assign(&mut self, role: u8, holder: Pubkey) -> Result<Pubkey> {
+		let slot = match role {
+			crate::ROLE_FARE_MANAGER => &mut self.fare_manager,
+			crate::ROLE_TREASURER => &mut self.treasurer,
+			crate::ROLE_OPERATOR => &mut self.operator,
+			crate::ROLE_ELIGIBILITY_ISSUER => &mut self.eligibility_issuer,
+			_ => return err!(FarePaymentError::InvalidRole),
+		};
+		Ok(std::mem::replace(slot, holder))
+	}
+
+	/// Write the next role change record and advance the counter
+	pub fn record_change(&mut self, change: &mut RoleChange, role: u8, previous: Pubkey, new: Pubkey, changed_by: Pubkey, bump: u8) -> Result<()> {
+		let clock = Clock::get()?;
+		*change = RoleChange {
+			version: RoleChange::VERSION,
+			change_id: self.change_count,
+			role,
+			previous,
+			new,
+			changed_by,
+			slot: clock.slot,
+			timestamp: clock.unix_timestamp,
+			bump,
+		};
+		self.change_count += 1;
+		Ok(())
+	}
+}
@@ -1,12 +1,64 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(InitSpace)]
 pub struct Ticket {
+	pub version: u8,               // Account layout version, Ticket::VERSION once initialized
 	pub user: Pubkey,
 	pub ticket_id: u64,
 	pub transport_mode: u8,     // 0 = bus, 1 = train
-	pub fare_amount: u64,
+	pub fare_amount: u64,       // Amount paid, in currency_mint
+	pub currency_mint: Pubkey,  // Mint the ticket was paid in
 	pub purchase_timestamp: i64,
 	pub status: u8,             // 0 = unused, 1 = used
+	pub sponsor_escrow: Pubkey, // Employer escrow that paid for the ticket, default if self-paid
+	pub loyalty_points_awarded: u64, // Points earned by this ticket, clawed back on refund
+	pub expires_at: i64,        // Ticket can no longer be used after this, 0 = never expires
+	pub rent_payer: Pubkey,     // Fee payer that funded the account, refunded on close
+	pub bump: u8,
+}
+
+impl Ticket {
+	/// Layout version written by this program
+	pub const VERSION: u8 = 1;
+}
+
+/// Ticket layout written before accounts carried a version byte
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TicketV0 {
+	pub user: Pubkey,
+	pub ticket_id: u64,
+	pub transport_mode: u8,
+	pub fare_amount: u64,
+	pub purchase_timestamp: i64,
+	pub status: u8,
 	pub bump: u8,
-}
\ No newline at end of file
+}
+
+impl TicketV0 {
+	/// Serialized size of the fields, without the discriminator
+	pub const LEN: usize = 32 + 8 + 1 + 8 + 8 + 1 + 1;
+	/// Largest ticket account the unversioned program allocated
+	pub const MAX_LEN: usize = 67;
+
+	/// Carry the old fields into the current layout. The old program only
+	/// took `currency_mint` and never expired tickets; nobody recorded who
+	/// paid the rent, so it goes back to the rider on close.
+	pub fn upgrade(self, currency_mint: Pubkey, bump: u8) -> Ticket {
+		Ticket {
+			version: Ticket::VERSION,
+			user: self.user,
+			ticket_id: self.ticket_id,
+			transport_mode: self.transport_mode,
+			fare_amount: self.fare_amount,
+			currency_mint,
+			purchase_timestamp: self.purchase_timestamp,
+			status: self.status,
+			sponsor_escrow: Pubkey::default(),
+			loyalty_points_awarded: 0,
+			expires_at: 0,
+			rent_payer: self.user,
+			bump,
+		}
+	}
+}